@@ -5,10 +5,19 @@
 //!
 //! Provides reusable contract execution functionality, supporting smart contract deployment and invocation
 
-use crate::mock_context::MockContext;
+use crate::mock_context::{AccessList, MockContext};
+#[cfg(feature = "cheatcodes")]
+use crate::mock_evm_bridge::create_cheat_host_functions;
 use crate::mock_evm_bridge::create_complete_evm_host_functions;
-use dtvmcore_rust::core::runtime::ZenRuntime;
+#[cfg(feature = "debug")]
+use crate::mock_evm_bridge::create_debug_host_functions;
+use dtvmcore_rust::core::runtime::{ZenModule, ZenRuntime};
+use dtvmcore_rust::evm::gas::{capped_refund, intrinsic_gas};
 use dtvmcore_rust::evm::EvmHost;
+use parity_wasm::elements::{External, Module};
+use sha3::{Digest, Keccak256};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::rc::Rc;
 
 /// Contract execution result
@@ -18,11 +27,92 @@ pub struct ContractExecutionResult {
     pub return_data: Vec<u8>,
     pub error_message: Option<String>,
     pub is_reverted: bool,
+    /// Gas left when execution ended (0 after an exceptional halt)
+    pub gas_left: u64,
+    /// Gas consumed by the execution (gas limit - gas left)
+    pub gas_used: i64,
+}
+
+/// Selector of the `Error(string)` revert payload emitted by `require`/`revert`
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of the `Panic(uint256)` revert payload emitted by failed checks
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Loaded modules keyed by contract name and code hash
+type ModuleCache = RefCell<HashMap<(String, [u8; 32]), Rc<ZenModule>>>;
+
+impl ContractExecutionResult {
+    /// Decode the Solidity revert reason of a reverted call
+    ///
+    /// `Error(string)` payloads yield the reason string, `Panic(uint256)` payloads
+    /// a description of the panic code. Returns None for calls that did not
+    /// revert or whose revert data is in neither format.
+    pub fn revert_reason(&self) -> Option<String> {
+        if !self.is_reverted {
+            return None;
+        }
+        decode_revert_reason(&self.return_data)
+    }
+
+    /// ABI-decode the return data of a successful call as `types`
+    /// Fails if the call did not succeed or the data does not match the types
+    pub fn decode(&self, types: &[ethabi::ParamType]) -> Result<Vec<ethabi::Token>, String> {
+        if !self.success {
+            return Err(match self.revert_reason() {
+                Some(reason) => format!("Call reverted: {}", reason),
+                None => format!(
+                    "Call failed: {}",
+                    self.error_message.as_deref().unwrap_or("unknown error")
+                ),
+            });
+        }
+        ethabi::decode(types, &self.return_data)
+            .map_err(|e| format!("Failed to decode return data: {}", e))
+    }
+}
+
+/// Decode `Error(string)` and `Panic(uint256)` revert data
+fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    let (selector, payload) = (data.get(..4)?, &data[4..]);
+
+    if selector == ERROR_STRING_SELECTOR {
+        let tokens = ethabi::decode(&[ethabi::ParamType::String], payload).ok()?;
+        return tokens.into_iter().next()?.into_string();
+    }
+
+    if selector == PANIC_SELECTOR {
+        let tokens = ethabi::decode(&[ethabi::ParamType::Uint(256)], payload).ok()?;
+        let code = tokens.into_iter().next()?.into_uint()?;
+        let description = match u64::try_from(code).unwrap_or(u64::MAX) {
+            0x00 => "generic compiler panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic underflow or overflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "invalid storage byte array encoding",
+            0x31 => "pop on empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "out of memory",
+            0x51 => "call to zero-initialized function",
+            _ => "unknown panic code",
+        };
+        return Some(format!("Panic(0x{:x}): {}", code, description));
+    }
+
+    None
 }
 
 /// Contract executor
 pub struct ContractExecutor {
     runtime: Rc<ZenRuntime>,
+    /// Gas used per label, recorded by `gas_snapshot`
+    gas_snapshots: RefCell<BTreeMap<String, u64>>,
+    /// Loaded modules keyed by contract name and code hash
+    modules: ModuleCache,
+    /// Number of modules parsed by the runtime
+    module_loads: Cell<usize>,
+    /// Names of the host functions registered in the `env` module
+    host_function_names: HashSet<String>,
 }
 
 impl ContractExecutor {
@@ -38,7 +128,82 @@ impl ContractExecutor {
             .create_host_module("env", host_funcs.iter(), true)
             .map_err(|e| format!("Host module creation failed: {}", e))?;
 
-        Ok(ContractExecutor { runtime: rt })
+        // Register test-only cheatcodes in their own host module
+        #[cfg(feature = "cheatcodes")]
+        {
+            let cheat_funcs = create_cheat_host_functions();
+            let _cheats_module = rt
+                .create_host_module("cheats", cheat_funcs.iter(), true)
+                .map_err(|e| format!("Cheats host module creation failed: {}", e))?;
+        }
+
+        // Register test-only debugging aids in their own host module
+        #[cfg(feature = "debug")]
+        {
+            let debug_funcs = create_debug_host_functions();
+            let _debug_module = rt
+                .create_host_module("debug", debug_funcs.iter(), true)
+                .map_err(|e| format!("Debug host module creation failed: {}", e))?;
+        }
+
+        Ok(ContractExecutor {
+            runtime: rt,
+            gas_snapshots: RefCell::new(BTreeMap::new()),
+            modules: RefCell::new(HashMap::new()),
+            module_loads: Cell::new(0),
+            host_function_names: host_funcs.into_iter().map(|desc| desc.name).collect(),
+        })
+    }
+
+    /// Load a module, reusing the one already loaded for identical name and code
+    fn load_module(&self, contract_name: &str, wasm_bytes: &[u8]) -> Result<Rc<ZenModule>, String> {
+        let key = (
+            contract_name.to_string(),
+            Keccak256::digest(wasm_bytes).into(),
+        );
+        if let Some(module) = self.modules.borrow().get(&key) {
+            return Ok(module.clone());
+        }
+
+        let module = self
+            .runtime
+            .load_module_from_bytes(contract_name, wasm_bytes)
+            .map_err(|e| format!("Failed to load WASM module: {}", e))?;
+        self.module_loads.set(self.module_loads.get() + 1);
+        self.modules.borrow_mut().insert(key, module.clone());
+        Ok(module)
+    }
+
+    /// Number of modules the runtime has parsed; cached modules are not counted again
+    pub fn module_load_count(&self) -> usize {
+        self.module_loads.get()
+    }
+
+    /// Check that every function a module imports from `env` is a registered host function
+    /// Returns the names of the imported functions that are not provided
+    ///
+    /// Modules that cannot be parsed pass; loading them reports the actual error.
+    pub fn validate_imports(&self, wasm_bytes: &[u8]) -> Result<(), Vec<String>> {
+        let Ok(module) = Module::from_bytes(wasm_bytes) else {
+            return Ok(());
+        };
+
+        let missing: Vec<String> = module
+            .import_section()
+            .map(|section| section.entries())
+            .unwrap_or_default()
+            .iter()
+            .filter(|entry| entry.module() == "env")
+            .filter(|entry| matches!(entry.external(), External::Function(_)))
+            .filter(|entry| !self.host_function_names.contains(entry.field()))
+            .map(|entry| entry.field().to_string())
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
     }
 
     /// Deploy contract
@@ -50,10 +215,21 @@ impl ContractExecutor {
         // Load WASM file
         let wasm_bytes = context.code_copy();
 
-        let wasm_mod = self
-            .runtime
-            .load_module_from_bytes(contract_name, &wasm_bytes)
-            .map_err(|e| format!("Failed to load WASM module: {}", e))?;
+        // The storage diff covers a single top-level call
+        if context.get_call_depth() == 0 {
+            context.clear_storage_diff();
+        }
+
+        // Report unsupported imports by name instead of failing to link
+        self.validate_imports(wasm_bytes).map_err(|missing| {
+            format!(
+                "Contract {} imports unsupported host functions: {}",
+                contract_name,
+                missing.join(", ")
+            )
+        })?;
+
+        let wasm_mod = self.load_module(contract_name, wasm_bytes)?;
 
         // Deploy contract
         let isolation = self
@@ -65,8 +241,17 @@ impl ContractExecutor {
             .new_instance_with_context(isolation, context.get_gas_limit() as u64, context.clone())
             .map_err(|e| format!("Failed to create instance: {}", e))?;
 
-        inst.call_wasm_func("deploy", &[])
-            .map_err(|e| format!("Failed to deploy contract: {}", e))?;
+        let deploy_result = inst.call_wasm_func("deploy", &[]);
+
+        // Transient storage and original slot values are discarded when the
+        // top-level transaction ends
+        if context.get_call_depth() == 0 {
+            context.clear_transient_storage();
+            context.clear_original_storage();
+            context.clear_created_contracts();
+        }
+
+        deploy_result.map_err(|e| format!("Failed to deploy contract: {}", e))?;
 
         Ok(())
     }
@@ -80,10 +265,12 @@ impl ContractExecutor {
         // Load WASM module
         let wasm_bytes = context.code_copy();
 
-        let wasm_mod = self
-            .runtime
-            .load_module_from_bytes(contract_name, &wasm_bytes)
-            .map_err(|e| format!("Failed to load WASM module: {}", e))?;
+        // The storage diff covers a single top-level call
+        if context.get_call_depth() == 0 {
+            context.clear_storage_diff();
+        }
+
+        let wasm_mod = self.load_module(contract_name, wasm_bytes)?;
 
         // Create isolation and call
         let isolation = self
@@ -91,12 +278,23 @@ impl ContractExecutor {
             .new_isolation()
             .map_err(|e| format!("Failed to create isolation: {}", e))?;
 
+        let gas_limit = context.get_gas_limit();
         let inst = wasm_mod
-            .new_instance_with_context(isolation, context.get_gas_limit() as u64, context.clone())
+            .new_instance_with_context(isolation, gas_limit as u64, context.clone())
             .map_err(|e| format!("Failed to create instance: {}", e))?;
 
         // Execute function call
-        match inst.call_wasm_func("call", &[]) {
+        let call_result = inst.call_wasm_func("call", &[]);
+
+        // Transient storage and original slot values are discarded when the
+        // top-level transaction ends
+        if context.get_call_depth() == 0 {
+            context.clear_transient_storage();
+            context.clear_original_storage();
+            context.clear_created_contracts();
+        }
+
+        match call_result {
             Ok(_) => {
                 let is_reverted = context.is_reverted();
 
@@ -107,11 +305,16 @@ impl ContractExecutor {
                         vec![]
                     };
 
+                    let error_message = decode_revert_reason(&return_data)
+                        .unwrap_or_else(|| "Transaction reverted".to_string());
+
                     Ok(ContractExecutionResult {
                         success: false,
                         return_data,
-                        error_message: Some("Transaction reverted".to_string()),
+                        error_message: Some(error_message),
                         is_reverted: true,
+                        gas_left: inst.get_gas_left(),
+                        gas_used: gas_limit - inst.get_gas_left() as i64,
                     })
                 } else {
                     let return_data = if context.has_return_data() {
@@ -125,17 +328,151 @@ impl ContractExecutor {
                         return_data,
                         error_message: None,
                         is_reverted: false,
+                        gas_left: inst.get_gas_left(),
+                        gas_used: gas_limit - inst.get_gas_left() as i64,
                     })
                 }
             }
-            Err(err) => Ok(ContractExecutionResult {
-                success: false,
-                return_data: vec![],
-                error_message: Some(err.to_string()),
-                is_reverted: context.is_reverted(),
-            }),
+            Err(err) => {
+                // A revert returns the remaining gas and its revert data (so callers
+                // can decode the reason); any other failure consumes all gas
+                let is_reverted = context.is_reverted();
+                let return_data = if is_reverted && context.has_return_data() {
+                    context.return_data_copy()
+                } else {
+                    vec![]
+                };
+                let error_message = decode_revert_reason(&return_data).unwrap_or(err.to_string());
+                let gas_left = if is_reverted { inst.get_gas_left() } else { 0 };
+                Ok(ContractExecutionResult {
+                    success: false,
+                    return_data,
+                    error_message: Some(error_message),
+                    is_reverted,
+                    gas_left,
+                    gas_used: gas_limit - gas_left as i64,
+                })
+            }
         }
     }
+
+    /// Execute a contract call and return the access list it generates (EIP-2930)
+    ///
+    /// Accesses made before the call are forgotten first, so the result lists
+    /// exactly the accounts and storage slots the call touched, like
+    /// `eth_createAccessList`.
+    pub fn generate_access_list(
+        &self,
+        contract_name: &str,
+        context: &mut MockContext,
+    ) -> Result<AccessList, String> {
+        context.clear_warm_accounts();
+        self.call_contract_function(contract_name, context)?;
+        Ok(context.access_list())
+    }
+
+    /// Execute a contract call as a full transaction and return the total gas charged
+    ///
+    /// Total = intrinsic gas (base + call data) + execution gas - refund,
+    /// with the refund capped at a fifth of the gas used and dropped if the call fails.
+    pub fn total_transaction_gas(
+        &self,
+        contract_name: &str,
+        context: &mut MockContext,
+    ) -> Result<u64, String> {
+        let intrinsic = intrinsic_gas(context.call_data_copy());
+        let gas_limit = context.get_gas_limit() as u64;
+
+        context.clear_gas_refund();
+        let result = self.call_contract_function(contract_name, context)?;
+
+        let gas_used = intrinsic + gas_limit.saturating_sub(result.gas_left);
+        let refund = if result.success {
+            capped_refund(gas_used, context.get_gas_refund())
+        } else {
+            0
+        };
+
+        Ok(gas_used - refund)
+    }
+
+    /// Call a contract function and record the execution gas it used under `label`
+    /// Recording the same label again replaces the previous value
+    pub fn gas_snapshot(
+        &self,
+        contract_name: &str,
+        context: &mut MockContext,
+        label: &str,
+    ) -> Result<u64, String> {
+        let result = self.call_contract_function(contract_name, context)?;
+        if !result.success {
+            return Err(format!(
+                "Gas snapshot '{}' failed: call did not succeed",
+                label
+            ));
+        }
+
+        let gas_used = result.gas_used.max(0) as u64;
+        self.gas_snapshots
+            .borrow_mut()
+            .insert(label.to_string(), gas_used);
+        Ok(gas_used)
+    }
+
+    /// Get all recorded gas snapshots (label -> gas used)
+    pub fn gas_snapshots(&self) -> BTreeMap<String, u64> {
+        self.gas_snapshots.borrow().clone()
+    }
+
+    /// Assert that the gas recorded under `label` is within `tolerance` of `expected`
+    pub fn assert_gas_within(&self, label: &str, expected: u64, tolerance: u64) {
+        let gas_used = *self
+            .gas_snapshots
+            .borrow()
+            .get(label)
+            .unwrap_or_else(|| panic!("No gas snapshot recorded for '{}'", label));
+
+        assert!(
+            gas_used.abs_diff(expected) <= tolerance,
+            "Gas snapshot '{}' used {} gas, expected {} +/- {}",
+            label,
+            gas_used,
+            expected,
+            tolerance
+        );
+    }
+
+    /// Write the recorded gas snapshots to a golden file, one `label gas` line per snapshot
+    pub fn write_gas_snapshots(&self, path: &str) -> Result<(), String> {
+        let contents: String = self
+            .gas_snapshots
+            .borrow()
+            .iter()
+            .map(|(label, gas)| format!("{} {}\n", label, gas))
+            .collect();
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write gas snapshots to {}: {}", path, e))
+    }
+
+    /// Read gas snapshots written by `write_gas_snapshots`, e.g. as baselines
+    pub fn read_gas_snapshots(path: &str) -> Result<BTreeMap<String, u64>, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read gas snapshots from {}: {}", path, e))?;
+
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (label, gas) = line
+                    .rsplit_once(' ')
+                    .ok_or_else(|| format!("Invalid gas snapshot line: {}", line))?;
+                let gas = gas
+                    .parse()
+                    .map_err(|_| format!("Invalid gas value in line: {}", line))?;
+                Ok((label.to_string(), gas))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -175,6 +512,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_repeated_calls_load_module_once() {
+        let counter_wasm =
+            std::fs::read("../example/counter.wasm").expect("Failed to load counter.wasm");
+
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        let mut context = MockContext::builder()
+            .with_code(counter_wasm)
+            .with_storage(Rc::new(RefCell::new(HashMap::new())))
+            .with_address([0x42; 20])
+            .with_gas_limit(1000000)
+            .build();
+        executor
+            .deploy_contract("counter", &mut context)
+            .expect("Counter deployment should succeed");
+
+        const COUNT_SELECTOR: [u8; 4] = [0x06, 0x66, 0x1a, 0xbd]; // count()
+        context.set_call_data(COUNT_SELECTOR.to_vec());
+        for _ in 0..100 {
+            let result = executor
+                .call_contract_function("counter", &mut context)
+                .expect("count() call should succeed");
+            assert!(result.success);
+        }
+
+        assert_eq!(
+            executor.module_load_count(),
+            1,
+            "Identical code should be parsed once"
+        );
+
+        executor
+            .call_contract_function("counter_copy", &mut context)
+            .expect("count() call should succeed");
+        assert_eq!(
+            executor.module_load_count(),
+            2,
+            "Modules are cached per contract name"
+        );
+    }
+
     #[test]
     fn test_call_contract_function_with_counter() {
         // Load counter.wasm file for testing
@@ -261,4 +639,130 @@ mod tests {
             }
         }
     }
+
+    fn reverted_with(return_data: Vec<u8>) -> ContractExecutionResult {
+        ContractExecutionResult {
+            success: false,
+            return_data,
+            error_message: None,
+            is_reverted: true,
+            gas_left: 0,
+            gas_used: 0,
+        }
+    }
+
+    #[test]
+    fn test_revert_reason_decodes_error_string() {
+        let mut data = ERROR_STRING_SELECTOR.to_vec();
+        data.extend(ethabi::encode(&[ethabi::Token::String(
+            "Insufficient balance".to_string(),
+        )]));
+
+        assert_eq!(
+            reverted_with(data).revert_reason().as_deref(),
+            Some("Insufficient balance")
+        );
+    }
+
+    #[test]
+    fn test_revert_reason_decodes_panic_code() {
+        let mut data = PANIC_SELECTOR.to_vec();
+        data.extend(ethabi::encode(&[ethabi::Token::Uint(0x11.into())]));
+
+        assert_eq!(
+            reverted_with(data).revert_reason().as_deref(),
+            Some("Panic(0x11): arithmetic underflow or overflow")
+        );
+    }
+
+    #[test]
+    fn test_revert_reason_ignores_unknown_payloads() {
+        assert_eq!(reverted_with(vec![]).revert_reason(), None);
+        assert_eq!(
+            reverted_with(vec![0xde, 0xad, 0xbe, 0xef]).revert_reason(),
+            None
+        );
+
+        let mut not_reverted = reverted_with(ERROR_STRING_SELECTOR.to_vec());
+        not_reverted.is_reverted = false;
+        assert_eq!(not_reverted.revert_reason(), None);
+    }
+
+    #[test]
+    fn test_decode_multi_return() {
+        let owner = ethabi::Address::from([0x42; 20]);
+        let result = ContractExecutionResult {
+            success: true,
+            return_data: ethabi::encode(&[
+                ethabi::Token::Uint(1234.into()),
+                ethabi::Token::Address(owner),
+            ]),
+            error_message: None,
+            is_reverted: false,
+            gas_left: 0,
+            gas_used: 0,
+        };
+
+        let tokens = result
+            .decode(&[ethabi::ParamType::Uint(256), ethabi::ParamType::Address])
+            .expect("Return data should decode");
+        assert_eq!(
+            tokens,
+            vec![
+                ethabi::Token::Uint(1234.into()),
+                ethabi::Token::Address(owner)
+            ]
+        );
+
+        let mut data = ERROR_STRING_SELECTOR.to_vec();
+        data.extend(ethabi::encode(&[ethabi::Token::String(
+            "Not owner".to_string(),
+        )]));
+        assert_eq!(
+            reverted_with(data).decode(&[ethabi::ParamType::Uint(256)]),
+            Err("Call reverted: Not owner".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deploy_rejects_unknown_imports() {
+        let wasm = wat::parse_str(
+            r#"
+(module
+  (import "env" "getAddress" (func $getAddress (param i32)))
+  (import "env" "doesNotExist" (func $doesNotExist))
+  (memory (export "memory") 1)
+  (func (export "deploy") (call $doesNotExist))
+  (func (export "call")))
+"#,
+        )
+        .expect("Failed to parse WAT");
+
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        assert_eq!(
+            executor.validate_imports(&wasm),
+            Err(vec!["doesNotExist".to_string()])
+        );
+
+        let mut context = MockContext::builder()
+            .with_code(wasm)
+            .with_gas_limit(1000000)
+            .build();
+        let error = executor
+            .deploy_contract("bogus_imports", &mut context)
+            .expect_err("Deploying a module with unknown imports should fail");
+        assert!(error.contains("doesNotExist"), "{}", error);
+    }
+
+    #[test]
+    fn test_validate_imports_accepts_every_registered_host_function() {
+        let imports: String = create_complete_evm_host_functions()
+            .iter()
+            .map(|desc| format!("  (import \"env\" \"{}\" (func))\n", desc.name))
+            .collect();
+        let wasm = wat::parse_str(format!("(module\n{})", imports)).expect("Failed to parse WAT");
+
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        assert_eq!(executor.validate_imports(&wasm), Ok(()));
+    }
 }
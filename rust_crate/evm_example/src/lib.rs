@@ -4,3 +4,4 @@
 pub mod contract_executor;
 pub mod mock_context;
 pub mod mock_evm_bridge;
+pub mod scenario;
@@ -8,12 +8,49 @@
 //! context implementations based on their specific needs.
 
 use crate::contract_executor::{ContractExecutionResult, ContractExecutor};
+use dtvmcore_rust::evm::error::{gas_error, HostFunctionResult};
+use dtvmcore_rust::evm::gas::{GasCosts, HostGasSchedule};
+use dtvmcore_rust::evm::host_functions::is_block_hash_available;
+use dtvmcore_rust::evm::precompiles::{is_precompile, run_precompile};
 use dtvmcore_rust::evm::traits::*;
+use dtvmcore_rust::evm::utils::{u128_to_bytes32, MAX_BUFFER_SIZE};
 use dtvmcore_rust::LogEvent;
-use std::cell::RefCell;
-use std::collections::HashMap;
+use num_bigint::BigUint;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::rc::Rc;
 
+mod storage;
+
+#[cfg(feature = "serde")]
+mod snapshot;
+
+pub use storage::{StorageBackend, StorageSnapshot};
+
+#[cfg(feature = "serde")]
+pub use snapshot::{ContextSnapshot, ContractSnapshot, EventSnapshot};
+
+/// Keccak-256 implementation a context can hash with (see `with_keccak`)
+pub type KeccakFn = fn(&[u8]) -> [u8; 32];
+
+/// EIP-2930 access list: accessed accounts with their accessed storage keys
+pub type AccessList = Vec<([u8; 20], Vec<[u8; 32]>)>;
+
+/// Per-slot state shared by all frames: (address, key) -> value
+type SlotMap<V> = Rc<RefCell<HashMap<([u8; 20], [u8; 32]), V>>>;
+
+/// Memory regions dumped by contracts: (offset, bytes)
+type MemoryDumps = Rc<RefCell<Vec<(u32, Vec<u8>)>>>;
+
+/// Canned call responses: (target, selector) -> (success, return data)
+type MockedCalls = Rc<RefCell<HashMap<([u8; 20], [u8; 4]), (bool, Vec<u8>)>>>;
+
+/// Written slots keyed like the storage backend: hex key -> (original, latest value)
+type DirtySlots = Rc<RefCell<HashMap<String, (Vec<u8>, Vec<u8>)>>>;
+
+/// Set of storage slots shared by all frames: (address, key)
+type SlotSet = Rc<RefCell<HashSet<([u8; 20], [u8; 32])>>>;
+
 /// Contract information stored in the registry
 #[derive(Clone, Debug)]
 pub struct ContractInfo {
@@ -27,19 +64,63 @@ impl ContractInfo {
     }
 }
 
+/// Initial state of an account, used to load genesis-like fixtures
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccountState {
+    /// Balance in wei (32-byte big-endian)
+    pub balance: [u8; 32],
+    /// Account nonce
+    pub nonce: u64,
+    /// Contract code (empty for externally owned accounts)
+    pub code: Vec<u8>,
+    /// Storage slots (key -> value)
+    pub storage: HashMap<[u8; 32], [u8; 32]>,
+}
+
+/// Behaviour when a call targets an address with no registered contract
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContractNotFoundPolicy {
+    /// Succeed with empty return data (EVM behavior for accounts without code)
+    #[default]
+    ReturnEmptySuccess,
+    /// Fail the call
+    Fail,
+    /// Execute the caller's own code at the target address (legacy mock behavior)
+    ExecuteCallerCode,
+}
+
+/// Number of previous blocks whose base fee `BlockInfo` keeps
+pub const BASE_FEE_HISTORY_SIZE: usize = 16;
+
+/// Deepest call depth a frame may run at; calls and creates beyond it fail
+pub const MAX_CALL_DEPTH: usize = 1024;
+
 /// Block information for EVM context
 /// Contains all block-related data needed for EVM execution
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlockInfo {
     pub number: i64,
     pub timestamp: i64,
     pub gas_limit: i64,
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
     pub coinbase: [u8; 20],
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
     pub prev_randao: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
     pub base_fee: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
     pub blob_base_fee: [u8; 32],
     /// Block hash for the current block (mock value)
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
     pub hash: [u8; 32],
+    /// Proof-of-work difficulty, used before the Merge
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
+    pub difficulty: [u8; 32],
+    /// Base fees of previous blocks, most recent (one block ago) first
+    /// Holds at most `BASE_FEE_HISTORY_SIZE` entries
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex::seq"))]
+    pub base_fee_history: VecDeque<[u8; 32]>,
 }
 
 impl Default for BlockInfo {
@@ -59,6 +140,9 @@ impl Default for BlockInfo {
         let mut hash = [0u8; 32];
         hash[0] = 0x06; // Mock block hash
 
+        let mut difficulty = [0u8; 32];
+        difficulty[29] = 0x02; // Mock difficulty (131072)
+
         Self {
             number: 12345,
             timestamp: 1234567890,
@@ -68,6 +152,8 @@ impl Default for BlockInfo {
             base_fee,
             blob_base_fee,
             hash,
+            difficulty,
+            base_fee_history: VecDeque::new(),
         }
     }
 }
@@ -98,6 +184,8 @@ impl BlockInfo {
             base_fee,
             blob_base_fee,
             hash,
+            difficulty: Self::default().difficulty,
+            base_fee_history: VecDeque::new(),
         }
     }
 
@@ -116,6 +204,11 @@ impl BlockInfo {
         &self.base_fee
     }
 
+    /// Get difficulty as bytes
+    pub fn get_difficulty_bytes(&self) -> &[u8; 32] {
+        &self.difficulty
+    }
+
     /// Get blob base fee as bytes
     pub fn get_blob_base_fee_bytes(&self) -> &[u8; 32] {
         &self.blob_base_fee
@@ -125,16 +218,56 @@ impl BlockInfo {
     pub fn get_hash(&self) -> &[u8; 32] {
         &self.hash
     }
+
+    /// Get the base fee of the block `blocks_ago` blocks before the current one
+    /// Returns the current base fee for 0, and None outside the kept history
+    pub fn get_base_fee_at(&self, blocks_ago: u64) -> Option<&[u8; 32]> {
+        match blocks_ago {
+            0 => Some(&self.base_fee),
+            n => self.base_fee_history.get(usize::try_from(n - 1).ok()?),
+        }
+    }
+
+    /// Move to a new base fee, keeping the current one in the history
+    /// The oldest entry is dropped once the history is full
+    pub fn push_base_fee(&mut self, base_fee: [u8; 32]) {
+        self.base_fee_history.push_front(self.base_fee);
+        self.base_fee_history.truncate(BASE_FEE_HISTORY_SIZE);
+        self.base_fee = base_fee;
+    }
 }
 
 /// Transaction information for EVM context
 /// Contains all transaction-related data needed for EVM execution
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransactionInfo {
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
     pub origin: [u8; 20],
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex"))]
     pub gas_price: [u8; 32],
+    /// Floor the effective gas price is raised to, if any
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "dtvmcore_rust::evm::serde_hex::option")
+    )]
+    pub min_gas_price: Option<[u8; 32]>,
+    /// Ceiling the effective gas price is capped at, if any
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "dtvmcore_rust::evm::serde_hex::option")
+    )]
+    pub max_gas_price: Option<[u8; 32]>,
     /// Gas left for execution
     pub gas_limit: i64,
+    /// Gas reserved by the host, hidden from the gas left reported to contracts
+    pub gas_stipend: Option<i64>,
+    /// Blob versioned hashes attached to the transaction (EIP-4844)
+    #[cfg_attr(feature = "serde", serde(with = "dtvmcore_rust::evm::serde_hex::seq"))]
+    pub blob_hashes: Vec<[u8; 32]>,
+    /// Gas left for host function charges (shared by all frames of the transaction)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub host_gas_left: Rc<Cell<u64>>,
 }
 
 impl Default for TransactionInfo {
@@ -148,7 +281,12 @@ impl Default for TransactionInfo {
         Self {
             origin,
             gas_price,
+            min_gas_price: None,
+            max_gas_price: None,
             gas_limit: 100, // Default gas limit
+            gas_stipend: None,
+            blob_hashes: Vec::new(),
+            host_gas_left: Rc::new(Cell::new(0)),
         }
     }
 }
@@ -164,6 +302,24 @@ impl TransactionInfo {
         &self.gas_price
     }
 
+    /// Get the gas price clamped into [min_gas_price, max_gas_price]
+    /// Big-endian byte arrays compare like the numbers they encode
+    pub fn get_effective_gas_price_bytes(&self) -> &[u8; 32] {
+        let mut price = &self.gas_price;
+        if let Some(min) = &self.min_gas_price {
+            price = price.max(min);
+        }
+        if let Some(max) = &self.max_gas_price {
+            price = price.min(max);
+        }
+        price
+    }
+
+    /// Get blob versioned hashes
+    pub fn get_blob_hashes(&self) -> &[[u8; 32]] {
+        &self.blob_hashes
+    }
+
     /// Get gas left
     pub fn get_gas_limit(&self) -> i64 {
         self.gas_limit
@@ -181,8 +337,12 @@ impl TransactionInfo {
 pub struct MockContext {
     /// Contract code with 4-byte length prefix (big-endian)
     contract_code: Vec<u8>,
-    /// Storage mapping (hex key -> 32-byte value)
-    storage: Rc<RefCell<HashMap<String, Vec<u8>>>>,
+    /// Immutable variables of the current contract, by index
+    immutables: Vec<[u8; 32]>,
+    /// Contract storage backend
+    storage: Rc<RefCell<dyn StorageBackend>>,
+    /// Transient storage (EIP-1153): (address, key) -> value, cleared after each transaction
+    transient_storage: SlotMap<[u8; 32]>,
     /// Call data for the current execution
     call_data: Vec<u8>,
     /// Current contract address
@@ -195,6 +355,12 @@ pub struct MockContext {
     chain_id: [u8; 32],
     /// Block information
     block_info: BlockInfo,
+    /// Current block number; overridable mid-execution (shared by all frames)
+    block_number: Rc<Cell<i64>>,
+    /// Current block timestamp; overridable mid-execution (shared by all frames)
+    block_timestamp: Rc<Cell<i64>>,
+    /// Known hashes of recent blocks: block number -> hash (shared by all frames)
+    block_hashes: Rc<RefCell<HashMap<i64, [u8; 32]>>>,
     /// Transaction information
     tx_info: TransactionInfo,
     /// Return data from contract execution (set by finish function)
@@ -205,20 +371,119 @@ pub struct MockContext {
     events: Rc<RefCell<Vec<LogEvent>>>,
     /// Contract registry: address -> contract info
     contract_registry: Rc<RefCell<HashMap<[u8; 20], ContractInfo>>>,
+    /// Behaviour when calling an address that is not in the registry
+    contract_not_found_policy: ContractNotFoundPolicy,
+    /// Runtime code of contracts created by CREATE/CREATE2: address -> code
+    runtime_codes: Rc<RefCell<HashMap<[u8; 20], Vec<u8>>>>,
+    /// Balances credited to the beneficiary by SELFDESTRUCT: contract address -> amount
+    destruct_amounts: Rc<RefCell<HashMap<[u8; 20], [u8; 32]>>>,
+    /// Contracts removed by SELFDESTRUCT
+    destructed: Rc<RefCell<HashSet<[u8; 20]>>>,
+    /// Contracts created by the current transaction (shared by all frames)
+    created_contracts: Rc<RefCell<HashSet<[u8; 20]>>>,
+    /// Memory regions dumped by contracts (debug): (offset, bytes)
+    memory_dumps: MemoryDumps,
+    /// Memoized keccak256 of registered contract code: address -> code hash
+    code_hash_cache: Rc<RefCell<HashMap<[u8; 20], [u8; 32]>>>,
+    /// Number of times contract code was actually hashed (cache misses)
+    code_hash_computations: Rc<Cell<usize>>,
+    /// keccak256 of the init code of the most recent CREATE2 (shared by all frames)
+    last_create2_init_code_hash: Rc<Cell<Option<[u8; 32]>>>,
+    /// Account nonces used for CREATE address derivation
+    nonces: Rc<RefCell<HashMap<[u8; 20], u64>>>,
+    /// Whether this context executes inside a static call frame
+    static_mode: bool,
+    /// Whether deployed contract code may be replaced (proxy/upgrade tests only)
+    mutable_code: bool,
+    /// Account balances (32-byte big-endian wei)
+    balances: Rc<RefCell<HashMap<[u8; 20], [u8; 32]>>>,
+    /// Canned call responses: (target, selector) -> (success, return data)
+    mocked_calls: MockedCalls,
+    /// Whether copy operations charge EVM memory-expansion gas
+    memory_expansion_gas: bool,
+    /// Highest memory size touched by copy operations in the current frame
+    memory_size: Cell<u64>,
+    /// Gas refund accumulated by the current transaction (shared by all frames)
+    gas_refund: Rc<Cell<u64>>,
+    /// Storage gas schedule of net-metered SSTORE (None disables SSTORE gas)
+    gas_costs: Option<GasCosts>,
+    /// Gas charged per host function call (None disables host gas)
+    host_gas_schedule: Option<HostGasSchedule>,
+    /// Slot values before their first write in the current transaction (shared by all frames)
+    original_storage: SlotMap<[u8; 32]>,
+    /// (value before the first write, latest value) of each slot written by the
+    /// current top-level call, keyed like the storage backend (shared by all frames)
+    dirty_slots: DirtySlots,
+    /// Whether calls charge EIP-2929 cold/warm account-access gas
+    account_access_gas: bool,
+    /// Whether address-returning host functions write 32-byte left-padded words
+    padded_address_results: bool,
+    /// Accounts accessed by the current transaction (shared by all frames)
+    warm_accounts: Rc<RefCell<HashSet<[u8; 20]>>>,
+    /// Storage slots accessed by the current transaction (shared by all frames)
+    warm_storage_slots: SlotSet,
+    /// Hardfork whose rules apply to the current block
+    hardfork: Hardfork,
+    /// Call depth of this frame (0 for the top-level transaction)
+    call_depth: usize,
+    /// Deepest call depth reached by any frame (shared by all frames)
+    max_call_depth: Rc<Cell<usize>>,
+    /// Caller override for the next call made by this frame (one-shot, set by prank)
+    prank: Cell<Option<[u8; 20]>>,
+    /// Entry point the contract last reported dispatching to in this frame
+    last_dispatch: RefCell<Option<Dispatch>>,
+    /// Error of the most recently completed call if it failed (shared by all frames)
+    last_call_error: Rc<RefCell<Option<String>>>,
+    /// Keccak-256 implementation used instead of the default one
+    keccak: Option<KeccakFn>,
+    /// Largest log data payload a contract may emit
+    max_log_data: usize,
+}
+
+/// Frame of a nested call: runs `code_address`'s code at `target`
+#[derive(Clone, Copy)]
+struct CallFrame<'a> {
+    target: [u8; 20],
+    code_address: [u8; 20],
+    caller: [u8; 20],
+    value: [u8; 32],
+    data: &'a [u8],
+    gas: i64,
+    static_mode: bool,
 }
 
 /// Builder for MockContext with fluent interface
 pub struct MockContextBuilder {
     contract_code: Vec<u8>,
-    storage: Option<Rc<RefCell<HashMap<String, Vec<u8>>>>>,
+    immutables: Vec<[u8; 32]>,
+    storage: Option<Rc<RefCell<dyn StorageBackend>>>,
     call_data: Vec<u8>,
     address: [u8; 20],
     caller: [u8; 20],
     call_value: [u8; 32],
     chain_id: [u8; 32],
     block_info: BlockInfo,
+    block_hashes: HashMap<i64, [u8; 32]>,
     tx_info: TransactionInfo,
     contract_registry: Rc<RefCell<HashMap<[u8; 20], ContractInfo>>>,
+    /// Contracts inserted into the registry on build
+    contracts: Vec<([u8; 20], ContractInfo)>,
+    contract_not_found_policy: ContractNotFoundPolicy,
+    mutable_code: bool,
+    genesis: HashMap<[u8; 20], AccountState>,
+    balances: HashMap<[u8; 20], [u8; 32]>,
+    access_list: AccessList,
+    memory_expansion_gas: bool,
+    account_access_gas: bool,
+    padded_address_results: bool,
+    hardfork: Hardfork,
+    keccak: Option<KeccakFn>,
+    /// Largest log data payload a contract may emit
+    max_log_data: usize,
+    /// Storage gas schedule of net-metered SSTORE
+    gas_costs: Option<GasCosts>,
+    /// Gas charged per host function call
+    host_gas_schedule: Option<HostGasSchedule>,
 }
 
 impl MockContextBuilder {
@@ -241,6 +506,7 @@ impl MockContextBuilder {
 
         Self {
             contract_code: Vec::new(),
+            immutables: Vec::new(),
             storage: None,
             call_data,
             address,
@@ -248,8 +514,23 @@ impl MockContextBuilder {
             call_value,
             chain_id,
             block_info: BlockInfo::default(),
+            block_hashes: HashMap::new(),
             tx_info: TransactionInfo::default(),
             contract_registry: Rc::new(RefCell::new(HashMap::new())),
+            contracts: Vec::new(),
+            contract_not_found_policy: ContractNotFoundPolicy::default(),
+            mutable_code: false,
+            genesis: HashMap::new(),
+            balances: HashMap::new(),
+            access_list: Vec::new(),
+            memory_expansion_gas: false,
+            account_access_gas: false,
+            padded_address_results: false,
+            hardfork: Hardfork::default(),
+            keccak: None,
+            max_log_data: MAX_BUFFER_SIZE as usize,
+            gas_costs: None,
+            host_gas_schedule: None,
         }
     }
 
@@ -259,12 +540,25 @@ impl MockContextBuilder {
         self
     }
 
+    /// Set the immutable variables of the contract, read by index
+    /// Lets tests inject immutables without running the constructor
+    pub fn with_immutables(mut self, immutables: Vec<[u8; 32]>) -> Self {
+        self.immutables = immutables;
+        self
+    }
+
     /// Set the storage (shared or independent)
     pub fn with_storage(mut self, storage: Rc<RefCell<HashMap<String, Vec<u8>>>>) -> Self {
         self.storage = Some(storage);
         self
     }
 
+    /// Set a custom storage backend (e.g. persistent or instrumented)
+    pub fn with_storage_backend(mut self, backend: Rc<RefCell<dyn StorageBackend>>) -> Self {
+        self.storage = Some(backend);
+        self
+    }
+
     /// Set call data
     pub fn with_call_data(mut self, data: Vec<u8>) -> Self {
         self.call_data = data;
@@ -291,9 +585,7 @@ impl MockContextBuilder {
 
     /// Set chain ID from u64
     pub fn with_chain_id_u64(mut self, chain_id: u64) -> Self {
-        let mut id = [0u8; 32];
-        id[24..32].copy_from_slice(&chain_id.to_be_bytes());
-        self.chain_id = id;
+        self.chain_id = u128_to_bytes32(chain_id.into());
         self
     }
 
@@ -303,6 +595,13 @@ impl MockContextBuilder {
         self
     }
 
+    /// Set the hash of block `number`
+    /// Only the 256 blocks before the current one have a hash; others read as None
+    pub fn with_block_hash(mut self, number: i64, hash: [u8; 32]) -> Self {
+        self.block_hashes.insert(number, hash);
+        self
+    }
+
     /// Set block timestamp
     pub fn with_block_timestamp(mut self, timestamp: i64) -> Self {
         self.block_info.timestamp = timestamp;
@@ -326,12 +625,32 @@ impl MockContextBuilder {
         self
     }
 
+    /// Set the base fees of previous blocks, most recent (one block ago) first
+    /// Entries beyond `BASE_FEE_HISTORY_SIZE` are dropped
+    pub fn with_base_fee_history(mut self, history: Vec<[u8; 32]>) -> Self {
+        self.block_info.base_fee_history =
+            history.into_iter().take(BASE_FEE_HISTORY_SIZE).collect();
+        self
+    }
+
     /// Set blob base fee
     pub fn with_blob_base_fee(mut self, blob_base_fee: [u8; 32]) -> Self {
         self.block_info.blob_base_fee = blob_base_fee;
         self
     }
 
+    /// Set block difficulty (returned by DIFFICULTY before the Merge)
+    pub fn with_block_difficulty(mut self, difficulty: [u8; 32]) -> Self {
+        self.block_info.difficulty = difficulty;
+        self
+    }
+
+    /// Set the hardfork whose rules apply to the current block
+    pub fn with_hardfork(mut self, hardfork: Hardfork) -> Self {
+        self.hardfork = hardfork;
+        self
+    }
+
     /// Set block previous randao
     pub fn with_block_prev_randao(mut self, prev_randao: [u8; 32]) -> Self {
         self.block_info.prev_randao = prev_randao;
@@ -346,9 +665,32 @@ impl MockContextBuilder {
 
     /// Set gas price from u64 (in wei)
     pub fn with_gas_price_wei(mut self, wei: u64) -> Self {
-        let mut price = [0u8; 32];
-        price[24..32].copy_from_slice(&wei.to_be_bytes());
-        self.tx_info.gas_price = price;
+        self.tx_info.gas_price = u128_to_bytes32(wei.into());
+        self
+    }
+
+    /// Set call value from u128 (in wei)
+    pub fn with_call_value_wei(mut self, wei: u128) -> Self {
+        self.call_value = u128_to_bytes32(wei);
+        self
+    }
+
+    /// Reserve `stipend` gas for the host, hiding it from the gas left contracts observe
+    pub fn with_gas_stipend(mut self, stipend: i64) -> Self {
+        self.tx_info.gas_stipend = Some(stipend);
+        self
+    }
+
+    /// Clamp the gas price read by contracts into [min_wei, max_wei]
+    pub fn with_gas_price_bounds_wei(mut self, min_wei: u64, max_wei: u64) -> Self {
+        self.tx_info.min_gas_price = Some(u128_to_bytes32(min_wei.into()));
+        self.tx_info.max_gas_price = Some(u128_to_bytes32(max_wei.into()));
+        self
+    }
+
+    /// Set blob versioned hashes
+    pub fn with_blob_hashes(mut self, blob_hashes: Vec<[u8; 32]>) -> Self {
+        self.tx_info.blob_hashes = blob_hashes;
         self
     }
 
@@ -367,26 +709,197 @@ impl MockContextBuilder {
         self
     }
 
+    /// Register a contract at `address` when the context is built
+    /// The contract goes into the registry set by `with_contract_registry`, if any
+    pub fn with_contract(mut self, address: [u8; 20], name: &str, code: Vec<u8>) -> Self {
+        self.contracts
+            .push((address, ContractInfo::new(name.to_string(), code)));
+        self
+    }
+
+    /// Register several (address, name, code) contracts when the context is built
+    pub fn with_contracts<I, S>(mut self, contracts: I) -> Self
+    where
+        I: IntoIterator<Item = ([u8; 20], S, Vec<u8>)>,
+        S: Into<String>,
+    {
+        self.contracts.extend(
+            contracts
+                .into_iter()
+                .map(|(address, name, code)| (address, ContractInfo::new(name.into(), code))),
+        );
+        self
+    }
+
+    /// Set the behaviour for calls to unregistered addresses
+    pub fn with_contract_not_found_policy(mut self, policy: ContractNotFoundPolicy) -> Self {
+        self.contract_not_found_policy = policy;
+        self
+    }
+
+    /// Allow replacing deployed contract code via `set_contract_code`
+    /// Only intended for proxy/upgrade pattern tests; code is immutable by default
+    pub fn with_mutable_code(mut self, mutable: bool) -> Self {
+        self.mutable_code = mutable;
+        self
+    }
+
+    /// Hash with `keccak` instead of the default Keccak-256 implementation
+    /// Lets tests exercise an accelerated backend through the whole host
+    pub fn with_keccak(mut self, keccak: KeccakFn) -> Self {
+        self.keccak = Some(keccak);
+        self
+    }
+
+    /// Cap the data size of a single emitted log (defaults to `MAX_BUFFER_SIZE`)
+    pub fn with_max_log_data(mut self, max_log_data: usize) -> Self {
+        self.max_log_data = max_log_data;
+        self
+    }
+
+    /// Charge net-metered SSTORE gas and refunds (EIP-2200) with `costs`
+    /// Without it SSTORE costs no gas and only clearing a slot is refunded
+    pub fn with_gas_costs(mut self, costs: GasCosts) -> Self {
+        self.gas_costs = Some(costs);
+        self
+    }
+
+    /// Charge host function calls by `schedule` out of a separate budget of `gas`
+    /// Host functions fail with a gas error once the budget is exhausted
+    pub fn with_host_gas_schedule(mut self, schedule: HostGasSchedule, gas: u64) -> Self {
+        self.host_gas_schedule = Some(schedule);
+        self.tx_info.host_gas_left = Rc::new(Cell::new(gas));
+        self
+    }
+
+    /// Charge EVM memory-expansion gas in copy operations (disabled by default)
+    pub fn with_memory_expansion_gas(mut self, enabled: bool) -> Self {
+        self.memory_expansion_gas = enabled;
+        self
+    }
+
+    /// Charge EIP-2929 cold/warm account-access gas in calls (disabled by default)
+    pub fn with_account_access_gas(mut self, enabled: bool) -> Self {
+        self.account_access_gas = enabled;
+        self
+    }
+
+    /// Pre-warm accounts and storage slots from an EIP-2930 access list
+    /// Each entry is an address and the storage keys of that address to warm
+    pub fn with_access_list(mut self, access_list: AccessList) -> Self {
+        self.access_list.extend(access_list);
+        self
+    }
+
+    /// Write address results (caller, origin, ...) as 32-byte left-padded words
+    pub fn with_padded_address_results(mut self, enabled: bool) -> Self {
+        self.padded_address_results = enabled;
+        self
+    }
+
+    /// Load the initial state of a set of accounts (balance, nonce, code and storage)
+    /// Storage slots are written into the context's storage backend
+    pub fn with_genesis(mut self, genesis: HashMap<[u8; 20], AccountState>) -> Self {
+        self.genesis.extend(genesis);
+        self
+    }
+
+    /// Set the balance of an account (32-byte big-endian wei)
+    /// Overrides the balance loaded from the genesis state
+    pub fn with_balance(mut self, address: [u8; 20], balance: [u8; 32]) -> Self {
+        self.balances.insert(address, balance);
+        self
+    }
+
     /// Build the MockContext
     pub fn build(self) -> MockContext {
-        let storage = self
+        let storage: Rc<RefCell<dyn StorageBackend>> = self
             .storage
-            .unwrap_or_else(|| Rc::new(RefCell::new(HashMap::new())));
+            .unwrap_or_else(|| Rc::new(RefCell::new(HashMap::<String, Vec<u8>>::new())));
+
+        let mut nonces = HashMap::new();
+        let mut balances = HashMap::new();
+        for (address, account) in self.genesis {
+            balances.insert(address, account.balance);
+            if account.nonce > 0 {
+                nonces.insert(address, account.nonce);
+            }
+            if !account.code.is_empty() {
+                let name = format!("Genesis_Contract_0x{}", hex::encode(&address[16..20]));
+                self.contract_registry
+                    .borrow_mut()
+                    .insert(address, ContractInfo::new(name, account.code));
+            }
+            let mut storage = storage.borrow_mut();
+            for (key, value) in account.storage {
+                storage.set(&address, &key, &value);
+            }
+        }
+
+        self.contract_registry.borrow_mut().extend(self.contracts);
+
+        balances.extend(self.balances);
+
+        // The transaction origin, the called contract and the access list start warm (EIP-2929)
+        let mut warm_accounts = HashSet::from([self.tx_info.origin, self.address]);
+        let mut warm_storage_slots = HashSet::new();
+        for (address, keys) in self.access_list {
+            warm_accounts.insert(address);
+            warm_storage_slots.extend(keys.into_iter().map(|key| (address, key)));
+        }
 
         MockContext {
             contract_code: self.contract_code,
+            immutables: self.immutables,
             storage,
+            transient_storage: Rc::new(RefCell::new(HashMap::new())),
             call_data: self.call_data,
             address: self.address,
             caller: self.caller,
             call_value: self.call_value,
             chain_id: self.chain_id,
+            block_number: Rc::new(Cell::new(self.block_info.number)),
+            block_timestamp: Rc::new(Cell::new(self.block_info.timestamp)),
+            block_hashes: Rc::new(RefCell::new(self.block_hashes)),
             block_info: self.block_info,
             tx_info: self.tx_info,
             return_data: Rc::new(RefCell::new(Vec::new())),
             execution_status: Rc::new(RefCell::new(None)),
             events: Rc::new(RefCell::new(Vec::new())),
             contract_registry: self.contract_registry,
+            runtime_codes: Rc::new(RefCell::new(HashMap::new())),
+            destruct_amounts: Rc::new(RefCell::new(HashMap::new())),
+            destructed: Rc::new(RefCell::new(HashSet::new())),
+            created_contracts: Rc::new(RefCell::new(HashSet::new())),
+            memory_dumps: Rc::new(RefCell::new(Vec::new())),
+            code_hash_cache: Rc::new(RefCell::new(HashMap::new())),
+            code_hash_computations: Rc::new(Cell::new(0)),
+            last_create2_init_code_hash: Rc::new(Cell::new(None)),
+            contract_not_found_policy: self.contract_not_found_policy,
+            nonces: Rc::new(RefCell::new(nonces)),
+            static_mode: false,
+            mutable_code: self.mutable_code,
+            balances: Rc::new(RefCell::new(balances)),
+            mocked_calls: Rc::new(RefCell::new(HashMap::new())),
+            memory_expansion_gas: self.memory_expansion_gas,
+            memory_size: Cell::new(0),
+            gas_refund: Rc::new(Cell::new(0)),
+            gas_costs: self.gas_costs,
+            host_gas_schedule: self.host_gas_schedule,
+            original_storage: Rc::new(RefCell::new(HashMap::new())),
+            dirty_slots: Rc::new(RefCell::new(HashMap::new())),
+            account_access_gas: self.account_access_gas,
+            padded_address_results: self.padded_address_results,
+            warm_accounts: Rc::new(RefCell::new(warm_accounts)),
+            warm_storage_slots: Rc::new(RefCell::new(warm_storage_slots)),
+            hardfork: self.hardfork,
+            call_depth: 0,
+            max_call_depth: Rc::new(Cell::new(0)),
+            prank: Cell::new(None),
+            last_dispatch: RefCell::new(None),
+            last_call_error: Rc::new(RefCell::new(None)),
+            keccak: self.keccak,
+            max_log_data: self.max_log_data,
         }
     }
 }
@@ -431,27 +944,198 @@ impl MockContext {
         self.address = address;
     }
 
+    /// Set the code executed by this context
+    pub fn set_code(&mut self, code: Vec<u8>) {
+        self.contract_code = code;
+    }
+
     /// Set call value
     pub fn set_call_value(&mut self, value: [u8; 32]) {
         self.call_value = value;
     }
 
+    /// Call value (`msg.value`) as an integer
+    pub fn call_value_u256(&self) -> BigUint {
+        BigUint::from_bytes_be(&self.call_value)
+    }
+
+    /// Gas left for host function charges (see `with_host_gas_schedule`)
+    pub fn host_gas_left(&self) -> u64 {
+        self.tx_info.host_gas_left.get()
+    }
+
+    /// Reset the transaction's gas refund counter
+    pub fn clear_gas_refund(&self) {
+        self.gas_refund.set(0);
+    }
+
+    /// Call depth of this frame (0 for the top-level transaction)
+    pub fn get_call_depth(&self) -> usize {
+        self.call_depth
+    }
+
+    /// Deepest call depth reached by any frame so far
+    pub fn get_max_call_depth(&self) -> usize {
+        self.max_call_depth.get()
+    }
+
+    /// Why the most recently completed call from any frame failed
+    /// None if it succeeded, reverted without a reason, or no call was made
+    pub fn last_call_error(&self) -> Option<String> {
+        self.last_call_error.borrow().clone()
+    }
+
+    /// keccak256 of the init code (creation code ++ constructor arguments) of the
+    /// most recent CREATE2, so tests can recompute the derived address
+    pub fn last_create2_init_code_hash(&self) -> Option<[u8; 32]> {
+        self.last_create2_init_code_hash.get()
+    }
+
+    /// Entry point the contract last reported dispatching to via `recordDispatch`
+    /// Returns None if the contract has not reported one
+    pub fn last_dispatch(&self) -> Option<Dispatch> {
+        *self.last_dispatch.borrow()
+    }
+
+    /// Memory regions dumped by contracts via `dump_memory`, in dump order
+    pub fn memory_dumps(&self) -> Vec<(u32, Vec<u8>)> {
+        self.memory_dumps.borrow().clone()
+    }
+
+    /// Copy the contract storage so it can be rolled back with `restore`
+    pub fn snapshot(&self) -> StorageSnapshot {
+        StorageSnapshot {
+            entries: self.storage.borrow().entries(),
+        }
+    }
+
+    /// Roll the contract storage back to `snapshot`
+    pub fn restore(&self, snapshot: StorageSnapshot) {
+        self.storage.borrow_mut().restore_entries(snapshot.entries);
+    }
+
+    /// Discard all transient storage (done at the end of each top-level transaction)
+    pub fn clear_transient_storage(&self) {
+        self.transient_storage.borrow_mut().clear();
+    }
+
+    /// Forget the original slot values used by net gas metering
+    /// (done at the end of each top-level transaction)
+    pub fn clear_original_storage(&self) {
+        self.original_storage.borrow_mut().clear();
+    }
+
+    /// Forget which contracts the current transaction created
+    /// (done at the end of each top-level transaction)
+    pub fn clear_created_contracts(&self) {
+        self.created_contracts.borrow_mut().clear();
+    }
+
+    /// Storage slots changed by the current (or last) top-level call, sorted by key
+    /// Each entry is (key, value before the call, value after it); slots written back
+    /// to their original value are left out
+    pub fn storage_diff(&self) -> Vec<(String, [u8; 32], [u8; 32])> {
+        let to_word = |bytes: &[u8]| {
+            let mut word = [0u8; 32];
+            let len = bytes.len().min(32);
+            word[32 - len..].copy_from_slice(&bytes[bytes.len() - len..]);
+            word
+        };
+        let mut diff: Vec<_> = self
+            .dirty_slots
+            .borrow()
+            .iter()
+            .filter(|(_, (original, new))| original != new)
+            .map(|(key, (original, new))| (key.clone(), to_word(original), to_word(new)))
+            .collect();
+        diff.sort_by(|a, b| a.0.cmp(&b.0));
+        diff
+    }
+
+    /// Forget the storage slots recorded for `storage_diff`
+    /// (done at the start of each top-level call)
+    pub fn clear_storage_diff(&self) {
+        self.dirty_slots.borrow_mut().clear();
+    }
+
+    /// Forget the accounts and storage slots accessed so far, making them cold again
+    /// Only the transaction origin and the current contract stay warm
+    pub fn clear_warm_accounts(&self) {
+        let mut warm_accounts = self.warm_accounts.borrow_mut();
+        warm_accounts.clear();
+        warm_accounts.insert(self.tx_info.origin);
+        warm_accounts.insert(self.address);
+        self.warm_storage_slots.borrow_mut().clear();
+    }
+
+    /// Access list of the accounts and storage slots accessed so far (EIP-2930)
+    ///
+    /// Like `eth_createAccessList`, the transaction origin and precompiles are
+    /// left out, as is the current contract unless it accessed storage. Accounts
+    /// and their slots are sorted.
+    pub fn access_list(&self) -> AccessList {
+        let mut slots_by_account: BTreeMap<[u8; 20], Vec<[u8; 32]>> = self
+            .warm_accounts
+            .borrow()
+            .iter()
+            .map(|address| (*address, Vec::new()))
+            .collect();
+        for (address, key) in self.warm_storage_slots.borrow().iter() {
+            slots_by_account.entry(*address).or_default().push(*key);
+        }
+
+        slots_by_account
+            .into_iter()
+            .filter(|(address, keys)| {
+                *address != self.tx_info.origin
+                    && (*address != self.address || !keys.is_empty())
+                    && !is_precompile(address)
+            })
+            .map(|(address, mut keys)| {
+                keys.sort();
+                (address, keys)
+            })
+            .collect()
+    }
+
     /// Check if there is return data available
     pub fn has_return_data(&self) -> bool {
         !self.return_data.borrow().is_empty()
     }
 
+    /// Get all events emitted so far, in emission order
+    pub fn get_events(&self) -> Vec<LogEvent> {
+        self.events.borrow().clone()
+    }
+
+    /// Get the events whose first topic (usually the event signature hash) is `topic0`
+    pub fn events_matching_topic0(&self, topic0: &[u8; 32]) -> Vec<LogEvent> {
+        self.events
+            .borrow()
+            .iter()
+            .filter(|event| event.topics.first() == Some(topic0))
+            .cloned()
+            .collect()
+    }
+
     /// Clear all emitted events
     pub fn clear_events(&mut self) {
         self.events.borrow_mut().clear();
     }
 
+    /// Return all events emitted so far and clear them
+    pub fn take_events(&self) -> Vec<LogEvent> {
+        std::mem::take(&mut *self.events.borrow_mut())
+    }
+
     /// Register a contract at the given address
     pub fn register_contract(&mut self, address: [u8; 20], name: String, code: Vec<u8>) {
         let contract_info = ContractInfo::new(name.clone(), code);
         self.contract_registry
             .borrow_mut()
             .insert(address, contract_info);
+        self.code_hash_cache.borrow_mut().remove(&address);
+        self.destructed.borrow_mut().remove(&address);
     }
 
     /// Get contract info by address
@@ -459,56 +1143,322 @@ impl MockContext {
         self.contract_registry.borrow().get(address).cloned()
     }
 
+    /// Get the runtime code of a contract created by CREATE/CREATE2
+    /// This is the creation code without its 4-byte length header, as opposed to
+    /// the init code kept in the contract registry
+    pub fn get_runtime_code(&self, address: &[u8; 20]) -> Option<Vec<u8>> {
+        self.runtime_codes.borrow().get(address).cloned()
+    }
+
+    /// Get the amount a self-destructed contract transferred to its beneficiary
+    /// Returns None if the contract has not self-destructed
+    pub fn get_destruct_amount(&self, address: &[u8; 20]) -> Option<[u8; 32]> {
+        self.destruct_amounts.borrow().get(address).copied()
+    }
+
+    /// Whether the contract at `address` has self-destructed
+    pub fn is_destructed(&self, address: &[u8; 20]) -> bool {
+        self.destructed.borrow().contains(address)
+    }
+
+    /// Replace the code of a deployed contract
+    /// Fails unless the context was built with `with_mutable_code(true)`
+    pub fn set_contract_code(&self, address: &[u8; 20], code: Vec<u8>) -> Result<(), String> {
+        if !self.mutable_code {
+            return Err(format!(
+                "Contract code at 0x{} is immutable; use with_mutable_code to allow upgrades",
+                hex::encode(address)
+            ));
+        }
+
+        match self.contract_registry.borrow_mut().get_mut(address) {
+            Some(info) => {
+                info.code = code;
+                self.code_hash_cache.borrow_mut().remove(address);
+                Ok(())
+            }
+            None => Err(format!(
+                "No contract registered at 0x{}",
+                hex::encode(address)
+            )),
+        }
+    }
+
+    /// Get how many times contract code has been hashed for EXTCODEHASH
+    /// Cached lookups do not count, so repeated queries of unchanged code stay at one
+    pub fn code_hash_computations(&self) -> usize {
+        self.code_hash_computations.get()
+    }
+
+    /// Set the behaviour for calls to unregistered addresses
+    pub fn set_contract_not_found_policy(&mut self, policy: ContractNotFoundPolicy) {
+        self.contract_not_found_policy = policy;
+    }
+
+    /// Mock calls to `target` whose call data starts with `selector`
+    /// Matching calls return the canned (success, return data) response without executing code
+    pub fn mock_call(&mut self, target: [u8; 20], selector: [u8; 4], response: (bool, Vec<u8>)) {
+        self.mocked_calls
+            .borrow_mut()
+            .insert((target, selector), response);
+    }
+
+    /// Look up the canned result for a mocked call, if any
+    fn mocked_call_result(&self, target: &[u8; 20], data: &[u8]) -> Option<ContractCallResult> {
+        let selector: [u8; 4] = data.get(..4)?.try_into().ok()?;
+        let (success, return_data) = self
+            .mocked_calls
+            .borrow()
+            .get(&(*target, selector))
+            .cloned()?;
+
+        self.set_return_data(return_data.clone());
+        if success {
+            Some(ContractCallResult::success(return_data, 0))
+        } else {
+            Some(ContractCallResult::failure(return_data, 0))
+        }
+    }
+
+    /// Run the precompile at `target`, if any, charging its gas from `gas`
+    fn precompile_result(
+        &self,
+        target: &[u8; 20],
+        data: &[u8],
+        gas: i64,
+    ) -> Option<ContractCallResult> {
+        let result = run_precompile(target, data, gas)?;
+        self.set_return_data(result.return_data.clone());
+        Some(result)
+    }
+
+    /// Resolve the code and name to execute for a call target
+    /// Returns Ok(None) when the call should complete as an empty success
+    fn resolve_call_target(&self, target: &[u8; 20]) -> Result<Option<(Vec<u8>, String)>, String> {
+        if let Some(info) = self.get_contract_info(target) {
+            return Ok(Some((info.code, info.name)));
+        }
+
+        // A contract calling itself re-enters its own code even when only set via the builder
+        if *target == self.address {
+            return Ok(Some((
+                self.get_contract_code().to_vec(),
+                "Self".to_string(),
+            )));
+        }
+
+        match self.contract_not_found_policy {
+            ContractNotFoundPolicy::ReturnEmptySuccess => Ok(None),
+            ContractNotFoundPolicy::Fail => Err(format!(
+                "No contract registered at 0x{}",
+                hex::encode(target)
+            )),
+            ContractNotFoundPolicy::ExecuteCallerCode => Ok(Some((
+                self.get_contract_code().to_vec(),
+                "Unknown".to_string(),
+            ))),
+        }
+    }
+
+    /// Run the code of `frame.code_address` at `frame.target` in a new frame
+    ///
+    /// Shared by CALL, CALLCODE, DELEGATECALL and STATICCALL: a pending prank overrides
+    /// the caller, mocked calls and precompiles are served without running code, and
+    /// the reason a call failed is kept for `last_call_error`.
+    fn dispatch_call(&self, frame: CallFrame) -> ContractCallResult {
+        // A pending prank overrides the caller of this call only
+        let frame = CallFrame {
+            caller: self.prank.take().unwrap_or(frame.caller),
+            ..frame
+        };
+        // Called accounts are part of the transaction's access list
+        self.warm_accounts.borrow_mut().insert(frame.code_address);
+        self.last_call_error.replace(None);
+
+        // Mocked calls take precedence over registered contracts
+        if let Some(result) = self.mocked_call_result(&frame.code_address, frame.data) {
+            return result;
+        }
+        // Precompiles are served natively and charge their own gas
+        if let Some(result) = self.precompile_result(&frame.code_address, frame.data, frame.gas) {
+            return result;
+        }
+
+        let result = self
+            .resolve_call_target(&frame.code_address)
+            .and_then(|resolved| match resolved {
+                Some((code, contract_name)) => self
+                    .execute_contract_call(code, &contract_name, &frame)
+                    .map(Some),
+                // Calling an account without code succeeds with no return data
+                None => Ok(None),
+            });
+
+        match result {
+            Ok(Some(result)) => {
+                let gas_used = result.gas_used.max(0);
+                self.set_return_data(result.return_data.clone());
+                if result.success && !result.is_reverted {
+                    // Failures of calls made by the callee are superseded
+                    self.last_call_error.replace(None);
+                    ContractCallResult::success(result.return_data, gas_used)
+                } else {
+                    self.last_call_error.replace(result.error_message);
+                    ContractCallResult::failure(result.return_data, gas_used)
+                }
+            }
+            Ok(None) => {
+                self.set_return_data(vec![]);
+                ContractCallResult::success(vec![], 0)
+            }
+            Err(e) => {
+                self.last_call_error.replace(Some(e));
+                ContractCallResult::failure(vec![], frame.gas.min(21000))
+            }
+        }
+    }
+
+    /// Move `value` wei from `from` to `to`
+    /// Returns false (and moves nothing) if `from` cannot cover the value
+    fn transfer_value(&self, from: &[u8; 20], to: &[u8; 20], value: &[u8; 32]) -> bool {
+        if *value == [0u8; 32] {
+            return true;
+        }
+
+        let value = BigUint::from_bytes_be(value);
+        let from_balance = self.funded_balance(from);
+        if from_balance < value {
+            return false;
+        }
+        if from == to {
+            return true;
+        }
+
+        // A credit past 2^256 - 1 must fail rather than wrap the balance
+        let Ok(to_balance) = bigint_to_bytes32_checked(&(self.funded_balance(to) + &value)) else {
+            return false;
+        };
+        self.balances
+            .borrow_mut()
+            .insert(*from, bigint_to_bytes32(&(from_balance - value)));
+        self.balances.borrow_mut().insert(*to, to_balance);
+        true
+    }
+
+    /// Balance an account actually holds, 0 if it was never funded
+    /// Unlike `get_external_balance`, unknown accounts have no mock balance
+    fn funded_balance(&self, address: &[u8; 20]) -> BigUint {
+        self.balances
+            .borrow()
+            .get(address)
+            .map_or_else(BigUint::default, |balance| BigUint::from_bytes_be(balance))
+    }
+
+    /// Run `call` with `value` moved from `from` to `to`
+    /// Fails without running the call on insufficient balance, and moves the
+    /// value back if the call fails
+    fn with_value_transfer(
+        &self,
+        from: &[u8; 20],
+        to: &[u8; 20],
+        value: &[u8; 32],
+        call: impl FnOnce() -> ContractCallResult,
+    ) -> ContractCallResult {
+        if !self.transfer_value(from, to, value) {
+            return ContractCallResult::failure(vec![], 0);
+        }
+
+        let result = call();
+        if !result.success {
+            self.transfer_value(to, from, value);
+        }
+        result
+    }
+
+    /// Get the nonce of an account (0 if it never deployed a contract)
+    pub fn get_nonce(&self, address: &[u8; 20]) -> u64 {
+        self.nonces.borrow().get(address).copied().unwrap_or(0)
+    }
+
+    /// Set the nonce of an account, e.g. to deploy at a specific CREATE address
+    pub fn set_nonce(&self, address: &[u8; 20], nonce: u64) {
+        self.nonces.borrow_mut().insert(*address, nonce);
+    }
+
+    /// Increment the nonce of an account
+    fn increment_nonce(&self, address: &[u8; 20]) {
+        *self.nonces.borrow_mut().entry(*address).or_insert(0) += 1;
+    }
+
     /// Generate CREATE address according to Ethereum rules
     /// address = keccak256(rlp([sender, nonce]))[12:]
-    fn generate_create_address(&self, _sender: &[u8; 20], _nonce: u64) -> [u8; 20] {
-        //address
-        let mut addr = [0u8; 20];
-        addr[19] = 9; // Set the last byte to distinguish addresses
-        addr
+    fn generate_create_address(&self, sender: &[u8; 20], nonce: u64) -> [u8; 20] {
+        create_address(sender, nonce)
     }
 
     /// Generate CREATE2 address according to Ethereum rules
     /// address = keccak256(0xff ++ sender ++ salt ++ keccak256(init_code))[12:]
+    /// The init code hash is recorded for `last_create2_init_code_hash`
     fn generate_create2_address(
         &self,
-        _sender: &[u8; 20],
-        _salt: &[u8; 32],
-        _init_code: &[u8],
+        sender: &[u8; 20],
+        salt: &[u8; 32],
+        init_code: &[u8],
     ) -> [u8; 20] {
-        //address
-
-        let mut addr = [0u8; 20];
-        addr[19] = 99; // Set the last byte to distinguish addresses
-        addr
+        let init_code_hash = self.keccak256(init_code.to_vec());
+        self.last_create2_init_code_hash.set(Some(init_code_hash));
+        create2_address(sender, salt, &init_code_hash)
     }
 
     /// Execute a contract call using ContractExecutor
     fn execute_contract_call(
         &self,
         target_code: Vec<u8>,
-        call_data: Vec<u8>,
-        caller: [u8; 20],
-        target: [u8; 20],
-        value: [u8; 32],
         contract_name: &str,
+        frame: &CallFrame,
     ) -> Result<ContractExecutionResult, String> {
+        if self.call_depth >= MAX_CALL_DEPTH {
+            return Ok(call_depth_exceeded());
+        }
+
         // Create a new context for the contract call
         let mut call_context = self.clone();
 
         // Set up the call context
-        call_context.set_caller(caller);
-        call_context.set_address(target);
-        call_context.set_call_value(value);
-        call_context.set_call_data(call_data);
+        call_context.static_mode = frame.static_mode;
+        call_context.call_depth = self.call_depth + 1;
+        self.max_call_depth
+            .set(self.max_call_depth.get().max(call_context.call_depth));
+        call_context.prank.set(None);
+        call_context.last_dispatch.replace(None);
+        call_context.memory_size.set(0);
+        // A reverting callee must not mark the calling frame as reverted
+        call_context.execution_status = Rc::new(RefCell::new(None));
+        call_context.set_caller(frame.caller);
+        call_context.set_address(frame.target);
+        call_context.set_call_value(frame.value);
+        call_context.set_call_data(frame.data.to_vec());
         call_context.contract_code = target_code;
+        // Immutables belong to the top-level contract only
+        call_context.immutables.clear();
+        call_context.tx_info.gas_limit = frame.gas;
+
+        // Storage is shared with the callee, so keep a copy to undo its
+        // writes if it reverts
+        let snapshot = self.snapshot();
+        let dirty_slots = self.dirty_slots.borrow().clone();
 
         // Create a contract executor
         let executor = ContractExecutor::new()
             .map_err(|e| format!("Failed to create contract executor: {}", e))?;
 
         // Execute the contract call
-        executor.call_contract_function(contract_name, &mut call_context)
+        let result = executor.call_contract_function(contract_name, &mut call_context);
+        if !result.as_ref().is_ok_and(|result| result.success) {
+            self.restore(snapshot);
+            *self.dirty_slots.borrow_mut() = dirty_slots;
+        }
+        result
     }
 
     /// Execute a contract deployment using ContractExecutor
@@ -520,15 +1470,25 @@ impl MockContext {
         new_address: [u8; 20],
         value: [u8; 32],
     ) -> Result<ContractExecutionResult, String> {
+        if self.call_depth >= MAX_CALL_DEPTH {
+            return Ok(call_depth_exceeded());
+        }
+
         // Create a new context for the contract deployment
         let mut deploy_context = self.clone();
 
         // Set up the deployment context
+        deploy_context.call_depth = self.call_depth + 1;
+        self.max_call_depth
+            .set(self.max_call_depth.get().max(deploy_context.call_depth));
+        deploy_context.prank.set(None);
+        deploy_context.memory_size.set(0);
         deploy_context.set_caller(creator);
         deploy_context.set_address(new_address);
         deploy_context.set_call_value(value);
         deploy_context.set_call_data(data);
         deploy_context.contract_code = code[4..].to_vec();
+        deploy_context.immutables.clear();
 
         // Create a contract executor
         let executor = ContractExecutor::new()
@@ -543,6 +1503,8 @@ impl MockContext {
                     return_data: deploy_context.return_data_copy(),
                     error_message: None,
                     is_reverted: false,
+                    gas_left: 0,
+                    gas_used: 0,
                 })
             }
             Err(e) => {
@@ -552,6 +1514,8 @@ impl MockContext {
                     return_data: vec![],
                     error_message: Some(e),
                     is_reverted: false,
+                    gas_left: 0,
+                    gas_used: 0,
                 })
             }
         }
@@ -577,6 +1541,106 @@ impl EvmHost for MockContext {
         &self.address
     }
 
+    fn get_self_code_hash(&self) -> [u8; 32] {
+        // Contracts created by CREATE/CREATE2 hash their runtime code, not the registered init code
+        let runtime_code = self.runtime_codes.borrow().get(&self.address).cloned();
+        self.keccak256(runtime_code.unwrap_or_else(|| self.contract_code.clone()))
+    }
+
+    fn get_immutable(&self, index: u32) -> Option<[u8; 32]> {
+        self.immutables.get(index as usize).copied()
+    }
+
+    fn is_static(&self) -> bool {
+        self.static_mode
+    }
+
+    fn max_log_data(&self) -> usize {
+        self.max_log_data
+    }
+
+    fn keccak256_slice(&self, input_data: &[u8]) -> [u8; 32] {
+        match self.keccak {
+            Some(keccak) => keccak(input_data),
+            None => keccak256_digest(input_data),
+        }
+    }
+
+    fn add_gas_refund(&self, amount: u64) {
+        self.gas_refund.set(self.gas_refund.get() + amount);
+    }
+
+    fn remove_gas_refund(&self, amount: u64) {
+        self.gas_refund
+            .set(self.gas_refund.get().saturating_sub(amount));
+    }
+
+    fn get_gas_refund(&self) -> u64 {
+        self.gas_refund.get()
+    }
+
+    fn charge_host_gas(&self, name: &str) -> HostFunctionResult<()> {
+        let Some(schedule) = &self.host_gas_schedule else {
+            return Ok(());
+        };
+
+        let cost = schedule.cost(name);
+        let gas_left = self.tx_info.host_gas_left.get();
+        if gas_left < cost {
+            self.tx_info.host_gas_left.set(0);
+            return Err(gas_error(
+                "insufficient host gas",
+                name,
+                Some(cost as i64),
+                Some(gas_left as i64),
+            ));
+        }
+        self.tx_info.host_gas_left.set(gas_left - cost);
+        Ok(())
+    }
+
+    fn sstore_gas_costs(&self) -> Option<GasCosts> {
+        self.gas_costs
+    }
+
+    fn original_storage_value(&self, key: &[u8; 32]) -> [u8; 32] {
+        match self.original_storage.borrow().get(&(self.address, *key)) {
+            Some(value) => *value,
+            None => self.storage.borrow().get(&self.address, key),
+        }
+    }
+
+    fn pads_address_results(&self) -> bool {
+        self.padded_address_results
+    }
+
+    fn charges_account_access(&self) -> bool {
+        self.account_access_gas
+    }
+
+    fn mark_account_warm(&self, address: &[u8; 20]) -> bool {
+        self.warm_accounts.borrow_mut().insert(*address)
+    }
+
+    fn access_storage(&self, address: &[u8; 20], key: &[u8; 32]) -> bool {
+        !self
+            .warm_storage_slots
+            .borrow_mut()
+            .insert((*address, *key))
+    }
+
+    fn charges_memory_expansion(&self) -> bool {
+        self.memory_expansion_gas
+    }
+
+    fn get_memory_size(&self) -> u64 {
+        self.memory_size.get()
+    }
+
+    fn set_memory_size(&self, size: u64) {
+        self.memory_size.set(size);
+    }
+
     fn get_caller(&self) -> &[u8; 20] {
         &self.caller
     }
@@ -594,11 +1658,11 @@ impl EvmHost for MockContext {
     }
 
     fn get_block_number(&self) -> i64 {
-        self.block_info.number
+        self.block_number.get()
     }
 
     fn get_block_timestamp(&self) -> i64 {
-        self.block_info.timestamp
+        self.block_timestamp.get()
     }
 
     fn get_block_gas_limit(&self) -> i64 {
@@ -613,20 +1677,61 @@ impl EvmHost for MockContext {
         self.block_info.get_prev_randao()
     }
 
+    fn get_block_difficulty(&self) -> &[u8; 32] {
+        self.block_info.get_difficulty_bytes()
+    }
+
+    fn set_block_timestamp(&self, timestamp: i64) {
+        self.block_timestamp.set(timestamp);
+    }
+
+    fn set_block_number(&self, number: i64) {
+        self.block_number.set(number);
+    }
+
+    fn set_prank(&self, caller: &[u8; 20]) {
+        self.prank.set(Some(*caller));
+    }
+
+    fn set_balance(&self, address: &[u8; 20], balance: &[u8; 32]) {
+        self.balances.borrow_mut().insert(*address, *balance);
+    }
+
+    fn dump_memory(&self, offset: u32, data: &[u8]) {
+        self.memory_dumps.borrow_mut().push((offset, data.to_vec()));
+    }
+
+    fn record_dispatch(&self, dispatch: Dispatch) {
+        self.last_dispatch.replace(Some(dispatch));
+    }
+
+    fn get_hardfork(&self) -> Hardfork {
+        self.hardfork
+    }
+
     fn get_base_fee(&self) -> &[u8; 32] {
         self.block_info.get_base_fee_bytes()
     }
 
+    fn get_base_fee_at(&self, blocks_ago: u64) -> Option<[u8; 32]> {
+        self.block_info.get_base_fee_at(blocks_ago).copied()
+    }
+
     fn get_blob_base_fee(&self) -> &[u8; 32] {
         self.block_info.get_blob_base_fee_bytes()
     }
 
     fn get_tx_gas_price(&self) -> &[u8; 32] {
-        self.tx_info.get_gas_price_bytes()
+        self.tx_info.get_effective_gas_price_bytes()
+    }
+
+    fn get_blob_hashes(&self) -> &[[u8; 32]] {
+        self.tx_info.get_blob_hashes()
     }
 
     fn get_gas_left(&self, gas_left: i64) -> i64 {
-        gas_left
+        let stipend = self.tx_info.gas_stipend.unwrap_or(0);
+        gas_left.saturating_sub(stipend).max(0)
     }
 
     fn call_data_copy(&self) -> &[u8] {
@@ -660,72 +1765,166 @@ impl EvmHost for MockContext {
     }
 
     fn storage_store(&self, key: &[u8; 32], value: &[u8; 32]) {
-        let key_hex = format!("0x{}", hex::encode(key));
-
-        self.storage.borrow_mut().insert(key_hex, value.to_vec());
+        self.warm_storage_slots
+            .borrow_mut()
+            .insert((self.address, *key));
+        // Remember the value the slot had before its first write in this transaction
+        self.original_storage
+            .borrow_mut()
+            .entry((self.address, *key))
+            .or_insert_with(|| self.storage.borrow().get(&self.address, key));
+        self.dirty_slots
+            .borrow_mut()
+            .entry(format!("0x{}", hex::encode(key)))
+            .or_insert_with(|| {
+                (
+                    self.storage.borrow().get(&self.address, key).to_vec(),
+                    vec![],
+                )
+            })
+            .1 = value.to_vec();
+        self.storage.borrow_mut().set(&self.address, key, value);
     }
 
     fn storage_load(&self, key: &[u8; 32]) -> [u8; 32] {
-        let key_hex = format!("0x{}", hex::encode(key));
+        self.warm_storage_slots
+            .borrow_mut()
+            .insert((self.address, *key));
+        self.storage.borrow().get(&self.address, key)
+    }
+
+    fn transient_store(&self, key: &[u8; 32], value: &[u8; 32]) {
+        self.transient_storage
+            .borrow_mut()
+            .insert((self.address, *key), *value);
+    }
 
-        let storage = self.storage.borrow();
+    fn transient_load(&self, key: &[u8; 32]) -> [u8; 32] {
+        self.transient_storage
+            .borrow()
+            .get(&(self.address, *key))
+            .copied()
+            .unwrap_or([0u8; 32])
+    }
 
-        let value = match storage.get(&key_hex) {
-            Some(value) => value.clone(),
-            None => {
-                vec![0u8; 32]
-            }
+    /// Self-destruct the current contract and transfer balance to recipient
+    /// Since Cancun (EIP-6780) the contract is only deleted if the current
+    /// transaction created it; otherwise it just sends its balance away
+    fn self_destruct(&self, recipient: &[u8; 20]) -> [u8; 32] {
+        let contract_address = *self.get_address();
+        let contract_balance = bigint_to_bytes32(&self.funded_balance(&contract_address));
+
+        // The credit fails if it would overflow the recipient's balance; then
+        // nothing is sent and the contract keeps its balance
+        let transferred = self.transfer_value(&contract_address, recipient, &contract_balance);
+        let credited = if transferred {
+            contract_balance
+        } else {
+            [0u8; 32]
         };
+        self.destruct_amounts
+            .borrow_mut()
+            .insert(contract_address, credited);
 
-        let mut result = [0u8; 32];
-        let copy_len = std::cmp::min(value.len(), 32);
-        result[..copy_len].copy_from_slice(&value[..copy_len]);
-        result
+        let deletes_contract = self.hardfork < Hardfork::Cancun
+            || self.created_contracts.borrow().contains(&contract_address);
+        if !deletes_contract {
+            return credited;
+        }
+
+        // The contract is removed with its code and storage, and keeps no
+        // balance unless the transfer failed
+        if transferred {
+            self.balances
+                .borrow_mut()
+                .insert(contract_address, [0u8; 32]);
+        }
+        self.destructed.borrow_mut().insert(contract_address);
+        self.contract_registry
+            .borrow_mut()
+            .remove(&contract_address);
+        self.runtime_codes.borrow_mut().remove(&contract_address);
+        self.code_hash_cache.borrow_mut().remove(&contract_address);
+        self.storage.borrow_mut().clear_account(&contract_address);
+
+        credited
     }
+    fn get_external_balance(&self, address: &[u8; 20]) -> [u8; 32] {
+        if let Some(balance) = self.balances.borrow().get(address) {
+            return *balance;
+        }
 
-    /// Self-destruct the current contract and transfer balance to recipient
-    fn self_destruct(&self, _recipient: &[u8; 20]) -> [u8; 32] {
-        // Get the current contract's balance using AccountBalanceProvider
-        let contract_address = self.get_address();
-        let contract_balance = self.get_external_balance(contract_address);
-
-        // In a real implementation, this would:
-        // 1. Transfer the balance to the recipient
-        // 2. Mark the contract as destructed
-        // 3. Clear the contract's storage
-        // 4. Remove the contract code
-
-        // For now, we just return the transferred amount
-        contract_balance
-    }
-    fn get_external_balance(&self, _address: &[u8; 20]) -> [u8; 32] {
-        // Return a mock balance (1000 ETH in wei)
+        // Accounts that were never funded report a mock balance of 1000 wei
         let mut balance = [0u8; 32];
         balance[24..32].copy_from_slice(&1000u64.to_be_bytes());
         balance
     }
 
-    fn get_block_hash(&self, _block_number: i64) -> Option<[u8; 32]> {
-        // Return a mock block hash
+    fn get_block_hash(&self, block_number: i64) -> Option<[u8; 32]> {
+        if !is_block_hash_available(block_number, self.block_number.get()) {
+            return None;
+        }
+
+        if let Some(hash) = self.block_hashes.borrow().get(&block_number) {
+            return Some(*hash);
+        }
+
+        // Return a mock block hash for blocks without a known hash
         let mut hash = [0u8; 32];
         hash[0] = 0xab;
         hash[31] = 0xcd;
         Some(hash)
     }
-    fn get_external_code_size(&self, _address: &[u8; 20]) -> Option<i32> {
+    fn get_external_code_size(&self, address: &[u8; 20]) -> Option<i32> {
+        // Self-destructed contracts have no code
+        if self.is_destructed(address) {
+            return Some(0);
+        }
+
         // Return mock code size
         Some(100)
     }
 
-    fn get_external_code_hash(&self, _address: &[u8; 20]) -> Option<[u8; 32]> {
-        // Return mock code hash
-        let mut hash = [0u8; 32];
-        hash[0] = 0xde;
-        hash[31] = 0xad;
-        Some(hash)
+    fn get_external_code_hash(&self, address: &[u8; 20]) -> Option<[u8; 32]> {
+        // Self-destructed accounts no longer exist
+        if self.is_destructed(address) {
+            return None;
+        }
+
+        if let Some(hash) = self.code_hash_cache.borrow().get(address) {
+            return Some(*hash);
+        }
+
+        // Registered contracts hash their code once; the cache is dropped when the code changes
+        if let Some(info) = self.contract_registry.borrow().get(address) {
+            if info.code.is_empty() {
+                return Some(EMPTY_CODE_HASH);
+            }
+            let hash = self.keccak256(info.code.clone());
+            self.code_hash_computations
+                .set(self.code_hash_computations.get() + 1);
+            self.code_hash_cache.borrow_mut().insert(*address, hash);
+            return Some(hash);
+        }
+
+        // Accounts holding a balance or nonce exist without code (EIP-1052)
+        let has_balance = self
+            .balances
+            .borrow()
+            .get(address)
+            .is_some_and(|balance| balance.iter().any(|&b| b != 0));
+        if has_balance || self.get_nonce(address) > 0 {
+            return Some(EMPTY_CODE_HASH);
+        }
+
+        None
     }
 
-    fn external_code_copy(&self, _address: &[u8; 20]) -> Option<Vec<u8>> {
+    fn external_code_copy(&self, address: &[u8; 20]) -> Option<Vec<u8>> {
+        if self.is_destructed(address) {
+            return Some(vec![]);
+        }
+
         // Return mock code
         Some(vec![0x60, 0x00, 0x60, 0x00, 0xf3]) // Simple mock bytecode
     }
@@ -738,35 +1937,18 @@ impl EvmHost for MockContext {
         data: &[u8],
         gas: i64,
     ) -> ContractCallResult {
-        // Get target contract code from registry
-        let (target_code, contract_name) = match self.get_contract_info(target) {
-            Some(info) => (info.code, info.name),
-            None => {
-                let current_code = self.get_contract_code();
-                (current_code.to_vec(), "Unknown".to_string())
-            }
-        };
-
-        // Execute the contract call
-        match self.execute_contract_call(
-            target_code,
-            data.to_vec(),
-            *caller,
-            *target,
-            *value,
-            &contract_name,
-        ) {
-            Ok(result) => {
-                let gas_used = gas.min(50000); // Mock gas consumption
-                self.set_return_data(result.return_data.clone());
-                if result.success && !result.is_reverted {
-                    ContractCallResult::success(result.return_data, gas_used)
-                } else {
-                    ContractCallResult::failure(result.return_data, gas_used)
-                }
-            }
-            Err(_e) => ContractCallResult::failure(vec![], gas.min(21000)),
-        }
+        // The calling contract pays the value up front and is refunded if the call fails
+        self.with_value_transfer(&self.address, target, value, || {
+            self.dispatch_call(CallFrame {
+                target: *target,
+                code_address: *target,
+                caller: *caller,
+                value: *value,
+                data,
+                gas,
+                static_mode: self.static_mode,
+            })
+        })
     }
 
     fn call_code(
@@ -777,33 +1959,19 @@ impl EvmHost for MockContext {
         data: &[u8],
         gas: i64,
     ) -> ContractCallResult {
-        // CALLCODE: Execute target's code but in current contract's context
-        // Use target's code but keep current address and storage
-        let (target_code, contract_name) = match self.get_contract_info(target) {
-            Some(info) => (info.code, info.name),
-            None => (self.get_contract_code().to_vec(), "Unknown".to_string()),
-        };
-        let current_address = self.get_address(); // Keep current address
-
-        match self.execute_contract_call(
-            target_code,
-            data.to_vec(),
-            *caller,
-            *current_address,
-            *value,
-            &contract_name,
-        ) {
-            Ok(result) => {
-                let gas_used = gas.min(50000);
-                self.set_return_data(result.return_data.clone());
-                if result.success && !result.is_reverted {
-                    ContractCallResult::success(result.return_data, gas_used)
-                } else {
-                    ContractCallResult::failure(result.return_data, gas_used)
-                }
-            }
-            Err(_e) => ContractCallResult::failure(vec![], gas.min(21000)),
-        }
+        // The value stays with the calling contract, but its balance must cover it
+        self.with_value_transfer(&self.address, &self.address, value, || {
+            // CALLCODE: Execute target's code but in current contract's context
+            self.dispatch_call(CallFrame {
+                target: self.address,
+                code_address: *target,
+                caller: *caller,
+                value: *value,
+                data,
+                gas,
+                static_mode: self.static_mode,
+            })
+        })
     }
 
     fn call_delegate(
@@ -814,33 +1982,16 @@ impl EvmHost for MockContext {
         gas: i64,
     ) -> ContractCallResult {
         // DELEGATECALL: Execute target's code in current contract's full context
-        // Use target's code but keep current address, caller, and value
-        let (target_code, contract_name) = match self.get_contract_info(target) {
-            Some(info) => (info.code, info.name),
-            None => (self.get_contract_code().to_vec(), "Unknown".to_string()),
-        };
-        let current_address = self.get_address(); // Keep current address
-        let current_value = self.get_call_value(); // Keep current value
-
-        match self.execute_contract_call(
-            target_code,
-            data.to_vec(),
-            *caller,
-            *current_address,
-            *current_value,
-            &contract_name,
-        ) {
-            Ok(result) => {
-                let gas_used = gas.min(50000);
-                self.set_return_data(result.return_data.clone());
-                if result.success && !result.is_reverted {
-                    ContractCallResult::success(result.return_data, gas_used)
-                } else {
-                    ContractCallResult::failure(result.return_data, gas_used)
-                }
-            }
-            Err(_e) => ContractCallResult::failure(vec![], gas.min(21000)),
-        }
+        // Keep current address and value; the host passes this frame's msg.sender
+        self.dispatch_call(CallFrame {
+            target: self.address,
+            code_address: *target,
+            caller: *caller,
+            value: self.call_value,
+            data,
+            gas,
+            static_mode: self.static_mode,
+        })
     }
 
     fn call_static(
@@ -850,32 +2001,17 @@ impl EvmHost for MockContext {
         data: &[u8],
         gas: i64,
     ) -> ContractCallResult {
-        // STATICCALL: Execute target's code but prevent state changes
-        let (target_code, contract_name) = match self.get_contract_info(target) {
-            Some(info) => (info.code, info.name),
-            None => (self.get_contract_code().to_vec(), "Unknown".to_string()),
-        };
-        let zero_value = [0u8; 32]; // No value transfer in static calls
-
-        match self.execute_contract_call(
-            target_code,
-            data.to_vec(),
-            *caller,
-            *target,
-            zero_value,
-            &contract_name,
-        ) {
-            Ok(result) => {
-                let gas_used = gas.min(50000);
-                self.set_return_data(result.return_data.clone());
-                if result.success && !result.is_reverted {
-                    ContractCallResult::success(result.return_data, gas_used)
-                } else {
-                    ContractCallResult::failure(result.return_data, gas_used)
-                }
-            }
-            Err(_e) => ContractCallResult::failure(vec![], gas.min(21000)),
-        }
+        // STATICCALL: Execute target's code in a static frame that rejects state changes
+        // No value is transferred in static calls
+        self.dispatch_call(CallFrame {
+            target: *target,
+            code_address: *target,
+            caller: *caller,
+            value: [0u8; 32],
+            data,
+            gas,
+            static_mode: true,
+        })
     }
 
     fn create_contract(
@@ -891,12 +2027,13 @@ impl EvmHost for MockContext {
         // Generate contract address according to Ethereum rules
         let new_address = if is_create2 {
             // CREATE2 address generation: keccak256(0xff ++ creator ++ salt ++ keccak256(init_code))[12:]
+            // The init code is the creation code followed by the constructor arguments
             let salt_bytes = salt.unwrap_or([0u8; 32]);
-            self.generate_create2_address(creator, &salt_bytes, code)
+            self.generate_create2_address(creator, &salt_bytes, &[code, data].concat())
         } else {
             // CREATE address generation: keccak256(rlp([sender, nonce]))[12:]
-            // For simplicity, we'll use a mock nonce based on current context
-            self.generate_create_address(creator, 0)
+            // The creator's current (pre-increment) nonce is used
+            self.generate_create_address(creator, self.get_nonce(creator))
         };
 
         // Simulate gas consumption based on code size
@@ -907,14 +2044,12 @@ impl EvmHost for MockContext {
             return ContractCreateResult::failure(vec![], gas_used);
         }
 
-        // Check value transfer (simplified)
-        let value_amount = u64::from_be_bytes([
-            value[24], value[25], value[26], value[27], value[28], value[29], value[30], value[31],
-        ]);
-
-        if value_amount > 0 {
-            // In a real implementation, we would check balance and transfer value
+        // The endowment moves to the new contract before its constructor runs
+        if !self.transfer_value(creator, &new_address, value) {
+            return ContractCreateResult::failure(vec![], gas_used);
         }
+        // Even its constructor may delete it with SELFDESTRUCT (EIP-6780)
+        self.created_contracts.borrow_mut().insert(new_address);
 
         // Execute constructor if data is provided
         let return_data = if !data.is_empty() {
@@ -931,10 +2066,12 @@ impl EvmHost for MockContext {
                     if result.success {
                         result.return_data
                     } else {
+                        self.transfer_value(&new_address, creator, value);
                         return ContractCreateResult::failure(result.return_data, gas_used);
                     }
                 }
                 Err(_e) => {
+                    self.transfer_value(&new_address, creator, value);
                     return ContractCreateResult::failure(vec![], gas_used);
                 }
             }
@@ -952,10 +2089,29 @@ impl EvmHost for MockContext {
         // Clone self to get mutable access for registration
         let mut mutable_self = self.clone();
         mutable_self.register_contract(new_address, contract_name, code.to_vec());
+        self.runtime_codes
+            .borrow_mut()
+            .insert(new_address, code.get(4..).unwrap_or_default().to_vec());
+        if !is_create2 {
+            self.increment_nonce(creator);
+        }
         ContractCreateResult::success(new_address, return_data, gas_used)
     }
 }
 
+/// Result of a call or create attempted beyond `MAX_CALL_DEPTH`, which fails
+/// without running any code
+fn call_depth_exceeded() -> ContractExecutionResult {
+    ContractExecutionResult {
+        success: false,
+        return_data: vec![],
+        error_message: Some("Call depth limit exceeded".to_string()),
+        is_reverted: false,
+        gas_left: 0,
+        gas_used: 0,
+    }
+}
+
 // Implement AsRef<MockContext> for MockContext to support the host functions API
 impl AsRef<MockContext> for MockContext {
     fn as_ref(&self) -> &MockContext {
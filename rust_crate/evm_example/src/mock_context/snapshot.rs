@@ -0,0 +1,261 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON snapshots of the persistent MockContext state
+//!
+//! A snapshot captures storage, nonces, balances, the contract registry and emitted events so
+//! that blockchain state fixtures can be saved and loaded back into a fresh
+//! context. Binary values (addresses, code, topics, data) are hex encoded.
+
+use super::{ContractInfo, MockContext};
+use dtvmcore_rust::LogEvent;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+/// Registered contract in a snapshot
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractSnapshot {
+    pub name: String,
+    /// Contract code as 0x-prefixed hex
+    pub code: String,
+}
+
+/// Emitted event in a snapshot
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSnapshot {
+    /// Emitting contract address as 0x-prefixed hex
+    pub address: String,
+    /// Event topics as 0x-prefixed hex
+    pub topics: Vec<String>,
+    /// Event data as 0x-prefixed hex
+    pub data: String,
+}
+
+/// Persistent state of a MockContext
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextSnapshot {
+    /// Storage mapping (hex key -> hex value)
+    pub storage: BTreeMap<String, String>,
+    /// Account nonces (hex address -> nonce)
+    #[serde(default)]
+    pub nonces: BTreeMap<String, u64>,
+    /// Account balances (hex address -> hex balance)
+    #[serde(default)]
+    pub balances: BTreeMap<String, String>,
+    /// Contract registry (hex address -> contract)
+    pub contracts: BTreeMap<String, ContractSnapshot>,
+    /// Events in emission order
+    pub events: Vec<EventSnapshot>,
+}
+
+impl ContextSnapshot {
+    /// Serialize the snapshot to a pretty-printed JSON document
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize snapshot: {}", e))
+    }
+
+    /// Parse a snapshot from a JSON document
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse snapshot: {}", e))
+    }
+}
+
+fn encode_hex(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(data))
+}
+
+fn decode_hex(value: &str, what: &str) -> Result<Vec<u8>, String> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid hex for {} '{}': {}", what, value, e))
+}
+
+fn decode_fixed<const N: usize>(value: &str, what: &str) -> Result<[u8; N], String> {
+    let bytes = decode_hex(value, what)?;
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        format!(
+            "Invalid {} length: expected {} bytes, got {}",
+            what,
+            N,
+            bytes.len()
+        )
+    })
+}
+
+impl MockContext {
+    /// Capture the persistent state of this context
+    pub fn context_snapshot(&self) -> ContextSnapshot {
+        let storage = self
+            .storage
+            .borrow()
+            .entries()
+            .into_iter()
+            .map(|(key, value)| (key, encode_hex(&value)))
+            .collect();
+
+        let nonces = self
+            .nonces
+            .borrow()
+            .iter()
+            .map(|(address, nonce)| (encode_hex(address), *nonce))
+            .collect();
+
+        let balances = self
+            .balances
+            .borrow()
+            .iter()
+            .map(|(address, balance)| (encode_hex(address), encode_hex(balance)))
+            .collect();
+
+        let contracts = self
+            .contract_registry
+            .borrow()
+            .iter()
+            .map(|(address, info)| {
+                (
+                    encode_hex(address),
+                    ContractSnapshot {
+                        name: info.name.clone(),
+                        code: encode_hex(&info.code),
+                    },
+                )
+            })
+            .collect();
+
+        let events = self
+            .events
+            .borrow()
+            .iter()
+            .map(|event| EventSnapshot {
+                address: encode_hex(&event.contract_address),
+                topics: event.topics.iter().map(|t| encode_hex(t)).collect(),
+                data: encode_hex(&event.data),
+            })
+            .collect();
+
+        ContextSnapshot {
+            storage,
+            nonces,
+            balances,
+            contracts,
+            events,
+        }
+    }
+
+    /// Serialize the persistent state of this context to JSON
+    pub fn to_snapshot_json(&self) -> Result<String, String> {
+        self.context_snapshot().to_json()
+    }
+
+    /// Reconstruct a context from a snapshot
+    /// Non-persistent fields (call data, block and transaction info) use builder defaults
+    pub fn from_snapshot(snapshot: &ContextSnapshot) -> Result<Self, String> {
+        let mut storage = HashMap::new();
+        for (key, value) in &snapshot.storage {
+            storage.insert(key.clone(), decode_hex(value, "storage value")?);
+        }
+
+        let mut nonces = HashMap::new();
+        for (address, nonce) in &snapshot.nonces {
+            nonces.insert(decode_fixed::<20>(address, "nonce address")?, *nonce);
+        }
+
+        let mut balances = HashMap::new();
+        for (address, balance) in &snapshot.balances {
+            balances.insert(
+                decode_fixed::<20>(address, "balance address")?,
+                decode_fixed::<32>(balance, "balance")?,
+            );
+        }
+
+        let mut registry = HashMap::new();
+        for (address, contract) in &snapshot.contracts {
+            registry.insert(
+                decode_fixed::<20>(address, "contract address")?,
+                ContractInfo::new(
+                    contract.name.clone(),
+                    decode_hex(&contract.code, "contract code")?,
+                ),
+            );
+        }
+
+        let mut events = Vec::with_capacity(snapshot.events.len());
+        for event in &snapshot.events {
+            let topics = event
+                .topics
+                .iter()
+                .map(|topic| decode_fixed::<32>(topic, "event topic"))
+                .collect::<Result<Vec<_>, _>>()?;
+            events.push(LogEvent {
+                contract_address: decode_fixed::<20>(&event.address, "event address")?,
+                data: decode_hex(&event.data, "event data")?,
+                topics,
+            });
+        }
+
+        let context = MockContext::builder()
+            .with_storage(Rc::new(RefCell::new(storage)))
+            .with_contract_registry(Rc::new(RefCell::new(registry)))
+            .build();
+        *context.nonces.borrow_mut() = nonces;
+        *context.balances.borrow_mut() = balances;
+        *context.events.borrow_mut() = events;
+
+        Ok(context)
+    }
+
+    /// Reconstruct a context from a JSON snapshot document
+    pub fn from_snapshot_json(json: &str) -> Result<Self, String> {
+        Self::from_snapshot(&ContextSnapshot::from_json(json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dtvmcore_rust::evm::traits::EvmHost;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut context = MockContext::builder().with_address([0x42; 20]).build();
+
+        let mut value = [0u8; 32];
+        value[31] = 7;
+        context.storage_store(&[1u8; 32], &value);
+        context.storage_store(&[2u8; 32], &[0xffu8; 32]);
+        context.register_contract([0x10; 20], "Target".to_string(), vec![0x00, 0x61, 0x73]);
+        context.create_contract(&[0x42; 20], &[0u8; 32], &[0x01], &[], 0, None, false);
+        context.emit_log_event(LogEvent {
+            contract_address: [0x42; 20],
+            data: vec![0xaa, 0xbb],
+            topics: vec![[0x11; 32], [0x22; 32]],
+        });
+
+        let json = context
+            .to_snapshot_json()
+            .expect("Failed to serialize snapshot");
+        let restored = MockContext::from_snapshot_json(&json).expect("Failed to load snapshot");
+
+        assert_eq!(restored.context_snapshot(), context.context_snapshot());
+        assert_eq!(restored.storage_load(&[1u8; 32]), value);
+        assert_eq!(restored.get_nonce(&[0x42; 20]), 1);
+        assert_eq!(
+            restored
+                .get_contract_info(&[0x10; 20])
+                .map(|info| info.code),
+            Some(vec![0x00, 0x61, 0x73])
+        );
+    }
+
+    #[test]
+    fn test_snapshot_rejects_invalid_hex() {
+        let mut snapshot = ContextSnapshot::default();
+        snapshot
+            .storage
+            .insert("0x01".to_string(), "not hex".to_string());
+
+        assert!(MockContext::from_snapshot(&snapshot).is_err());
+    }
+}
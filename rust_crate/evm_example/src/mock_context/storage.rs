@@ -0,0 +1,72 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pluggable contract storage for MockContext
+//!
+//! MockContext reads and writes contract storage through the `StorageBackend`
+//! trait, so storage can be backed by a database, a merkle store or an
+//! instrumented wrapper. The default backend is an in-memory map.
+
+use std::collections::HashMap;
+
+/// Contract storage used by MockContext
+pub trait StorageBackend {
+    /// Load the value of `key` in the storage of `address`
+    /// Unset slots read as zero
+    fn get(&self, address: &[u8; 20], key: &[u8; 32]) -> [u8; 32];
+
+    /// Store `value` at `key` in the storage of `address`
+    fn set(&mut self, address: &[u8; 20], key: &[u8; 32], value: &[u8; 32]);
+
+    /// All stored slots as a hex key -> value map, used by snapshots
+    /// Backends that cannot enumerate their contents return an empty map
+    fn entries(&self) -> HashMap<String, Vec<u8>> {
+        HashMap::new()
+    }
+
+    /// Remove all storage of `address`, as SELFDESTRUCT does
+    /// Does nothing by default, which keeps the storage of backends that are
+    /// not namespaced by address, such as the shared HashMap
+    fn clear_account(&mut self, _address: &[u8; 20]) {}
+
+    /// Replace all stored slots with `entries`, as returned by `entries`
+    /// Used to roll back reverted calls; backends that cannot enumerate their
+    /// contents cannot be rolled back and ignore it
+    fn restore_entries(&mut self, _entries: HashMap<String, Vec<u8>>) {}
+}
+
+/// Copy of the contract storage taken by `MockContext::snapshot`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageSnapshot {
+    /// Stored slots (hex key -> value)
+    pub(super) entries: HashMap<String, Vec<u8>>,
+}
+
+/// Default in-memory backend (hex key -> 32-byte value)
+/// Keys are not namespaced by address: all contracts share one storage map
+impl StorageBackend for HashMap<String, Vec<u8>> {
+    fn get(&self, _address: &[u8; 20], key: &[u8; 32]) -> [u8; 32] {
+        let key_hex = format!("0x{}", hex::encode(key));
+
+        let mut result = [0u8; 32];
+        if let Some(value) = HashMap::get(self, &key_hex) {
+            let copy_len = std::cmp::min(value.len(), 32);
+            result[..copy_len].copy_from_slice(&value[..copy_len]);
+        }
+        result
+    }
+
+    fn set(&mut self, _address: &[u8; 20], key: &[u8; 32], value: &[u8; 32]) {
+        let key_hex = format!("0x{}", hex::encode(key));
+
+        self.insert(key_hex, value.to_vec());
+    }
+
+    fn entries(&self) -> HashMap<String, Vec<u8>> {
+        self.clone()
+    }
+
+    fn restore_entries(&mut self, entries: HashMap<String, Vec<u8>>) {
+        *self = entries;
+    }
+}
@@ -20,17 +20,47 @@ pub type MockInstance = ZenInstance<MockContext>;
 extern "C" fn storage_store(wasm_inst: *mut ZenInstanceExtern, key_offset: i32, value_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::storage::storage_store(inst, key_offset, value_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::storage::storage_store(inst, key_offset, value_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn storage_load(wasm_inst: *mut ZenInstanceExtern, key_offset: i32, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::storage::storage_load(inst, key_offset, result_offset){
-        inst.set_exception_by_hostapi(9);
+    if let Err(err) = dtvmcore_rust::evm::host_functions::storage::storage_load(inst, key_offset, result_offset){
+        err.raise(inst);
+    }
+}
+
+extern "C" fn transient_store(wasm_inst: *mut ZenInstanceExtern, key_offset: i32, value_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::storage::transient_store(inst, key_offset, value_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn transient_load(wasm_inst: *mut ZenInstanceExtern, key_offset: i32, result_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::storage::transient_load(inst, key_offset, result_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn access_storage(wasm_inst: *mut ZenInstanceExtern, key_offset: i32) -> i32 {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    match dtvmcore_rust::evm::host_functions::storage::access_storage(inst, key_offset) {
+        Ok(warm) => warm,
+        Err(err) => {
+            err.raise(inst);
+            0
+        }
     }
 }
 
@@ -41,45 +71,62 @@ extern "C" fn storage_load(wasm_inst: *mut ZenInstanceExtern, key_offset: i32, r
 extern "C" fn get_address(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::account::get_address(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::get_address(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_caller(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::account::get_caller(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::get_caller(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_call_value(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::account::get_call_value(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::get_call_value(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_chain_id(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::account::get_chain_id(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::get_chain_id(inst, result_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn verify_chain_id(
+    wasm_inst: *mut ZenInstanceExtern,
+    expected: i64,
+    result_offset: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::verify_chain_id(
+        inst,
+        expected,
+        result_offset,
+    )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_tx_origin(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::account::get_tx_origin(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::get_tx_origin(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -90,13 +137,34 @@ extern "C" fn get_external_balance(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::account::get_external_balance(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::get_external_balance(
         inst,
         addr_offset,
         result_offset,
     )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
+    }
+}
+
+extern "C" fn get_self_balance(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::account::get_self_balance(inst, result_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn access_account(wasm_inst: *mut ZenInstanceExtern, addr_offset: i32) -> i32 {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    match dtvmcore_rust::evm::host_functions::account::access_account(inst, addr_offset) {
+        Ok(warm) => warm,
+        Err(err) => {
+            err.raise(inst);
+            0
+        }
     }
 }
 
@@ -125,62 +193,93 @@ extern "C" fn get_block_gas_limit(wasm_inst: *mut ZenInstanceExtern) -> i64 {
 extern "C" fn get_block_coinbase(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::block::get_block_coinbase(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::block::get_block_coinbase(inst, result_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn get_block_info(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::block::get_block_info(inst, result_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn get_base_fee_at(
+    wasm_inst: *mut ZenInstanceExtern,
+    blocks_ago: i64,
+    result_offset: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) =
+        dtvmcore_rust::evm::host_functions::fee::get_base_fee_at(inst, blocks_ago, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_blob_base_fee(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::fee::get_blob_base_fee(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::fee::get_blob_base_fee(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_base_fee(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::fee::get_base_fee(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::fee::get_base_fee(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_tx_gas_price(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::transaction::get_tx_gas_price(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::transaction::get_tx_gas_price(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_block_prev_randao(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::block::get_block_prev_randao(inst, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::block::get_block_prev_randao(inst, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
+    }
+}
+
+extern "C" fn get_difficulty(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::block::get_difficulty(inst, result_offset)
+    {
+        err.raise(inst);
     }
 }
 
 extern "C" fn get_block_hash(
     wasm_inst: *mut ZenInstanceExtern,
-    number_offset: i32,
+    block_num: i64,
     result_offset: i32,
-) {
+) -> i32 {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::block::get_block_hash(
-        inst,
-        number_offset as i64,
-        result_offset,
-    )
-    {
-        inst.set_exception_by_hostapi(9);
+    match dtvmcore_rust::evm::host_functions::block::get_block_hash(inst, block_num, result_offset) {
+        Ok(found) => found,
+        Err(err) => {
+            err.raise(inst);
+            0
+        }
     }
 }
 
@@ -202,14 +301,38 @@ extern "C" fn call_data_copy(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::transaction::call_data_copy(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::transaction::call_data_copy(
         inst,
         result_offset,
         data_offset,
         length,
     )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
+    }
+}
+
+extern "C" fn get_blob_hash_count(wasm_inst: *mut ZenInstanceExtern) -> i32 {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    match dtvmcore_rust::evm::host_functions::transaction::get_blob_hash_count(inst) {
+        Ok(count) => count,
+        Err(err) => {
+            err.raise(inst);
+            0
+        }
+    }
+}
+
+extern "C" fn get_blob_hash(wasm_inst: *mut ZenInstanceExtern, index_offset: i32, result_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::transaction::get_blob_hash(
+        inst,
+        index_offset,
+        result_offset,
+    ) {
+        err.raise(inst);
     }
 }
 
@@ -231,9 +354,9 @@ extern "C" fn code_copy(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::code::code_copy(inst, result_offset, code_offset, length)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::code::code_copy(inst, result_offset, code_offset, length)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -251,13 +374,13 @@ extern "C" fn get_external_code_hash(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::code::get_external_code_hash(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::code::get_external_code_hash(
         inst,
         addr_offset,
         result_offset,
     )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -270,7 +393,7 @@ extern "C" fn external_code_copy(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::code::external_code_copy(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::code::external_code_copy(
         inst,
         addr_offset,
         result_offset,
@@ -278,7 +401,23 @@ extern "C" fn external_code_copy(
         length,
     )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
+    }
+}
+
+extern "C" fn get_self_code_hash(wasm_inst: *mut ZenInstanceExtern, result_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::code::get_self_code_hash(inst, result_offset) {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn get_immutable(wasm_inst: *mut ZenInstanceExtern, index: i32, result_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::code::get_immutable(inst, index, result_offset) {
+        err.raise(inst);
     }
 }
 
@@ -294,9 +433,9 @@ extern "C" fn sha256(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::crypto::sha256(inst, data_offset, length, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::crypto::sha256(inst, data_offset, length, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -308,9 +447,103 @@ extern "C" fn keccak256(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::crypto::keccak256(inst, data_offset, length, result_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::crypto::keccak256(inst, data_offset, length, result_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn keccak512(
+    wasm_inst: *mut ZenInstanceExtern,
+    data_offset: i32,
+    length: i32,
+    result_offset: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::crypto::keccak512(inst, data_offset, length, result_offset)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn ripemd160(
+    wasm_inst: *mut ZenInstanceExtern,
+    data_offset: i32,
+    length: i32,
+    result_offset: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::crypto::ripemd160(inst, data_offset, length, result_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
+    }
+}
+
+extern "C" fn verify_merkle_proof(
+    wasm_inst: *mut ZenInstanceExtern,
+    leaf_offset: i32,
+    proof_offset: i32,
+    proof_count: i32,
+    root_offset: i32,
+) -> i32 {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    match dtvmcore_rust::evm::host_functions::crypto::verify_merkle_proof(
+        inst,
+        leaf_offset,
+        proof_offset,
+        proof_count,
+        root_offset,
+    ) {
+        Ok(valid) => valid,
+        Err(err) => {
+            err.raise(inst);
+            0
+        }
+    }
+}
+
+extern "C" fn ec_recover(
+    wasm_inst: *mut ZenInstanceExtern,
+    hash_offset: i32,
+    v_offset: i32,
+    r_offset: i32,
+    s_offset: i32,
+    result_offset: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::precompile::ec_recover(
+        inst,
+        hash_offset,
+        v_offset,
+        r_offset,
+        s_offset,
+        result_offset,
+    ) {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn modexp(
+    wasm_inst: *mut ZenInstanceExtern,
+    input_offset: i32,
+    input_length: i32,
+    result_offset: i32,
+    result_length: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::precompile::modexp(
+        inst,
+        input_offset,
+        input_length,
+        result_offset,
+        result_length,
+    ) {
+        err.raise(inst);
     }
 }
 
@@ -327,7 +560,7 @@ extern "C" fn addmod(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::math::addmod(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::math::addmod(
         inst,
         a_offset,
         b_offset,
@@ -335,7 +568,7 @@ extern "C" fn addmod(
         result_offset,
     )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -348,7 +581,7 @@ extern "C" fn mulmod(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::math::mulmod(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::math::mulmod(
         inst,
         a_offset,
         b_offset,
@@ -356,7 +589,7 @@ extern "C" fn mulmod(
         result_offset,
     )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -369,7 +602,7 @@ extern "C" fn expmod(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::math::expmod(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::math::expmod(
         inst,
         base_offset,
         exp_offset,
@@ -377,7 +610,7 @@ extern "C" fn expmod(
         result_offset,
     )
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -502,6 +735,24 @@ extern "C" fn create_contract(
     }
 }
 
+extern "C" fn compute_create2_address(
+    wasm_inst: *mut ZenInstanceExtern,
+    salt_offset: i32,
+    code_hash_offset: i32,
+    result_offset: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::contract::compute_create2_address(
+        inst,
+        salt_offset,
+        code_hash_offset,
+        result_offset,
+    ) {
+        err.raise(inst);
+    }
+}
+
 // ============================================================================
 // Control Operations - For execution control
 // ============================================================================
@@ -509,36 +760,73 @@ extern "C" fn create_contract(
 extern "C" fn finish(wasm_inst: *mut ZenInstanceExtern, data_offset: i32, length: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::control::finish(inst, data_offset, length)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::control::finish(inst, data_offset, length)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
+    }
+}
+
+extern "C" fn stop(wasm_inst: *mut ZenInstanceExtern) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::control::stop(inst)
+    {
+        err.raise(inst);
     }
 }
 
 extern "C" fn revert(wasm_inst: *mut ZenInstanceExtern, data_offset: i32, length: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) =  dtvmcore_rust::evm::host_functions::control::revert(inst, data_offset, length)
+    if let Err(err) =  dtvmcore_rust::evm::host_functions::control::revert(inst, data_offset, length)
+    {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn revert_with_selector(
+    wasm_inst: *mut ZenInstanceExtern,
+    selector_offset: i32,
+    data_offset: i32,
+    length: i32,
+) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::control::revert_with_selector(
+        inst,
+        selector_offset,
+        data_offset,
+        length,
+    ) {
+        err.raise(inst);
+    }
+}
+
+extern "C" fn record_dispatch(wasm_inst: *mut ZenInstanceExtern, kind: i32, selector_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) =
+        dtvmcore_rust::evm::host_functions::control::record_dispatch(inst, kind, selector_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn invalid(wasm_inst: *mut ZenInstanceExtern) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::control::invalid(inst)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::control::invalid(inst)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
 extern "C" fn self_destruct(wasm_inst: *mut ZenInstanceExtern, beneficiary_offset: i32) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::control::self_destruct(inst, beneficiary_offset)
+    if let Err(err) = dtvmcore_rust::evm::host_functions::control::self_destruct(inst, beneficiary_offset)
     {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -556,13 +844,13 @@ extern "C" fn return_data_copy(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::control::return_data_copy(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::control::return_data_copy(
         inst,
         result_offset,
         data_offset,
         length,
     ) {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -582,7 +870,7 @@ extern "C" fn emit_log_event(
 ) {
     let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
 
-    if let Err(_) = dtvmcore_rust::evm::host_functions::log::emit_log_event(
+    if let Err(err) = dtvmcore_rust::evm::host_functions::log::emit_log_event(
         inst,
         data_offset,
         length,
@@ -592,7 +880,7 @@ extern "C" fn emit_log_event(
         topic3_offset,
         topic4_offset,
     ) {
-        inst.set_exception_by_hostapi(9);
+        err.raise(inst);
     }
 }
 
@@ -606,15 +894,115 @@ extern "C" fn get_gas_left(wasm_inst: *mut ZenInstanceExtern) -> i64 {
     dtvmcore_rust::evm::host_functions::transaction::get_gas_left(inst)
 }
 
+// ============================================================================
+// Cheatcode Operations - Test-only environment manipulation
+// ============================================================================
+
+#[cfg(feature = "cheatcodes")]
+extern "C" fn warp(wasm_inst: *mut ZenInstanceExtern, new_timestamp: i64) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::cheatcode::warp(inst, new_timestamp) {
+        err.raise(inst);
+    }
+}
+
+#[cfg(feature = "cheatcodes")]
+extern "C" fn roll(wasm_inst: *mut ZenInstanceExtern, new_block_number: i64) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::cheatcode::roll(inst, new_block_number) {
+        err.raise(inst);
+    }
+}
+
+#[cfg(feature = "cheatcodes")]
+extern "C" fn prank(wasm_inst: *mut ZenInstanceExtern, addr_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::cheatcode::prank(inst, addr_offset) {
+        err.raise(inst);
+    }
+}
+
+#[cfg(feature = "cheatcodes")]
+extern "C" fn deal(wasm_inst: *mut ZenInstanceExtern, addr_offset: i32, amount_offset: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::cheatcode::deal(
+        inst,
+        addr_offset,
+        amount_offset,
+    )
+    {
+        err.raise(inst);
+    }
+}
+
+/// Create the cheatcode host function descriptors, registered as the `cheats` host module
+#[cfg(feature = "cheatcodes")]
+pub fn create_cheat_host_functions() -> Vec<ZenHostFuncDesc> {
+    vec![
+        ZenHostFuncDesc {
+            name: "warp".to_string(),
+            arg_types: vec![ZenValueType::I64],
+            ret_types: vec![],
+            ptr: warp as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "roll".to_string(),
+            arg_types: vec![ZenValueType::I64],
+            ret_types: vec![],
+            ptr: roll as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "prank".to_string(),
+            arg_types: vec![ZenValueType::I32],
+            ret_types: vec![],
+            ptr: prank as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "deal".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: deal as *const cty::c_void,
+        },
+    ]
+}
+
+// ============================================================================
+// Debug Operations - Test-only contract debugging aids
+// ============================================================================
+
+#[cfg(feature = "debug")]
+extern "C" fn dump_memory(wasm_inst: *mut ZenInstanceExtern, offset: i32, length: i32) {
+    let inst: &MockInstance = ZenInstance::from_raw_pointer(wasm_inst);
+
+    if let Err(err) = dtvmcore_rust::evm::host_functions::debug::dump_memory(inst, offset, length) {
+        err.raise(inst);
+    }
+}
+
+/// Create the debug host function descriptors, registered as the `debug` host module
+#[cfg(feature = "debug")]
+pub fn create_debug_host_functions() -> Vec<ZenHostFuncDesc> {
+    vec![ZenHostFuncDesc {
+        name: "dumpMemory".to_string(),
+        arg_types: vec![ZenValueType::I32, ZenValueType::I32],
+        ret_types: vec![],
+        ptr: dump_memory as *const cty::c_void,
+    }]
+}
+
 // ============================================================================
 // Host Function Descriptors Creation
 // ============================================================================
 
 /// Create complete EVM host functions
-/// Returns a vector of all 42 EVM host function descriptors (matching evmabimock.cpp)
+/// Returns a vector of all 64 EVM host function descriptors (matching evmabimock.cpp)
 pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
     vec![
-        // Account operations (6 functions)
+        // Account operations (9 functions)
         ZenHostFuncDesc {
             name: "getAddress".to_string(),
             arg_types: vec![ZenValueType::I32],
@@ -639,6 +1027,12 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: get_chain_id as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "verifyChainId".to_string(),
+            arg_types: vec![ZenValueType::I64, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: verify_chain_id as *const cty::c_void,
+        },
         ZenHostFuncDesc {
             name: "getTxOrigin".to_string(),
             arg_types: vec![ZenValueType::I32],
@@ -651,6 +1045,18 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: get_external_balance as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "getSelfBalance".to_string(),
+            arg_types: vec![ZenValueType::I32],
+            ret_types: vec![],
+            ptr: get_self_balance as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "accessAccount".to_string(),
+            arg_types: vec![ZenValueType::I32],
+            ret_types: vec![ZenValueType::I32],
+            ptr: access_account as *const cty::c_void,
+        },
         // Block operations (6 functions) - these return values directly
         ZenHostFuncDesc {
             name: "getBlockNumber".to_string(),
@@ -688,6 +1094,12 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: get_base_fee as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "getBaseFeeAt".to_string(),
+            arg_types: vec![ZenValueType::I64, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: get_base_fee_at as *const cty::c_void,
+        },
         ZenHostFuncDesc {
             name: "getTxGasPrice".to_string(),
             arg_types: vec![ZenValueType::I32],
@@ -700,12 +1112,24 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: get_block_prev_randao as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "getDifficulty".to_string(),
+            arg_types: vec![ZenValueType::I32],
+            ret_types: vec![],
+            ptr: get_difficulty as *const cty::c_void,
+        },
         ZenHostFuncDesc {
             name: "getBlockHash".to_string(),
             arg_types: vec![ZenValueType::I64, ZenValueType::I32],
             ret_types: vec![ZenValueType::I32],
             ptr: get_block_hash as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "getBlockInfo".to_string(),
+            arg_types: vec![ZenValueType::I32],
+            ret_types: vec![],
+            ptr: get_block_info as *const cty::c_void,
+        },
         // Storage operations (2 functions) - use camelCase as per counter.wasm
         ZenHostFuncDesc {
             name: "storageStore".to_string(),
@@ -719,7 +1143,27 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: storage_load as *const cty::c_void,
         },
-        // Call data operations (2 functions)
+        // Transient storage operations (2 functions, EIP-1153)
+        ZenHostFuncDesc {
+            name: "transientStore".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: transient_store as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "transientLoad".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: transient_load as *const cty::c_void,
+        },
+        // Storage access tracking (1 function, EIP-2929/2930)
+        ZenHostFuncDesc {
+            name: "accessStorage".to_string(),
+            arg_types: vec![ZenValueType::I32],
+            ret_types: vec![ZenValueType::I32],
+            ptr: access_storage as *const cty::c_void,
+        },
+        // Call data operations (4 functions)
         ZenHostFuncDesc {
             name: "getCallDataSize".to_string(),
             arg_types: vec![],
@@ -732,7 +1176,19 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: call_data_copy as *const cty::c_void,
         },
-        // Code operations (5 functions)
+        ZenHostFuncDesc {
+            name: "getBlobHashCount".to_string(),
+            arg_types: vec![],
+            ret_types: vec![ZenValueType::I32],
+            ptr: get_blob_hash_count as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "getBlobHash".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: get_blob_hash as *const cty::c_void,
+        },
+        // Code operations (7 functions)
         ZenHostFuncDesc {
             name: "getCodeSize".to_string(),
             arg_types: vec![],
@@ -768,7 +1224,19 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: external_code_copy as *const cty::c_void,
         },
-        // Crypto operations (2 functions) - keep lowercase as standard
+        ZenHostFuncDesc {
+            name: "getSelfCodeHash".to_string(),
+            arg_types: vec![ZenValueType::I32],
+            ret_types: vec![],
+            ptr: get_self_code_hash as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "getImmutable".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: get_immutable as *const cty::c_void,
+        },
+        // Crypto operations (7 functions) - keep lowercase as standard
         ZenHostFuncDesc {
             name: "sha256".to_string(),
             arg_types: vec![ZenValueType::I32, ZenValueType::I32, ZenValueType::I32],
@@ -781,6 +1249,52 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: keccak256 as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "keccak512".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: keccak512 as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "ripemd160".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: ripemd160 as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "verifyMerkleProof".to_string(),
+            arg_types: vec![
+                ZenValueType::I32,
+                ZenValueType::I32,
+                ZenValueType::I32,
+                ZenValueType::I32,
+            ],
+            ret_types: vec![ZenValueType::I32],
+            ptr: verify_merkle_proof as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "ecRecover".to_string(),
+            arg_types: vec![
+                ZenValueType::I32,
+                ZenValueType::I32,
+                ZenValueType::I32,
+                ZenValueType::I32,
+                ZenValueType::I32,
+            ],
+            ret_types: vec![],
+            ptr: ec_recover as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "modexp".to_string(),
+            arg_types: vec![
+                ZenValueType::I32,
+                ZenValueType::I32,
+                ZenValueType::I32,
+                ZenValueType::I32,
+            ],
+            ret_types: vec![],
+            ptr: modexp as *const cty::c_void,
+        },
         // Math operations (3 functions) - keep lowercase as standard
         ZenHostFuncDesc {
             name: "addmod".to_string(),
@@ -815,7 +1329,7 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![],
             ptr: expmod as *const cty::c_void,
         },
-        // Contract operations (5 functions) - use camelCase for consistency
+        // Contract operations (6 functions) - use camelCase for consistency
         ZenHostFuncDesc {
             name: "callContract".to_string(),
             arg_types: vec![
@@ -877,19 +1391,43 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
             ret_types: vec![ZenValueType::I32],
             ptr: create_contract as *const cty::c_void,
         },
-        // Control operations (6 functions)
+        ZenHostFuncDesc {
+            name: "computeCreate2Address".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: compute_create2_address as *const cty::c_void,
+        },
+        // Control operations (9 functions)
         ZenHostFuncDesc {
             name: "finish".to_string(),
             arg_types: vec![ZenValueType::I32, ZenValueType::I32],
             ret_types: vec![],
             ptr: finish as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "stop".to_string(),
+            arg_types: vec![],
+            ret_types: vec![],
+            ptr: stop as *const cty::c_void,
+        },
         ZenHostFuncDesc {
             name: "revert".to_string(),
             arg_types: vec![ZenValueType::I32, ZenValueType::I32],
             ret_types: vec![],
             ptr: revert as *const cty::c_void,
         },
+        ZenHostFuncDesc {
+            name: "revertWithSelector".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: revert_with_selector as *const cty::c_void,
+        },
+        ZenHostFuncDesc {
+            name: "recordDispatch".to_string(),
+            arg_types: vec![ZenValueType::I32, ZenValueType::I32],
+            ret_types: vec![],
+            ptr: record_dispatch as *const cty::c_void,
+        },
         ZenHostFuncDesc {
             name: "invalid".to_string(),
             arg_types: vec![],
@@ -938,3 +1476,88 @@ pub fn create_complete_evm_host_functions() -> Vec<ZenHostFuncDesc> {
         },
     ]
 }
+
+/// Host function signature: (name, argument types, return types)
+pub type HostFunctionSignature = (String, Vec<ZenValueType>, Vec<ZenValueType>);
+
+/// Create the manifest of all EVM host functions
+/// Derived from `create_complete_evm_host_functions`, so binding generators stay
+/// in sync with the registered descriptors
+pub fn create_complete_evm_host_functions_manifest() -> Vec<HostFunctionSignature> {
+    create_complete_evm_host_functions()
+        .into_iter()
+        .map(|desc| (desc.name, desc.arg_types, desc.ret_types))
+        .collect()
+}
+
+/// Category of an EVM host function, following the descriptor groups
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HostFunctionCategory {
+    Account,
+    Block,
+    Storage,
+    TransientStorage,
+    CallData,
+    Code,
+    Crypto,
+    Math,
+    Contract,
+    Control,
+    Log,
+    Gas,
+}
+
+/// Metadata describing one registered EVM host function
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostFunctionMeta {
+    pub name: String,
+    pub category: HostFunctionCategory,
+    /// Number of arguments
+    pub arity: usize,
+    /// Whether the function returns a value
+    pub returns_value: bool,
+}
+
+/// Category of the host function named `name`, or None if it is not an EVM host function
+pub fn host_function_category(name: &str) -> Option<HostFunctionCategory> {
+    use HostFunctionCategory::*;
+    let category = match name {
+        "getAddress" | "getCaller" | "getCallValue" | "getChainId" | "verifyChainId"
+        | "getTxOrigin" | "getExternalBalance" | "getSelfBalance" | "accessAccount" => Account,
+        "getBlockNumber" | "getBlockTimestamp" | "getBlockGasLimit" | "getBlockCoinbase"
+        | "getBlobBaseFee" | "getBaseFee" | "getBaseFeeAt" | "getTxGasPrice"
+        | "getBlockPrevRandao" | "getDifficulty" | "getBlockHash" | "getBlockInfo" => Block,
+        "storageStore" | "storageLoad" | "accessStorage" => Storage,
+        "transientStore" | "transientLoad" => TransientStorage,
+        "getCallDataSize" | "callDataCopy" | "getBlobHashCount" | "getBlobHash" => CallData,
+        "getCodeSize" | "codeCopy" | "getExternalCodeSize" | "getExternalCodeHash"
+        | "externalCodeCopy" | "getSelfCodeHash" | "getImmutable" => Code,
+        "sha256" | "keccak256" | "keccak512" | "ripemd160" | "verifyMerkleProof"
+        | "ecRecover" | "modexp" => Crypto,
+        "addmod" | "mulmod" | "expmod" => Math,
+        "callContract" | "callCode" | "callDelegate" | "callStatic" | "createContract"
+        | "computeCreate2Address" => Contract,
+        "finish" | "stop" | "revert" | "revertWithSelector" | "recordDispatch" | "invalid"
+        | "selfDestruct" | "getReturnDataSize" | "returnDataCopy" => Control,
+        "emitLogEvent" => Log,
+        "getGasLeft" => Gas,
+        _ => return None,
+    };
+    Some(category)
+}
+
+/// Create the catalog of all EVM host functions with their metadata
+/// Lets tooling check that a WASM module only imports supported host functions
+pub fn evm_host_function_catalog() -> Vec<HostFunctionMeta> {
+    create_complete_evm_host_functions()
+        .into_iter()
+        .filter_map(|desc| {
+            Some(HostFunctionMeta {
+                category: host_function_category(&desc.name)?,
+                arity: desc.arg_types.len(),
+                returns_value: !desc.ret_types.is_empty(),
+                name: desc.name,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,169 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Declarative multi-contract scenarios
+//!
+//! A `Scenario` declares the contracts to deploy, the initial balances and a
+//! sequence of calls, then runs them against one shared state:
+//!
+//! ```ignore
+//! let results = Scenario::new()
+//!     .contract("SimpleTarget.wasm", target_address, target_wasm)
+//!     .contract("ContractCalls.wasm", calls_address, calls_wasm)
+//!     .call(owner_address, calls_address, call_data)
+//!     .run()?;
+//! ```
+
+use crate::contract_executor::{ContractExecutionResult, ContractExecutor};
+use crate::mock_context::{ContractInfo, MockContext};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Default gas limit of every deployment and call in a scenario
+const DEFAULT_GAS_LIMIT: i64 = 1_000_000;
+
+/// Contract deployed by a scenario
+struct ScenarioContract {
+    name: String,
+    address: [u8; 20],
+    wasm: Vec<u8>,
+}
+
+/// Call made by a scenario
+struct ScenarioCall {
+    from: [u8; 20],
+    to: [u8; 20],
+    value: [u8; 32],
+    call_data: Vec<u8>,
+}
+
+/// Declarative description of contracts, balances and calls
+///
+/// Contracts are deployed in declaration order by the deployer, then the
+/// calls run in declaration order. All of them share storage, balances and
+/// the contract registry.
+pub struct Scenario {
+    deployer: [u8; 20],
+    gas_limit: i64,
+    contracts: Vec<ScenarioContract>,
+    balances: Vec<([u8; 20], [u8; 32])>,
+    calls: Vec<ScenarioCall>,
+}
+
+impl Scenario {
+    /// Create an empty scenario deploying from the zero address
+    pub fn new() -> Self {
+        Self {
+            deployer: [0u8; 20],
+            gas_limit: DEFAULT_GAS_LIMIT,
+            contracts: Vec::new(),
+            balances: Vec::new(),
+            calls: Vec::new(),
+        }
+    }
+
+    /// Deploy all contracts from `deployer`
+    pub fn deployer(mut self, deployer: [u8; 20]) -> Self {
+        self.deployer = deployer;
+        self
+    }
+
+    /// Gas limit of every deployment and call
+    pub fn gas_limit(mut self, gas_limit: i64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Deploy `wasm` at `address` under `name`
+    pub fn contract(mut self, name: &str, address: [u8; 20], wasm: Vec<u8>) -> Self {
+        self.contracts.push(ScenarioContract {
+            name: name.to_string(),
+            address,
+            wasm,
+        });
+        self
+    }
+
+    /// Give `address` an initial balance (32-byte big-endian wei)
+    pub fn balance(mut self, address: [u8; 20], balance: [u8; 32]) -> Self {
+        self.balances.push((address, balance));
+        self
+    }
+
+    /// Call the contract at `to` from `from` with `call_data`
+    pub fn call(self, from: [u8; 20], to: [u8; 20], call_data: Vec<u8>) -> Self {
+        self.call_with_value(from, to, [0u8; 32], call_data)
+    }
+
+    /// Call the contract at `to` from `from`, sending `value` along with `call_data`
+    pub fn call_with_value(
+        mut self,
+        from: [u8; 20],
+        to: [u8; 20],
+        value: [u8; 32],
+        call_data: Vec<u8>,
+    ) -> Self {
+        self.calls.push(ScenarioCall {
+            from,
+            to,
+            value,
+            call_data,
+        });
+        self
+    }
+
+    /// Deploy the contracts, then make the calls
+    /// Returns the result of each call, in declaration order
+    pub fn run(&self) -> Result<Vec<ContractExecutionResult>, String> {
+        let executor = ContractExecutor::new()?;
+
+        let registry = Rc::new(RefCell::new(HashMap::new()));
+        for contract in &self.contracts {
+            registry.borrow_mut().insert(
+                contract.address,
+                ContractInfo::new(contract.name.clone(), contract.wasm.clone()),
+            );
+        }
+
+        let mut builder = MockContext::builder()
+            .with_storage(Rc::new(RefCell::new(HashMap::new())))
+            .with_contract_registry(registry.clone())
+            .with_gas_limit(self.gas_limit);
+        for (address, balance) in &self.balances {
+            builder = builder.with_balance(*address, *balance);
+        }
+        let mut context = builder.build();
+
+        for contract in &self.contracts {
+            context.set_code(contract.wasm.clone());
+            context.set_address(contract.address);
+            context.set_caller(self.deployer);
+            context.set_call_value([0u8; 32]);
+            context.set_call_data(Vec::new());
+            executor.deploy_contract(&contract.name, &mut context)?;
+        }
+
+        let mut results = Vec::with_capacity(self.calls.len());
+        for call in &self.calls {
+            let target = registry.borrow().get(&call.to).cloned().ok_or_else(|| {
+                format!("Scenario calls unknown contract 0x{}", hex::encode(call.to))
+            })?;
+
+            context.set_code(target.code);
+            context.set_address(call.to);
+            context.set_caller(call.from);
+            context.set_call_value(call.value);
+            context.set_call_data(call.call_data.clone());
+            results.push(executor.call_contract_function(&target.name, &mut context)?);
+        }
+
+        Ok(results)
+    }
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self::new()
+    }
+}
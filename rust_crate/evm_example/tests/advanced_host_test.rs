@@ -9,17 +9,25 @@
 //! - Mathematical operations (addmod, mulmod)
 //! - Contract lifecycle (self-destruct)
 //! - Error handling (invalid operations)
+//!
+//! Host functions the contract does not cover (storage, gas metering, logs,
+//! contract creation) are checked with hand-written WAT contracts run by
+//! `run_wat`, one module per host function group.
 
 mod common;
 
 use common::calldata::{set_call_data_with_params, ParamBuilder};
 use common::*;
+use dtvmcore_rust::evm::traits::{EvmHost, Hardfork};
+use dtvmcore_rust::evm::utils::u128_to_bytes32;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
 // Test constants for better maintainability
 const TEST_EXTERNAL_BALANCE: u64 = 1000;
+const TEST_DESTRUCTED_CONTRACT_BALANCE: u64 = 700;
+const TEST_BENEFICIARY_BALANCE: u64 = 250;
 const TEST_EXTERNAL_CODE_SIZE: u64 = 100;
 const TEST_SELF_CODE_SIZE: u64 = 30_334;
 const TEST_ADDMOD_A: u64 = 123;
@@ -36,8 +44,9 @@ const TEST_OWNER_ADDRESS_ID: u8 = 1;
 
 // Expected test results as constants
 const EXPECTED_CODE_COPY_HEX: &str = "000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000640061736d010000000176106000017f60037f7f7f0060017f0060027f7f0060077f7f7f7f7f7f7f0060057e7f7f7f7f017f6000006000017e60017f017f60047f7f7f7f0060037f7f7f017f60077e7f7f7f7f7f7f017f600d7f7e7e7e7e7e7e7e7e7e7e7e00000000000000000000000000000000000000000000000000000000";
+// The target account does not exist, so its code hash is zero (EIP-1052)
 const EXPECTED_EXTERNAL_CODE_HASH_HEX: &str =
-    "de000000000000000000000000000000000000000000000000000000000000ad";
+    "0000000000000000000000000000000000000000000000000000000000000000";
 const EXPECTED_EXTERNAL_CODE_COPY_HEX: &str = "0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000006460006000f3000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
 
 // Function selectors - organized by category
@@ -80,6 +89,10 @@ impl AdvancedHostTestFixture {
     }
 
     fn create_context(&self) -> MockContext {
+        self.create_context_with_hardfork(Hardfork::default())
+    }
+
+    fn create_context_with_hardfork(&self, hardfork: Hardfork) -> MockContext {
         let owner_address = random_test_address(TEST_OWNER_ADDRESS_ID);
         let contract_address = random_test_address(TEST_CONTRACT_ADDRESS_ID);
 
@@ -88,6 +101,7 @@ impl AdvancedHostTestFixture {
             .with_code(self.wasm_bytes.clone())
             .with_caller(owner_address)
             .with_address(contract_address)
+            .with_hardfork(hardfork)
             .build()
     }
 
@@ -130,6 +144,7 @@ fn test_advanced_host_functions() {
     test_mul_mod(&fixture);
     test_self_code_size(&fixture);
     test_self_destruct(&fixture);
+    test_self_destruct_removes_code(&fixture);
     test_invalid(&fixture);
 }
 
@@ -313,8 +328,17 @@ fn test_self_code_size(fixture: &AdvancedHostTestFixture) {
 /// Test self destruct operation
 fn test_self_destruct(fixture: &AdvancedHostTestFixture) {
     let mut context = fixture.create_context();
+    let contract_address = random_test_address(TEST_CONTRACT_ADDRESS_ID);
     let target_address = random_test_address(TEST_TARGET_ADDRESS_ID);
     let params = ParamBuilder::new().address(&target_address).build();
+    context.set_balance(
+        &contract_address,
+        &u128_to_bytes32(TEST_DESTRUCTED_CONTRACT_BALANCE as u128),
+    );
+    context.set_balance(
+        &target_address,
+        &u128_to_bytes32(TEST_BENEFICIARY_BALANCE as u128),
+    );
 
     let result = fixture
         .call_function(&mut context, &selectors::TEST_SELF_DESTRUCT, params.clone())
@@ -335,10 +359,63 @@ fn test_self_destruct(fixture: &AdvancedHostTestFixture) {
     let balance = decode_uint256(&balance_result.return_data)
         .expect("Failed to decode balance after self destruct");
 
+    // The target keeps its own balance and receives the contract's whole balance
+    let expected_balance = TEST_BENEFICIARY_BALANCE + TEST_DESTRUCTED_CONTRACT_BALANCE;
     assert_eq!(
-        balance, TEST_EXTERNAL_BALANCE,
+        balance, expected_balance,
         "Balance after self destruct should be {}, got {}",
-        TEST_EXTERNAL_BALANCE, balance
+        expected_balance, balance
+    );
+    assert_eq!(
+        context
+            .get_destruct_amount(&contract_address)
+            .and_then(|amount| decode_uint256(&amount).ok()),
+        Some(TEST_DESTRUCTED_CONTRACT_BALANCE),
+        "Destruct amount should equal the contract's prior balance"
+    );
+    assert_eq!(
+        context.get_external_balance(&contract_address),
+        [0u8; 32],
+        "The destructed contract should keep no balance"
+    );
+}
+
+/// Test that a self-destructed contract has no code afterwards
+/// Before Cancun (EIP-6780) SELFDESTRUCT deletes any contract
+fn test_self_destruct_removes_code(fixture: &AdvancedHostTestFixture) {
+    let mut context = fixture.create_context_with_hardfork(Hardfork::Shanghai);
+    let contract_address = random_test_address(TEST_CONTRACT_ADDRESS_ID);
+    let target_address = random_test_address(TEST_TARGET_ADDRESS_ID);
+
+    let result = fixture
+        .call_function(
+            &mut context,
+            &selectors::TEST_SELF_DESTRUCT,
+            ParamBuilder::new().address(&target_address).build(),
+        )
+        .expect("Failed to call testSelfDestruct()");
+    assert!(result.success, "testSelfDestruct() should succeed");
+    assert!(context.is_destructed(&contract_address));
+
+    let result = fixture
+        .call_function(
+            &mut context,
+            &selectors::TEST_EXTERNAL_CODE_SIZE,
+            ParamBuilder::new().address(&contract_address).build(),
+        )
+        .expect("Failed to call testExternalCodeSize() after self destruct");
+    assert!(result.success, "testExternalCodeSize() should succeed");
+
+    let code_size =
+        decode_uint256(&result.return_data).expect("Failed to decode code size from return data");
+    assert_eq!(
+        code_size, 0,
+        "A self-destructed contract should have no code"
+    );
+    assert_eq!(
+        context.get_external_balance(&contract_address),
+        [0u8; 32],
+        "A self-destructed contract should keep no balance"
     );
 }
 
@@ -353,3 +430,758 @@ fn test_invalid(fixture: &AdvancedHostTestFixture) {
     // Invalid operations should fail
     assert!(!result.success, "testInvalid() should fail as expected");
 }
+
+/// Access List
+///
+/// Verifies the EIP-2929/2930 accessAccount/accessStorage host functions with a
+/// hand-written contract. Each access reports whether the account or slot was
+/// already warm (1) or cold (0), and warms it for the rest of the transaction.
+mod access_list {
+    use super::*;
+
+    const TEST_CONTRACT_ADDRESS_ID: u8 = 13;
+    const TEST_FRESH_ADDRESS_ID: u8 = 14;
+    const TEST_LISTED_ADDRESS_ID: u8 = 15;
+
+    /// Accesses a fresh account twice, a listed account once, a fresh slot (key 1)
+    /// twice and a listed slot (key 2) once; finishes with the six results as
+    /// little-endian i32 values
+    fn access_wat(fresh: [u8; 20], listed: [u8; 20]) -> String {
+        let fresh: String = fresh.iter().map(|b| format!("\\{:02x}", b)).collect();
+        let listed: String = listed.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "accessAccount" (func $accessAccount (param i32) (result i32)))
+  (import "env" "accessStorage" (func $accessStorage (param i32) (result i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: fresh address, 32..52: listed address, 64..96: key 1, 96..128: key 2, 128..152: results
+  (data (i32.const 0) "{fresh}")
+  (data (i32.const 32) "{listed}")
+  (data (i32.const 95) "\01")
+  (data (i32.const 127) "\02")
+  (func (export "deploy"))
+  (func (export "call")
+    (i32.store (i32.const 128) (call $accessAccount (i32.const 0)))
+    (i32.store (i32.const 132) (call $accessAccount (i32.const 0)))
+    (i32.store (i32.const 136) (call $accessAccount (i32.const 32)))
+    (i32.store (i32.const 140) (call $accessStorage (i32.const 64)))
+    (i32.store (i32.const 144) (call $accessStorage (i32.const 64)))
+    (i32.store (i32.const 148) (call $accessStorage (i32.const 96)))
+    (call $finish (i32.const 128) (i32.const 24))))
+"#
+        )
+    }
+
+    fn key(value: u8) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        key[31] = value;
+        key
+    }
+
+    fn warm(data: &[u8], index: usize) -> bool {
+        i32::from_le_bytes(data[index * 4..index * 4 + 4].try_into().unwrap()) == 1
+    }
+
+    #[test]
+    fn test_first_access_is_cold_then_warm() {
+        let contract = random_test_address(TEST_CONTRACT_ADDRESS_ID);
+        let fresh = random_test_address(TEST_FRESH_ADDRESS_ID);
+        let listed = random_test_address(TEST_LISTED_ADDRESS_ID);
+
+        let result = run_wat(
+            &access_wat(fresh, listed),
+            MockContext::builder()
+                .with_address(contract)
+                .with_access_list(vec![(listed, vec![]), (contract, vec![key(2)])]),
+        );
+        assert!(result.success, "Contract call should succeed");
+
+        let data = &result.return_data;
+        assert!(
+            !warm(data, 0),
+            "First access of a fresh account should be cold"
+        );
+        assert!(warm(data, 1), "Second access of the account should be warm");
+        assert!(
+            warm(data, 2),
+            "Accounts in the access list should start warm"
+        );
+        assert!(
+            !warm(data, 3),
+            "First access of a fresh slot should be cold"
+        );
+        assert!(warm(data, 4), "Second access of the slot should be warm");
+        assert!(warm(data, 5), "Slots in the access list should start warm");
+    }
+}
+
+/// Account Access Gas
+///
+/// Verifies the EIP-2929 cold/warm account-access cost of calls. A hand-written
+/// contract calls the same account (which has no code) twice with no gas
+/// forwarded and returns its remaining gas before, between and after the calls.
+mod account_access_gas {
+    use super::*;
+    use dtvmcore_rust::evm::gas::{COLD_ACCOUNT_ACCESS_COST, WARM_ACCOUNT_ACCESS_COST};
+    use dtvmcore_rust::evm::GAS_OPCODE_COST;
+
+    const TEST_GAS_LIMIT: u64 = 1_000_000;
+    const TEST_TARGET_ADDRESS_ID: u8 = 4;
+
+    /// Calls `target` twice and finishes with three little-endian u64 gas readings
+    fn double_call_wat(target: [u8; 20]) -> String {
+        let target: String = target.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "getGasLeft" (func $getGasLeft (result i64)))
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: target address, 32..64: zero call value, 64..88: gas readings
+  (data (i32.const 0) "{target}")
+  (func (export "deploy"))
+  (func (export "call")
+    (i64.store (i32.const 64) (call $getGasLeft))
+    (drop (call $callContract (i64.const 0) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))
+    (i64.store (i32.const 72) (call $getGasLeft))
+    (drop (call $callContract (i64.const 0) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))
+    (i64.store (i32.const 80) (call $getGasLeft))
+    (call $finish (i32.const 64) (i32.const 24))))
+"#
+        )
+    }
+
+    fn gas_reading(data: &[u8], index: usize) -> u64 {
+        u64::from_le_bytes(data[index * 8..index * 8 + 8].try_into().unwrap())
+    }
+
+    fn call_costs(account_access_gas: bool) -> (u64, u64) {
+        let target = random_test_address(TEST_TARGET_ADDRESS_ID);
+        let result = run_wat(
+            &double_call_wat(target),
+            MockContext::builder()
+                .with_gas_limit(TEST_GAS_LIMIT as i64)
+                .with_account_access_gas(account_access_gas),
+        );
+        assert!(result.success, "Contract call should succeed");
+
+        // Each interval also includes the GAS opcode that closes it
+        let readings: Vec<u64> = (0..3)
+            .map(|i| gas_reading(&result.return_data, i))
+            .collect();
+        (
+            readings[0] - readings[1] - GAS_OPCODE_COST,
+            readings[1] - readings[2] - GAS_OPCODE_COST,
+        )
+    }
+
+    #[test]
+    fn test_first_call_to_account_is_cold() {
+        let (first, second) = call_costs(true);
+
+        assert!(first > second, "First (cold) call should cost more");
+        assert_eq!(first, COLD_ACCOUNT_ACCESS_COST);
+        assert_eq!(second, WARM_ACCOUNT_ACCESS_COST);
+    }
+
+    #[test]
+    fn test_account_access_gas_disabled_by_default() {
+        assert_eq!(call_costs(false), (0, 0));
+    }
+}
+
+/// CREATE2 Address
+///
+/// Verifies that the address computed by `computeCreate2Address` is the
+/// address an actual CREATE2 with the same salt and init code deploys to.
+mod create2_address {
+    use super::*;
+    use dtvmcore_rust::evm::traits::{create2_address, keccak256_digest};
+
+    const TEST_DEPLOYER_ADDRESS_ID: u8 = 40;
+
+    /// Init code deployed by the test: a bare WASM header
+    const INIT_CODE: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+    fn salt() -> [u8; 32] {
+        let mut salt = [0u8; 32];
+        salt[31] = 0x2a;
+        salt
+    }
+
+    fn wat_bytes(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("\\{:02x}", b)).collect()
+    }
+
+    /// Deploys `INIT_CODE` with CREATE2, computes the address it expects with
+    /// `computeCreate2Address`, and returns both addresses (deployed first)
+    fn create2_wat() -> String {
+        let salt = wat_bytes(&salt());
+        let init_code = wat_bytes(&INIT_CODE);
+        let code_hash = wat_bytes(&keccak256_digest(&INIT_CODE));
+        format!(
+            r#"
+(module
+  (import "env" "createContract" (func $createContract (param i32 i32 i32 i32 i32 i32 i32 i32) (result i32)))
+  (import "env" "computeCreate2Address" (func $computeCreate2Address (param i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: salt, 32..64: zero value, 64..72: init code, 96..128: init code hash
+  ;; 128..148: deployed address, 148..168: computed address
+  (data (i32.const 0) "{salt}")
+  (data (i32.const 64) "{init_code}")
+  (data (i32.const 96) "{code_hash}")
+  (func (export "deploy"))
+  (func (export "call")
+    (drop (call $createContract (i32.const 32) (i32.const 64) (i32.const 8) (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 1) (i32.const 128)))
+    (call $computeCreate2Address (i32.const 0) (i32.const 96) (i32.const 148))
+    (call $finish (i32.const 128) (i32.const 40))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_compute_create2_address_matches_deployment() {
+        let deployer_address = random_test_address(TEST_DEPLOYER_ADDRESS_ID);
+
+        let (result, context) = run_wat_with_context(
+            &create2_wat(),
+            MockContext::builder()
+                .with_address(deployer_address)
+                .with_gas_limit(1000000),
+        );
+        assert!(result.success, "the deployer should succeed");
+        assert_eq!(result.return_data.len(), 40);
+
+        let (deployed, computed) = result.return_data.split_at(20);
+        assert_ne!(deployed, [0u8; 20], "CREATE2 should deploy a contract");
+        assert_eq!(
+            computed, deployed,
+            "computeCreate2Address must match the address CREATE2 deploys to"
+        );
+
+        let expected = create2_address(&deployer_address, &salt(), &keccak256_digest(&INIT_CODE));
+        assert_eq!(deployed, expected);
+        let deployed_info = context
+            .get_contract_info(&expected)
+            .expect("the CREATE2 contract should be registered");
+        assert_eq!(deployed_info.code, INIT_CODE);
+    }
+}
+
+/// Custom Error Revert
+///
+/// Verifies that `revertWithSelector` produces Solidity custom-error revert data:
+/// the 4-byte error selector followed by the ABI-encoded error arguments.
+mod custom_error {
+    use super::*;
+
+    const TEST_ERROR_SIGNATURE: &str = "InsufficientBalance(uint256,uint256)";
+    const TEST_AVAILABLE: u64 = 10;
+    const TEST_REQUIRED: u64 = 30;
+
+    /// Reverts with `selector` and the arguments (10, 30)
+    fn custom_error_wat(selector: [u8; 4]) -> String {
+        let selector: String = selector.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "revertWithSelector" (func $revertWithSelector (param i32 i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..4: selector, 32..96: two uint256 arguments
+  (data (i32.const 0) "{selector}")
+  (data (i32.const 63) "\0a")
+  (data (i32.const 95) "\1e")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $revertWithSelector (i32.const 0) (i32.const 32) (i32.const 64))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_revert_with_custom_error_selector() {
+        let selector = calculate_selector(TEST_ERROR_SIGNATURE);
+
+        let result = run_wat(&custom_error_wat(selector), MockContext::builder());
+        expect_revert(&result, None);
+
+        let (decoded_selector, args) =
+            decode_custom_error(&result.return_data).expect("Failed to decode custom error");
+        assert_eq!(decoded_selector, selector);
+        assert_eq!(args.len(), 64, "Expected two ABI-encoded arguments");
+        assert_eq!(decode_uint256(&args[0..32]).unwrap(), TEST_AVAILABLE);
+        assert_eq!(decode_uint256(&args[32..64]).unwrap(), TEST_REQUIRED);
+    }
+}
+
+/// Host Function Gas
+///
+/// Verifies that with a `HostGasSchedule` configured, every host function call
+/// is charged out of the transaction's host gas budget and fails once it runs out.
+mod host_gas {
+    use super::*;
+    use dtvmcore_rust::evm::gas::HostGasSchedule;
+    use dtvmcore_rust::evm::traits::EvmHost;
+
+    const STORE_COST: u64 = 5000;
+    const STORE_COUNT: u8 = 100;
+
+    /// Stores 1 to slots 0 through 99, one slot per store
+    const SSTORE_LOOP_WAT: &str = r#"
+(module
+  (import "env" "storageStore" (func $storageStore (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: slot key, 32..64: stored value
+  (data (i32.const 63) "\01")
+  (func (export "deploy"))
+  (func (export "call")
+    (local $i i32)
+    (loop $again
+      (i32.store8 (i32.const 31) (local.get $i))
+      (call $storageStore (i32.const 0) (i32.const 32))
+      (local.set $i (i32.add (local.get $i) (i32.const 1)))
+      (br_if $again (i32.lt_u (local.get $i) (i32.const 100))))))
+"#;
+
+    fn slot(index: u8) -> [u8; 32] {
+        let mut slot = [0u8; 32];
+        slot[31] = index;
+        slot
+    }
+
+    /// Number of slots the loop stored to
+    fn stored_slots(context: &MockContext) -> usize {
+        (0..STORE_COUNT)
+            .filter(|&index| context.storage_load(&slot(index)) != [0u8; 32])
+            .count()
+    }
+
+    fn run_sstore_loop(host_gas: u64) -> (ContractExecutionResult, MockContext) {
+        let schedule = HostGasSchedule::new().with_cost("storage_store", STORE_COST);
+
+        run_wat_with_context(
+            SSTORE_LOOP_WAT,
+            MockContext::builder().with_host_gas_schedule(schedule, host_gas),
+        )
+    }
+
+    #[test]
+    fn test_sstore_loop_runs_out_of_host_gas() {
+        let (result, context) = run_sstore_loop(4 * STORE_COST);
+        assert!(
+            !result.success,
+            "the fifth store should exhaust the host gas budget"
+        );
+        assert!(!result.is_reverted, "running out of gas is not a revert");
+        let error_message = result
+            .error_message
+            .expect("an out of gas failure should report an error")
+            .to_lowercase()
+            .replace(' ', "");
+        assert!(
+            error_message.contains("outofgas"),
+            "expected an out of gas error, got {}",
+            error_message
+        );
+        assert_eq!(result.gas_left, 0);
+
+        assert_eq!(context.host_gas_left(), 0);
+        assert_eq!(
+            stored_slots(&context),
+            4,
+            "only the first four stores apply"
+        );
+    }
+
+    #[test]
+    fn test_sstore_loop_within_host_gas() {
+        let (result, context) = run_sstore_loop(1_000_000);
+        assert!(
+            result.success,
+            "all stores should fit in the host gas budget"
+        );
+        assert_eq!(
+            context.host_gas_left(),
+            1_000_000 - STORE_COUNT as u64 * STORE_COST
+        );
+        assert_eq!(stored_slots(&context), STORE_COUNT as usize);
+    }
+}
+
+/// Immutables
+///
+/// Uses a hand-written contract that reads immutables injected through the
+/// context by index, without running a constructor.
+mod immutables {
+    use super::*;
+
+    /// Returns immutables 0 and 1 (32 bytes each)
+    const IMMUTABLES_WAT: &str = r#"
+(module
+  (import "env" "getImmutable" (func $getImmutable (param i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $getImmutable (i32.const 0) (i32.const 0))
+    (call $getImmutable (i32.const 1) (i32.const 32))
+    (call $finish (i32.const 0) (i32.const 64))))
+"#;
+
+    fn call_immutables_contract(immutables: Vec<[u8; 32]>) -> ContractExecutionResult {
+        run_wat(
+            IMMUTABLES_WAT,
+            MockContext::builder()
+                .with_immutables(immutables)
+                .with_gas_limit(1000000),
+        )
+    }
+
+    #[test]
+    fn test_read_immutables_by_index() {
+        let owner = {
+            let mut word = [0u8; 32];
+            word[12..].copy_from_slice(&random_test_address(0x42));
+            word
+        };
+        let decimals = {
+            let mut word = [0u8; 32];
+            word[31] = 18;
+            word
+        };
+
+        let result = call_immutables_contract(vec![owner, decimals]);
+        assert!(result.success, "Reading immutables should succeed");
+        assert_eq!(&result.return_data[..32], &owner);
+        assert_eq!(&result.return_data[32..64], &decimals);
+    }
+
+    #[test]
+    fn test_missing_immutable_fails() {
+        let result = call_immutables_contract(vec![[0x01; 32]]);
+        assert!(!result.success, "Reading an unset immutable should fail");
+    }
+}
+
+/// Log Data Size Cap
+///
+/// Verifies that `emitLogEvent` rejects log data larger than the context's
+/// `max_log_data` before copying it, and still accepts logs at the cap.
+mod log_size {
+    use super::*;
+
+    const MAX_LOG_DATA: usize = 64;
+
+    /// Emits a LOG0 with `length` bytes of data starting at offset 0
+    fn log_wat(length: usize) -> String {
+        format!(
+            r#"
+(module
+  (import "env" "emitLogEvent" (func $emitLogEvent (param i32 i32 i32 i32 i32 i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $emitLogEvent (i32.const 0) (i32.const {length}) (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 0))))
+"#
+        )
+    }
+
+    fn emit_log(length: usize) -> (ContractExecutionResult, MockContext) {
+        run_wat_with_context(
+            &log_wat(length),
+            MockContext::builder()
+                .with_max_log_data(MAX_LOG_DATA)
+                .with_gas_limit(1000000),
+        )
+    }
+
+    #[test]
+    fn test_oversized_log_is_rejected() {
+        let (result, context) = emit_log(MAX_LOG_DATA + 1);
+
+        assert!(!result.success, "log above the cap should fail");
+        assert!(
+            context.get_events().is_empty(),
+            "no event should be recorded for a rejected log"
+        );
+    }
+
+    #[test]
+    fn test_log_at_cap_is_emitted() {
+        let (result, context) = emit_log(MAX_LOG_DATA);
+
+        assert!(result.success, "log at the cap should succeed");
+        let events = context.get_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data.len(), MAX_LOG_DATA);
+    }
+}
+
+/// Memory Expansion Gas
+///
+/// Verifies that copy host functions charge EVM memory-expansion gas based on
+/// the highest memory offset they touch, when enabled on the context.
+mod memory_gas {
+    use super::*;
+
+    const TEST_GAS_LIMIT: i64 = 1_000_000;
+    const TEST_LOW_OFFSET: u32 = 64;
+    const TEST_HIGH_OFFSET: u32 = 32 * 1024;
+
+    /// Copies 32 bytes of call data to `offset`, then returns the remaining gas as i64
+    fn copy_then_gas_left_wat(offset: u32) -> String {
+        format!(
+            r#"
+(module
+  (import "env" "callDataCopy" (func $callDataCopy (param i32 i32 i32)))
+  (import "env" "getGasLeft" (func $getGasLeft (result i64)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $callDataCopy (i32.const {offset}) (i32.const 0) (i32.const 32))
+    (i64.store (i32.const 0) (call $getGasLeft))
+    (call $finish (i32.const 0) (i32.const 8))))
+"#
+        )
+    }
+
+    fn gas_left_after_copy(offset: u32, charge_memory: bool) -> i64 {
+        let result = run_wat(
+            &copy_then_gas_left_wat(offset),
+            MockContext::builder()
+                .with_call_data(vec![0xab; 32])
+                .with_gas_limit(TEST_GAS_LIMIT)
+                .with_memory_expansion_gas(charge_memory),
+        );
+        assert!(result.success, "Copy at offset {} should succeed", offset);
+
+        i64::from_le_bytes(result.return_data[..8].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_copy_to_high_offset_costs_more() {
+        let low = gas_left_after_copy(TEST_LOW_OFFSET, true);
+        let high = gas_left_after_copy(TEST_HIGH_OFFSET, true);
+        assert!(
+            high < low,
+            "Copying to offset {} should cost more gas than to offset {} ({} vs {} left)",
+            TEST_HIGH_OFFSET,
+            TEST_LOW_OFFSET,
+            high,
+            low
+        );
+
+        // Without memory-expansion charging both copies cost the same
+        let low = gas_left_after_copy(TEST_LOW_OFFSET, false);
+        let high = gas_left_after_copy(TEST_HIGH_OFFSET, false);
+        assert_eq!(high, low);
+    }
+}
+
+/// SSTORE Net Gas Metering
+///
+/// Verifies that with a `GasCosts` schedule configured, SSTORE is charged by
+/// the slot's original, current and new values (EIP-2200).
+mod sstore_gas {
+    use super::*;
+    use dtvmcore_rust::evm::gas::GasCosts;
+    use dtvmcore_rust::evm::traits::EvmHost;
+
+    /// Sets storage slot 0 to 1, then back to its original value 0
+    const SET_AND_RESTORE_WAT: &str = r#"
+(module
+  (import "env" "storageStore" (func $storageStore (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: slot 0, 32..64: value 1, 64..96: value 0
+  (data (i32.const 63) "\01")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $storageStore (i32.const 0) (i32.const 32))
+    (call $storageStore (i32.const 0) (i32.const 64))))
+"#;
+
+    #[test]
+    fn test_set_and_restore_slot_is_net_metered() {
+        let costs = GasCosts::default();
+
+        let (result, context) = run_wat_with_context(
+            SET_AND_RESTORE_WAT,
+            MockContext::builder()
+                .with_gas_costs(costs)
+                .with_gas_limit(1000000),
+        );
+        assert!(result.success, "both stores should succeed");
+
+        // 0 -> 1 on a clean, cold slot costs SSTORE_SET plus the cold access;
+        // 1 -> 0 on the now dirty, warm slot costs SLOAD and refunds the first
+        // change minus SLOAD
+        assert_eq!(
+            result.gas_used,
+            (costs.cold_sload + costs.sstore_set + costs.sload) as i64
+        );
+        assert_eq!(context.get_gas_refund(), costs.sstore_set - costs.sload);
+    }
+}
+
+/// Stop Host Function
+///
+/// Verifies that `stop` (STOP) ends execution successfully with no return data,
+/// unlike `finish` (RETURN), even when a nested call left return data behind.
+mod stop {
+    use super::*;
+
+    const TEST_CALLEE_ADDRESS_ID: u8 = 7;
+
+    /// Finishes with 32 bytes of 0xee
+    const CALLEE_WAT: &str = r#"
+(module
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee\ee")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $finish (i32.const 0) (i32.const 32))))
+"#;
+
+    /// Calls `callee`, then stops; the call's return data must not become the output
+    fn caller_wat(callee: [u8; 20]) -> String {
+        let callee: String = callee.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "stop" (func $stop))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: callee address, 32..64: zero call value
+  (data (i32.const 0) "{callee}")
+  (func (export "deploy"))
+  (func (export "call")
+    (drop (call $callContract (i64.const -1) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))
+    (call $stop)
+    ;; Unreachable: stop ends execution
+    (call $finish (i32.const 0) (i32.const 20))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_stop_returns_no_data() {
+        let callee_address = random_test_address(TEST_CALLEE_ADDRESS_ID);
+
+        let result = run_wat(
+            &caller_wat(callee_address),
+            MockContext::builder()
+                .with_gas_limit(1000000)
+                .with_contract(
+                    callee_address,
+                    "Callee",
+                    wat::parse_str(CALLEE_WAT).expect("Failed to parse callee WAT"),
+                ),
+        );
+
+        assert!(result.success, "stop should end execution successfully");
+        assert!(!result.is_reverted);
+        assert!(
+            result.return_data.is_empty(),
+            "stop should return no data, got 0x{}",
+            hex::encode(&result.return_data)
+        );
+    }
+}
+
+/// Transient Storage
+///
+/// Verifies the EIP-1153 transientStore/transientLoad host functions with a
+/// hand-written contract. When called with non-empty call data the contract
+/// writes a transient slot; it always returns the transient value followed by
+/// the persistent storage value of the same key. Transient storage is only
+/// available from the Cancun hardfork on.
+mod transient_storage {
+    use super::*;
+    use dtvmcore_rust::evm::traits::{EvmHost, Hardfork};
+
+    const TRANSIENT_VALUE: u8 = 0x2a;
+
+    /// Optionally stores 0x2a at key 1 in transient storage, then returns
+    /// (transient value, storage value) for key 1
+    const TRANSIENT_WAT: &str = r#"
+(module
+  (import "env" "getCallDataSize" (func $getCallDataSize (result i32)))
+  (import "env" "transientStore" (func $transientStore (param i32 i32)))
+  (import "env" "transientLoad" (func $transientLoad (param i32 i32)))
+  (import "env" "storageLoad" (func $storageLoad (param i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: key, 32..64: value to store, 64..96: transient value, 96..128: storage value
+  (data (i32.const 31) "\01")
+  (data (i32.const 63) "\2a")
+  (func (export "deploy"))
+  (func (export "call")
+    (if (i32.gt_u (call $getCallDataSize) (i32.const 0))
+      (then (call $transientStore (i32.const 0) (i32.const 32))))
+    (call $transientLoad (i32.const 0) (i32.const 64))
+    (call $storageLoad (i32.const 0) (i32.const 96))
+    (call $finish (i32.const 64) (i32.const 64))))
+"#;
+
+    fn word(value: u8) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[31] = value;
+        word
+    }
+
+    #[test]
+    fn test_transient_storage_is_cleared_between_transactions() {
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        let mut context = MockContext::builder()
+            .with_code(wat::parse_str(TRANSIENT_WAT).expect("Failed to parse WAT"))
+            .build();
+
+        // Within a transaction the transient write is visible, and storage is untouched
+        context.set_call_data(vec![0x01]);
+        let result = executor
+            .call_contract_function("Transient", &mut context)
+            .expect("Failed to call contract");
+        assert!(result.success, "Writing call should succeed");
+        assert_eq!(&result.return_data[..32], &word(TRANSIENT_VALUE));
+        assert_eq!(&result.return_data[32..], &[0u8; 32]);
+        assert_eq!(context.storage_load(&word(1)), [0u8; 32]);
+
+        // The next transaction reads the unset key as zero
+        context.set_call_data(vec![]);
+        let result = executor
+            .call_contract_function("Transient", &mut context)
+            .expect("Failed to call contract");
+        assert!(result.success, "Reading call should succeed");
+        assert_eq!(&result.return_data[..32], &[0u8; 32]);
+        assert_eq!(context.transient_load(&word(1)), [0u8; 32]);
+    }
+
+    fn store_under(hardfork: Hardfork) -> bool {
+        run_wat(
+            TRANSIENT_WAT,
+            MockContext::builder()
+                .with_call_data(vec![0x01])
+                .with_hardfork(hardfork),
+        )
+        .success
+    }
+
+    #[test]
+    fn test_transient_store_requires_cancun() {
+        assert!(
+            !store_under(Hardfork::Shanghai),
+            "transientStore should fail before Cancun"
+        );
+        assert!(
+            store_under(Hardfork::Cancun),
+            "transientStore should succeed under Cancun"
+        );
+    }
+}
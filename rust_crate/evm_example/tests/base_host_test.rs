@@ -9,11 +9,15 @@
 //! - Transaction information (origin, gas price, gas left)
 //! - Chain information (chain ID, base fee, blob base fee, prev randao)
 //! - Cryptographic functions (SHA256)
+//!
+//! Host functions the contract does not cover are checked with hand-written
+//! WAT contracts run by `run_wat`, one module per host function group.
 
 mod common;
 
 use common::calldata::{set_call_data_with_params, ParamBuilder};
 use common::*;
+use dtvmcore_rust::evm::host_functions::GAS_OPCODE_COST;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -26,7 +30,7 @@ const TEST_GAS_PRICE: u64 = 20_000_000_000; // 20 gwei
 const TEST_BASE_FEE: u64 = 10_000_000_000; // 10 gwei
 const TEST_BLOB_BASE_FEE: u64 = 1_000_000_000; // 1 gwei
 const TEST_CHAIN_ID: u64 = 1; // Ethereum mainnet
-const TEST_GAS_LEFT: u64 = 100;
+const TEST_GAS_LEFT: u64 = 100 - GAS_OPCODE_COST; // gasleft() is reported after its own cost
 const TEST_CONTRACT_ADDRESS_ID: u8 = 5;
 const TEST_OWNER_ADDRESS_ID: u8 = 1;
 const TEST_COINBASE_ADDRESS_ID: u8 = 99;
@@ -132,6 +136,7 @@ fn test_base_info_contract() {
     test_transaction_origin(&fixture);
     test_gas_price(&fixture);
     test_gas_left(&fixture);
+    test_gas_left_twice(&fixture);
     test_chain_id(&fixture);
     test_base_fee(&fixture);
     test_blob_base_fee(&fixture);
@@ -326,6 +331,34 @@ fn test_gas_left(fixture: &BaseInfoTestFixture) {
     );
 }
 
+/// Test that consecutive gasleft() calls differ by exactly the GAS opcode cost
+fn test_gas_left_twice(fixture: &BaseInfoTestFixture) {
+    let mut context = MockContext::builder()
+        .with_code(fixture.wasm_bytes.clone())
+        .build();
+
+    let selector = calculate_selector("getGasleftTwice()");
+    let result = fixture
+        .call_function(&mut context, &selector, vec![])
+        .expect("Failed to call getGasleftTwice");
+
+    assert!(result.success, "getGasleftTwice should succeed");
+
+    let first = decode_uint256(&result.return_data[0..32])
+        .expect("Failed to decode first gas left from return data");
+    let second = decode_uint256(&result.return_data[32..64])
+        .expect("Failed to decode second gas left from return data");
+
+    assert_eq!(
+        first - second,
+        GAS_OPCODE_COST,
+        "Consecutive gasleft() calls should differ by {}, got {} and {}",
+        GAS_OPCODE_COST,
+        first,
+        second
+    );
+}
+
 /// Test chain ID retrieval
 fn test_chain_id(fixture: &BaseInfoTestFixture) {
     let mut context = MockContext::builder()
@@ -483,3 +516,929 @@ fn test_sha256_function(fixture: &BaseInfoTestFixture) {
         actual_hex
     );
 }
+
+/// Padded Address Result
+///
+/// Verifies that with padded address results enabled, getCaller writes a
+/// 32-byte ABI word (12 zero bytes followed by the address), and that by
+/// default it writes only the raw 20-byte address. The result buffer is
+/// pre-filled with 0xff so untouched bytes are visible.
+mod address_word {
+    use super::*;
+
+    const TEST_CALLER_ADDRESS_ID: u8 = 7;
+
+    /// Writes the caller at offset 0 of a 0xff-filled buffer and finishes with 32 bytes
+    const CALLER_WAT: &str = r#"
+(module
+  (import "env" "getCaller" (func $getCaller (param i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff\ff")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $getCaller (i32.const 0))
+    (call $finish (i32.const 0) (i32.const 32))))
+"#;
+
+    fn caller_result(padded: bool) -> Vec<u8> {
+        let result = run_wat(
+            CALLER_WAT,
+            MockContext::builder()
+                .with_caller(random_test_address(TEST_CALLER_ADDRESS_ID))
+                .with_padded_address_results(padded),
+        );
+        assert!(result.success, "Contract call should succeed");
+        result.return_data
+    }
+
+    #[test]
+    fn test_padded_caller_is_left_padded_word() {
+        let caller = random_test_address(TEST_CALLER_ADDRESS_ID);
+        let word = caller_result(true);
+
+        assert_eq!(&word[..12], &[0u8; 12]);
+        assert_eq!(&word[12..], &caller);
+    }
+
+    #[test]
+    fn test_caller_is_raw_address_by_default() {
+        let caller = random_test_address(TEST_CALLER_ADDRESS_ID);
+        let data = caller_result(false);
+
+        assert_eq!(&data[..20], &caller);
+        assert_eq!(&data[20..], &[0xffu8; 12]);
+    }
+}
+
+/// Base Fee History
+///
+/// Verifies that `getBaseFeeAt` returns the current base fee for offset 0,
+/// the seeded historical base fees for offsets inside the window, and zero
+/// outside it. A hand-written contract reads offsets 0 through 4 into
+/// consecutive 32-byte slots and returns them.
+mod base_fee_history {
+    use super::*;
+
+    /// Returns the base fees 0, 1, 2, 3 and 4 blocks ago
+    const BASE_FEE_HISTORY_WAT: &str = r#"
+(module
+  (import "env" "getBaseFeeAt" (func $getBaseFeeAt (param i64 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $getBaseFeeAt (i64.const 0) (i32.const 0))
+    (call $getBaseFeeAt (i64.const 1) (i32.const 32))
+    (call $getBaseFeeAt (i64.const 2) (i32.const 64))
+    (call $getBaseFeeAt (i64.const 3) (i32.const 96))
+    (call $getBaseFeeAt (i64.const 4) (i32.const 128))
+    (call $finish (i32.const 0) (i32.const 160))))
+"#;
+
+    fn fee(value: u64) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[24..].copy_from_slice(&value.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_get_base_fee_at_reads_history() {
+        let history = vec![fee(900), fee(800), fee(700)];
+
+        let result = run_wat(
+            BASE_FEE_HISTORY_WAT,
+            MockContext::builder()
+                .with_base_fee(fee(1000))
+                .with_base_fee_history(history.clone()),
+        );
+        assert!(result.success, "getBaseFeeAt should succeed");
+
+        let fees: Vec<&[u8]> = result.return_data.chunks(32).collect();
+        assert_eq!(fees.len(), 5);
+        assert_eq!(
+            fees[0],
+            &fee(1000),
+            "offset 0 should be the current base fee"
+        );
+        for (blocks_ago, expected) in history.iter().enumerate() {
+            assert_eq!(
+                fees[blocks_ago + 1],
+                expected,
+                "wrong base fee {} blocks ago",
+                blocks_ago + 1
+            );
+        }
+        assert_eq!(
+            fees[4], &[0u8; 32],
+            "offsets outside the history should read as zero"
+        );
+    }
+}
+
+/// Blob Hash Host Functions
+///
+/// Solidity has no way to query the number of blob hashes, so this test uses a
+/// hand-written contract that loops `getBlobHash` over `getBlobHashCount` and
+/// returns every hash it read. A second contract looks up the u256 index
+/// passed as call data to check the out-of-range behaviour.
+mod blob_hash {
+    use super::*;
+    use dtvmcore_rust::evm::traits::Hardfork;
+
+    /// Returns the blob hash count followed by each blob hash (32 bytes each)
+    /// The u256 index passed to getBlobHash is kept in a scratch word at 1024
+    const BLOB_HASH_ITERATOR_WAT: &str = r#"
+(module
+  (import "env" "getBlobHashCount" (func $getBlobHashCount (result i32)))
+  (import "env" "getBlobHash" (func $getBlobHash (param i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (local $count i32)
+    (local $i i32)
+    (local.set $count (call $getBlobHashCount))
+    (i32.store8 (i32.const 31) (local.get $count))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $i) (local.get $count)))
+        (i32.store8 (i32.const 1055) (local.get $i))
+        (call $getBlobHash
+          (i32.const 1024)
+          (i32.add (i32.const 32) (i32.mul (local.get $i) (i32.const 32))))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $next)))
+    (call $finish
+      (i32.const 0)
+      (i32.add (i32.const 32) (i32.mul (local.get $count) (i32.const 32))))))
+"#;
+
+    /// Returns the blob hash at the u256 index given as call data
+    const BLOB_HASH_LOOKUP_WAT: &str = r#"
+(module
+  (import "env" "callDataCopy" (func $callDataCopy (param i32 i32 i32)))
+  (import "env" "getBlobHash" (func $getBlobHash (param i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $callDataCopy (i32.const 0) (i32.const 0) (i32.const 32))
+    (call $getBlobHash (i32.const 0) (i32.const 32))
+    (call $finish (i32.const 32) (i32.const 32))))
+"#;
+
+    #[test]
+    fn test_blob_hash_count_and_iteration() {
+        let blob_hashes = vec![[0x01; 32], [0x02; 32], [0x03; 32]];
+
+        let result = run_wat(
+            BLOB_HASH_ITERATOR_WAT,
+            MockContext::builder()
+                .with_blob_hashes(blob_hashes.clone())
+                .with_gas_limit(1000000),
+        );
+
+        assert!(result.success, "Blob hash iteration should succeed");
+        assert_eq!(result.return_data.len(), 32 * (1 + blob_hashes.len()));
+
+        let count = decode_uint256(&result.return_data[0..32]).expect("Failed to decode count");
+        assert_eq!(count, 3, "Blob hash count should be 3");
+
+        for (index, expected) in blob_hashes.iter().enumerate() {
+            let start = 32 * (index + 1);
+            assert_eq!(
+                &result.return_data[start..start + 32],
+                expected,
+                "Blob hash {} mismatch",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_blob_hash_out_of_range_is_zero() {
+        let blob_hashes = vec![[0x01; 32], [0x02; 32]];
+
+        let wasm_bytes = wat::parse_str(BLOB_HASH_LOOKUP_WAT).expect("Failed to parse WAT");
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        let mut context = MockContext::builder()
+            .with_code(wasm_bytes)
+            .with_blob_hashes(blob_hashes)
+            .with_gas_limit(1000000)
+            .build();
+
+        let mut lookup = |index: [u8; 32]| {
+            context.set_call_data(index.to_vec());
+            let result = executor
+                .call_contract_function("BlobHashLookup", &mut context)
+                .expect("Failed to call contract");
+            assert!(result.success, "Blob hash lookup should succeed");
+            result.return_data
+        };
+
+        let mut index = [0u8; 32];
+        index[31] = 1;
+        assert_eq!(lookup(index), vec![0x02; 32]);
+
+        // Past the last blob
+        index[31] = 2;
+        assert_eq!(lookup(index), vec![0u8; 32]);
+
+        // Index 2^64 + 1 does not wrap around to 1
+        index[23] = 1;
+        index[31] = 1;
+        assert_eq!(lookup(index), vec![0u8; 32]);
+    }
+
+    #[test]
+    fn test_blob_hash_count_requires_cancun() {
+        let result = run_wat(
+            BLOB_HASH_ITERATOR_WAT,
+            MockContext::builder()
+                .with_blob_hashes(vec![[0x01; 32]])
+                .with_hardfork(Hardfork::Shanghai)
+                .with_gas_limit(1000000),
+        );
+
+        assert!(
+            !result.success,
+            "getBlobHashCount should fail before Cancun"
+        );
+    }
+}
+
+/// Packed Block Info
+///
+/// Verifies that `getBlockInfo` writes the block number, timestamp, gas limit,
+/// base fee and coinbase in its documented packed layout. A hand-written
+/// contract returns the 76-byte structure as-is.
+mod block_info {
+    use super::*;
+    use dtvmcore_rust::evm::BLOCK_INFO_SIZE;
+
+    const TEST_BLOCK_NUMBER: i64 = 12_345_678;
+    const TEST_BLOCK_TIMESTAMP: i64 = 1_700_000_000;
+    const TEST_BLOCK_GAS_LIMIT: i64 = 30_000_000;
+    const TEST_COINBASE_ADDRESS_ID: u8 = 9;
+
+    /// Returns the packed block info
+    const BLOCK_INFO_WAT: &str = r#"
+(module
+  (import "env" "getBlockInfo" (func $getBlockInfo (param i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $getBlockInfo (i32.const 0))
+    (call $finish (i32.const 0) (i32.const 76))))
+"#;
+
+    #[test]
+    fn test_get_block_info_packed_layout() {
+        let coinbase = random_test_address(TEST_COINBASE_ADDRESS_ID);
+        let mut base_fee = [0u8; 32];
+        base_fee[30..].copy_from_slice(&[0x12, 0x34]);
+
+        let result = run_wat(
+            BLOCK_INFO_WAT,
+            MockContext::builder()
+                .with_block_number(TEST_BLOCK_NUMBER)
+                .with_block_timestamp(TEST_BLOCK_TIMESTAMP)
+                .with_block_gas_limit(TEST_BLOCK_GAS_LIMIT)
+                .with_base_fee(base_fee)
+                .with_block_coinbase(coinbase),
+        );
+        assert!(result.success, "getBlockInfo should succeed");
+
+        let info = &result.return_data;
+        assert_eq!(info.len(), BLOCK_INFO_SIZE as usize);
+        let read_u64 =
+            |range: std::ops::Range<usize>| u64::from_be_bytes(info[range].try_into().unwrap());
+        assert_eq!(read_u64(0..8), TEST_BLOCK_NUMBER as u64);
+        assert_eq!(read_u64(8..16), TEST_BLOCK_TIMESTAMP as u64);
+        assert_eq!(read_u64(16..24), TEST_BLOCK_GAS_LIMIT as u64);
+        assert_eq!(&info[24..56], &base_fee);
+        assert_eq!(&info[56..76], &coinbase);
+    }
+}
+
+/// Chain ID Verification
+///
+/// Verifies the verifyChainId host function used for EIP-155 replay
+/// protection. A hand-written contract checks the configured chain ID against
+/// 1 (mainnet) and returns the resulting boolean word.
+mod chain_id {
+    use super::*;
+
+    const MAINNET_CHAIN_ID: u64 = 1;
+    const SEPOLIA_CHAIN_ID: u64 = 11_155_111;
+
+    /// Verifies the chain ID against 1 and finishes with the 32-byte boolean word
+    const VERIFY_CHAIN_ID_WAT: &str = r#"
+(module
+  (import "env" "verifyChainId" (func $verifyChainId (param i64 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $verifyChainId (i64.const 1) (i32.const 0))
+    (call $finish (i32.const 0) (i32.const 32))))
+"#;
+
+    fn verifies_as_mainnet(chain_id: u64) -> bool {
+        let result = run_wat(
+            VERIFY_CHAIN_ID_WAT,
+            MockContext::builder().with_chain_id_u64(chain_id),
+        );
+        assert!(result.success, "Contract call should succeed");
+
+        decode_bool(&result.return_data).expect("Failed to decode boolean word")
+    }
+
+    #[test]
+    fn test_verify_chain_id_matches() {
+        assert!(verifies_as_mainnet(MAINNET_CHAIN_ID));
+    }
+
+    #[test]
+    fn test_verify_chain_id_mismatches() {
+        assert!(!verifies_as_mainnet(SEPOLIA_CHAIN_ID));
+    }
+}
+
+/// Difficulty Host Function
+///
+/// DIFFICULTY and PREVRANDAO share an opcode. Verifies that `getDifficulty`
+/// returns the proof-of-work difficulty before the Merge and the previous
+/// randao from the Merge onwards.
+mod difficulty {
+    use super::*;
+    use dtvmcore_rust::evm::traits::Hardfork;
+
+    const TEST_PREV_RANDAO: [u8; 32] = [0x5a; 32];
+    const TEST_DIFFICULTY: [u8; 32] = [0x0d; 32];
+
+    /// Returns the 32-byte result of `getDifficulty`
+    const DIFFICULTY_WAT: &str = r#"
+(module
+  (import "env" "getDifficulty" (func $getDifficulty (param i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $getDifficulty (i32.const 0))
+    (call $finish (i32.const 0) (i32.const 32))))
+"#;
+
+    fn difficulty_under(hardfork: Hardfork) -> Vec<u8> {
+        let result = run_wat(
+            DIFFICULTY_WAT,
+            MockContext::builder()
+                .with_block_prev_randao(TEST_PREV_RANDAO)
+                .with_block_difficulty(TEST_DIFFICULTY)
+                .with_hardfork(hardfork),
+        );
+        assert!(result.success, "getDifficulty should succeed");
+
+        result.return_data
+    }
+
+    #[test]
+    fn test_difficulty_is_prev_randao_after_merge() {
+        assert_eq!(difficulty_under(Hardfork::Paris), TEST_PREV_RANDAO);
+        assert_eq!(difficulty_under(Hardfork::Cancun), TEST_PREV_RANDAO);
+        assert_eq!(difficulty_under(Hardfork::London), TEST_DIFFICULTY);
+    }
+}
+
+/// ECRECOVER
+///
+/// Verifies that `ecRecover` recovers the signer of a known signature and
+/// writes the zero address for an invalid one. The signature is over
+/// keccak256("hello dtvm") with the private key
+/// 0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318,
+/// whose address is 0x2c7536e3605d9c16a7a3d7b1898e529396a65c23.
+mod ec_recover {
+    use super::*;
+    use dtvmcore_rust::evm::precompiles::ECRECOVER_ADDRESS;
+    use dtvmcore_rust::evm::traits::EvmHost;
+
+    const MESSAGE_HASH: &str = "312dfb10cfb022caab6554ed430ef331f97092d3b414c82aa9d6d87779f955b3";
+    const SIGNATURE_V: u8 = 28;
+    const SIGNATURE_R: &str = "bb50e2d89a4ed70663d080659fe0ad4b9bc3e06c17a227433966cb59ceee020d";
+    const SIGNATURE_S: &str = "4caf6874289b258043699d0b5a31de5cc87bb7751bf587b991debbddde5e9a9f";
+    const SIGNER: &str = "2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+
+    fn word(hex_value: &str) -> [u8; 32] {
+        hex::decode(hex_value).unwrap().try_into().unwrap()
+    }
+
+    fn v_word(v: u8) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[31] = v;
+        word
+    }
+
+    /// Recovers the signer of (hash, v, r, s) and returns the 20-byte address
+    fn ec_recover_wat(v: u8) -> String {
+        let hex_bytes =
+            |bytes: &[u8]| -> String { bytes.iter().map(|b| format!("\\{:02x}", b)).collect() };
+        format!(
+            r#"
+(module
+  (import "env" "ecRecover" (func $ecRecover (param i32 i32 i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: hash, 32..64: v, 64..96: r, 96..128: s, 128..148: recovered address
+  (data (i32.const 0) "{hash}")
+  (data (i32.const 32) "{v}")
+  (data (i32.const 64) "{r}")
+  (data (i32.const 96) "{s}")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $ecRecover (i32.const 0) (i32.const 32) (i32.const 64) (i32.const 96) (i32.const 128))
+    (call $finish (i32.const 128) (i32.const 20))))
+"#,
+            hash = hex_bytes(&word(MESSAGE_HASH)),
+            v = hex_bytes(&v_word(v)),
+            r = hex_bytes(&word(SIGNATURE_R)),
+            s = hex_bytes(&word(SIGNATURE_S)),
+        )
+    }
+
+    fn recover_with_contract(v: u8) -> Vec<u8> {
+        let result = run_wat(&ec_recover_wat(v), MockContext::builder());
+        assert!(result.success, "ecRecover should succeed");
+        result.return_data
+    }
+
+    #[test]
+    fn test_ec_recover_known_signer() {
+        assert_eq!(
+            recover_with_contract(SIGNATURE_V),
+            hex::decode(SIGNER).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ec_recover_invalid_signature_returns_zero_address() {
+        // Only 27 and 28 are valid recovery values
+        assert_eq!(recover_with_contract(29), vec![0u8; 20]);
+    }
+
+    #[test]
+    fn test_ecrecover_precompile_call() {
+        let input = [
+            word(MESSAGE_HASH),
+            v_word(SIGNATURE_V),
+            word(SIGNATURE_R),
+            word(SIGNATURE_S),
+        ]
+        .concat();
+
+        let context = MockContext::builder().build();
+        let result = context.call_static(&ECRECOVER_ADDRESS, &[0u8; 20], &input, 10_000);
+        assert!(result.success, "ecrecover precompile should succeed");
+        assert_eq!(&result.return_data[..12], &[0u8; 12]);
+        assert_eq!(&result.return_data[12..], &hex::decode(SIGNER).unwrap()[..]);
+    }
+}
+
+/// Keccak-256 Host Function
+///
+/// Hashes a 1 MiB region of contract memory in place and checks the digest
+/// against the sha3 crate, and checks that the host function hashes with the
+/// context's Keccak-256 override.
+mod keccak {
+    use super::*;
+    use sha3::{Digest, Keccak256};
+
+    const REGION_SIZE: usize = 1024 * 1024;
+
+    /// Inputs this small are copied out of memory before they are hashed
+    const SMALL_REGION_SIZE: usize = 64;
+
+    /// Fills memory[0..size] with `i * 31 + 7` (mod 256), hashes it with
+    /// keccak256 into memory[size..size + 32] and returns the digest
+    fn hash_region_wat(size: usize) -> String {
+        format!(
+            r#"
+(module
+  (import "env" "keccak256" (func $keccak256 (param i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 17)
+  (func (export "deploy"))
+  (func (export "call")
+    (local $i i32)
+    (block $done
+      (loop $fill
+        (br_if $done (i32.ge_u (local.get $i) (i32.const {size})))
+        (i32.store8 (local.get $i)
+          (i32.add (i32.mul (local.get $i) (i32.const 31)) (i32.const 7)))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $fill)))
+    (call $keccak256 (i32.const 0) (i32.const {size}) (i32.const {size}))
+    (call $finish (i32.const {size}) (i32.const 32))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_keccak256_large_region_matches_sha3() {
+        let result = run_wat(
+            &hash_region_wat(REGION_SIZE),
+            MockContext::builder().with_gas_limit(100000000),
+        );
+
+        assert!(result.success, "hashing a 1 MiB region should succeed");
+
+        let region: Vec<u8> = (0..REGION_SIZE)
+            .map(|i| (i as u32).wrapping_mul(31).wrapping_add(7) as u8)
+            .collect();
+        let expected: [u8; 32] = Keccak256::digest(&region).into();
+        assert_eq!(
+            result.return_data,
+            expected.to_vec(),
+            "keccak256 of the memory region should match sha3"
+        );
+    }
+
+    fn sentinel_keccak(_data: &[u8]) -> [u8; 32] {
+        [0xab; 32]
+    }
+
+    /// Hashes a `size`-byte region with `sentinel_keccak` installed
+    fn hash_region_with_override(size: usize) -> Vec<u8> {
+        let result = run_wat(
+            &hash_region_wat(size),
+            MockContext::builder()
+                .with_gas_limit(100000000)
+                .with_keccak(sentinel_keccak),
+        );
+
+        assert!(
+            result.success,
+            "hashing a {} byte region should succeed",
+            size
+        );
+        result.return_data
+    }
+
+    #[test]
+    fn test_keccak256_host_function_uses_override() {
+        assert_eq!(
+            hash_region_with_override(REGION_SIZE),
+            vec![0xab; 32],
+            "keccak256 should hash large inputs with the installed override"
+        );
+        assert_eq!(
+            hash_region_with_override(SMALL_REGION_SIZE),
+            vec![0xab; 32],
+            "keccak256 should hash small inputs with the installed override"
+        );
+    }
+}
+
+/// Merkle Proof
+///
+/// Verifies that `verifyMerkleProof` accepts a valid proof for a leaf of a
+/// four-leaf tree built with sorted-pair keccak256 hashing (the OpenZeppelin
+/// convention) and rejects the same proof with one sibling tampered.
+mod merkle_proof {
+    use super::*;
+    use sha3::{Digest, Keccak256};
+
+    fn keccak(data: &[u8]) -> [u8; 32] {
+        Keccak256::digest(data).into()
+    }
+
+    /// Hash a pair of nodes in sorted order
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak(&[a, b].concat())
+        } else {
+            keccak(&[b, a].concat())
+        }
+    }
+
+    fn hex_bytes(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("\\{:02x}", b)).collect()
+    }
+
+    /// Verifies the same leaf and root against a valid and a tampered proof,
+    /// returning one result byte for each
+    fn merkle_wat(
+        leaf: [u8; 32],
+        proof: &[[u8; 32]; 2],
+        tampered: &[[u8; 32]; 2],
+        root: [u8; 32],
+    ) -> String {
+        format!(
+            r#"
+(module
+  (import "env" "verifyMerkleProof" (func $verifyMerkleProof (param i32 i32 i32 i32) (result i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: leaf, 32..96: proof, 96..160: tampered proof, 160..192: root, 192..194: results
+  (data (i32.const 0) "{leaf}")
+  (data (i32.const 32) "{proof}")
+  (data (i32.const 96) "{tampered}")
+  (data (i32.const 160) "{root}")
+  (func (export "deploy"))
+  (func (export "call")
+    (i32.store8 (i32.const 192)
+      (call $verifyMerkleProof (i32.const 0) (i32.const 32) (i32.const 2) (i32.const 160)))
+    (i32.store8 (i32.const 193)
+      (call $verifyMerkleProof (i32.const 0) (i32.const 96) (i32.const 2) (i32.const 160)))
+    (call $finish (i32.const 192) (i32.const 2))))
+"#,
+            leaf = hex_bytes(&leaf),
+            proof = hex_bytes(&proof.concat()),
+            tampered = hex_bytes(&tampered.concat()),
+            root = hex_bytes(&root),
+        )
+    }
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let leaves: Vec<[u8; 32]> = (0u8..4).map(|i| keccak(&[i])).collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        // Proof for leaf 2: its sibling, then the other half of the tree
+        let proof = [leaves[3], left];
+        let mut tampered = proof;
+        tampered[0][0] ^= 0x01;
+
+        let result = run_wat(
+            &merkle_wat(leaves[2], &proof, &tampered, root),
+            MockContext::builder(),
+        );
+        assert!(result.success, "verifyMerkleProof should succeed");
+        assert_eq!(result.return_data[0], 1, "Valid proof should verify");
+        assert_eq!(
+            result.return_data[1], 0,
+            "Tampered proof should be rejected"
+        );
+    }
+}
+
+/// Modexp Precompile
+///
+/// Verifies that calls routed to the modexp precompile (0x05) charge the
+/// EIP-2565 cost from the forwarded gas, fail with all of it consumed when the
+/// budget is too small, and return the left-padded result otherwise. Also
+/// checks the `modexp` host function against the EIP-198 test vectors.
+mod modexp_precompile {
+    use super::*;
+    use dtvmcore_rust::evm::gas::modexp_gas;
+    use dtvmcore_rust::evm::precompiles::{modexp_output, MODEXP_ADDRESS};
+    use dtvmcore_rust::evm::traits::EvmHost;
+
+    const TEST_CALLER_ADDRESS_ID: u8 = 1;
+
+    /// Encode a modexp precompile input
+    fn modexp_input(base: &[u8], exponent: &[u8], modulus: &[u8]) -> Vec<u8> {
+        let mut input = Vec::new();
+        for len in [base.len(), exponent.len(), modulus.len()] {
+            let mut word = [0u8; 32];
+            word[24..].copy_from_slice(&(len as u64).to_be_bytes());
+            input.extend_from_slice(&word);
+        }
+        input.extend_from_slice(base);
+        input.extend_from_slice(exponent);
+        input.extend_from_slice(modulus);
+        input
+    }
+
+    #[test]
+    fn test_modexp_gas_matches_eip2565() {
+        // EIP-198 example: 3^(p-1) mod p = 1 for the secp256k1 field prime
+        let exponent =
+            hex::decode("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e")
+                .unwrap();
+        let modulus =
+            hex::decode("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+                .unwrap();
+        let input = modexp_input(&[3], &exponent, &modulus);
+
+        // ceil(32 / 8)^2 * (256 - 1) / 3
+        assert_eq!(modexp_gas(1, 32, 32, &exponent), 1360);
+
+        let context = MockContext::builder().build();
+        let caller = random_test_address(TEST_CALLER_ADDRESS_ID);
+        let result = context.call_static(&MODEXP_ADDRESS, &caller, &input, 100_000);
+        assert!(result.success, "modexp should succeed with enough gas");
+        assert_eq!(result.gas_used, 1360);
+
+        let mut expected = vec![0u8; 32];
+        expected[31] = 1;
+        assert_eq!(result.return_data, expected);
+    }
+
+    #[test]
+    fn test_modexp_large_operands_need_enough_gas() {
+        // 256-byte operands with a 256-byte exponent whose head is all ones
+        let base = vec![0x02; 256];
+        let exponent = vec![0xff; 256];
+        let mut modulus = vec![0xff; 256];
+        modulus[255] = 0xfd;
+        let input = modexp_input(&base, &exponent, &modulus);
+
+        // ceil(256 / 8)^2 * (8 * (256 - 32) + 255) / 3
+        let cost = modexp_gas(256, 256, 256, &exponent[..32]);
+        assert_eq!(cost, 1024 * (8 * 224 + 255) / 3);
+
+        let context = MockContext::builder().build();
+        let caller = random_test_address(TEST_CALLER_ADDRESS_ID);
+
+        let tight = context.call_contract(
+            &MODEXP_ADDRESS,
+            &caller,
+            &[0u8; 32],
+            &input,
+            cost as i64 - 1,
+        );
+        assert!(
+            !tight.success,
+            "modexp should run out of gas under a tight budget"
+        );
+        assert_eq!(
+            tight.gas_used,
+            cost as i64 - 1,
+            "out of gas consumes all forwarded gas"
+        );
+        assert!(tight.return_data.is_empty());
+
+        let generous =
+            context.call_contract(&MODEXP_ADDRESS, &caller, &[0u8; 32], &input, 10_000_000);
+        assert!(
+            generous.success,
+            "modexp should succeed with a generous budget"
+        );
+        assert_eq!(generous.gas_used, cost as i64);
+        assert_eq!(generous.return_data.len(), 256);
+    }
+
+    #[test]
+    fn test_modexp_huge_exponent_with_empty_modulus() {
+        // base_len = 0, exp_len = u64::MAX, mod_len = 0 and no operand bytes
+        let mut input = vec![0u8; 96];
+        input[56..64].copy_from_slice(&u64::MAX.to_be_bytes());
+
+        let context = MockContext::builder().build();
+        let caller = random_test_address(TEST_CALLER_ADDRESS_ID);
+        let result = context.call_static(&MODEXP_ADDRESS, &caller, &input, 100_000);
+        assert!(
+            result.success,
+            "an empty modulus should succeed without reading the exponent"
+        );
+        assert_eq!(result.gas_used, 200);
+        assert!(result.return_data.is_empty());
+
+        assert!(modexp_output(&input, 0, u64::MAX, 0).is_empty());
+    }
+
+    #[test]
+    fn test_modexp_operands_past_the_input_read_as_zero() {
+        // The declared exponent runs past the end of the input, so the modulus is zero
+        let mut input = modexp_input(&[2], &[10], &[]);
+        input[56..64].copy_from_slice(&u64::MAX.to_be_bytes());
+        input[88..96].copy_from_slice(&4u64.to_be_bytes());
+        assert_eq!(modexp_output(&input, 1, u64::MAX, 4), vec![0u8; 4]);
+
+        // A modulus cut short is zero-padded on the right: 2^10 mod 0x0700 = 0x0400
+        let mut input = modexp_input(&[2], &[10], &[7]);
+        input[88..96].copy_from_slice(&2u64.to_be_bytes());
+        assert_eq!(modexp_output(&input, 1, 1, 2), vec![0x04, 0x00]);
+    }
+
+    /// Runs `input` through the `modexp` host function and returns `result_length` bytes
+    fn host_modexp(input: &[u8], result_length: usize) -> ContractExecutionResult {
+        let data: String = input.iter().map(|b| format!("\\{:02x}", b)).collect();
+        let wat = format!(
+            r#"
+(module
+  (import "env" "modexp" (func $modexp (param i32 i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "{data}")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $modexp (i32.const 0) (i32.const {input_length}) (i32.const 4096) (i32.const {result_length}))
+    (call $finish (i32.const 4096) (i32.const {result_length}))))
+"#,
+            input_length = input.len()
+        );
+
+        run_wat(&wat, MockContext::builder().with_gas_limit(1000000))
+    }
+
+    #[test]
+    fn test_modexp_host_function_eip198_vectors() {
+        let exponent =
+            hex::decode("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e")
+                .unwrap();
+        let modulus =
+            hex::decode("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+                .unwrap();
+
+        // 3^(p-1) mod p = 1
+        let result = host_modexp(&modexp_input(&[3], &exponent, &modulus), 32);
+        assert!(result.success, "modexp should succeed");
+        let mut expected = vec![0u8; 32];
+        expected[31] = 1;
+        assert_eq!(result.return_data, expected);
+
+        // An empty base reads as zero: 0^(p-1) mod p = 0
+        let result = host_modexp(&modexp_input(&[], &exponent, &modulus), 32);
+        assert!(result.success, "modexp should succeed");
+        assert_eq!(result.return_data, vec![0u8; 32]);
+    }
+
+    #[test]
+    fn test_modexp_host_function_zero_modulus() {
+        let result = host_modexp(&modexp_input(&[2], &[10], &[0, 0]), 32);
+        assert!(result.success, "modexp should succeed");
+        assert_eq!(result.return_data, vec![0u8; 32]);
+    }
+
+    #[test]
+    fn test_modexp_host_function_pads_result_longer_than_modulus() {
+        // 2^10 mod 7 = 2, written right-aligned into 32 bytes
+        let result = host_modexp(&modexp_input(&[2], &[10], &[7]), 32);
+        assert!(result.success, "modexp should succeed");
+        let mut expected = vec![0u8; 32];
+        expected[31] = 2;
+        assert_eq!(result.return_data, expected);
+    }
+}
+
+/// RIPEMD-160 and Keccak-512 Host Functions
+///
+/// Checks `ripemd160` and `keccak512` against known-answer digests.
+mod ripemd160 {
+    use super::*;
+
+    /// Hashes `input` with the host function `name` and returns `output_len` bytes of the result
+    fn hash_wat(name: &str, input: &[u8], output_len: usize) -> String {
+        let data: String = input.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "{name}" (func $hash (param i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "{data}")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $hash (i32.const 0) (i32.const {length}) (i32.const 256))
+    (call $finish (i32.const 256) (i32.const {output_len}))))
+"#,
+            length = input.len()
+        )
+    }
+
+    fn hash(name: &str, input: &[u8], output_len: usize) -> Vec<u8> {
+        let result = run_wat(
+            &hash_wat(name, input, output_len),
+            MockContext::builder().with_gas_limit(1000000),
+        );
+        assert!(result.success, "{} should succeed", name);
+        result.return_data
+    }
+
+    fn padded_ripemd160(digest_hex: &str) -> Vec<u8> {
+        let mut expected = vec![0u8; 12];
+        expected.extend(hex::decode(digest_hex).unwrap());
+        expected
+    }
+
+    #[test]
+    fn test_ripemd160_abc() {
+        assert_eq!(
+            hash("ripemd160", b"abc", 32),
+            padded_ripemd160("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")
+        );
+    }
+
+    #[test]
+    fn test_ripemd160_empty_input() {
+        assert_eq!(
+            hash("ripemd160", b"", 32),
+            padded_ripemd160("9c1185a5c5e9fc54612808977ee8f548b2258d31")
+        );
+    }
+
+    #[test]
+    fn test_keccak512_empty_input() {
+        let expected = hex::decode(
+            "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304\
+         c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e",
+        )
+        .unwrap();
+        assert_eq!(hash("keccak512", b"", 64), expected);
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cheatcode Integration Test
+//!
+//! Verifies the test-only cheatcode host functions with hand-written
+//! contracts, which import them from the `cheats` host module. Requires the
+//! `cheatcodes` feature:
+//!
+//! ```text
+//! cargo test --features cheatcodes --test cheatcode_test
+//! ```
+
+#![cfg(feature = "cheatcodes")]
+
+mod common;
+
+use common::*;
+use dtvmcore_rust::evm::traits::EvmHost;
+
+const TEST_BLOCK_TIMESTAMP: i64 = 1_700_000_000;
+const TEST_WARPED_TIMESTAMP: i64 = 1_800_000_000;
+const TEST_BLOCK_NUMBER: i64 = 1_000;
+const TEST_ROLLED_BLOCK_NUMBER: i64 = 1_100;
+// Within 256 blocks of TEST_BLOCK_NUMBER, but not of TEST_ROLLED_BLOCK_NUMBER
+const TEST_HASHED_BLOCK: i64 = 800;
+const TEST_ECHO_ADDRESS_ID: u8 = 8;
+const TEST_WHALE_ADDRESS_ID: u8 = 9;
+const TEST_FUNDED_ADDRESS_ID: u8 = 10;
+const TEST_DEAL_AMOUNT_WEI: u128 = 100 * 1_000_000_000_000_000_000;
+
+/// Returns its caller (20 bytes)
+const ECHO_CALLER_WAT: &str = r#"
+(module
+  (import "env" "getCaller" (func $getCaller (param i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $getCaller (i32.const 0))
+    (call $finish (i32.const 0) (i32.const 20))))
+"#;
+
+/// Reads the timestamp, warps it, reads it again and returns both readings
+/// as little-endian i64 values
+const WARP_WAT: &str = r#"
+(module
+  (import "env" "getBlockTimestamp" (func $getBlockTimestamp (result i64)))
+  (import "cheats" "warp" (func $warp (param i64)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (i64.store (i32.const 0) (call $getBlockTimestamp))
+    (call $warp (i64.const 1800000000))
+    (i64.store (i32.const 8) (call $getBlockTimestamp))
+    (call $finish (i32.const 0) (i32.const 16))))
+"#;
+
+/// Reads the block number and whether block 800 has a hash, rolls to block 1100
+/// and reads both again; returns the four readings as little-endian i64 values
+const ROLL_WAT: &str = r#"
+(module
+  (import "env" "getBlockNumber" (func $getBlockNumber (result i64)))
+  (import "env" "getBlockHash" (func $getBlockHash (param i64 i32) (result i32)))
+  (import "cheats" "roll" (func $roll (param i64)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (i64.store (i32.const 0) (call $getBlockNumber))
+    (i32.store (i32.const 8) (call $getBlockHash (i64.const 800) (i32.const 64)))
+    (call $roll (i64.const 1100))
+    (i64.store (i32.const 16) (call $getBlockNumber))
+    (i32.store (i32.const 24) (call $getBlockHash (i64.const 800) (i32.const 64)))
+    (call $finish (i32.const 0) (i32.const 32))))
+"#;
+
+/// Calls `echo` three times, pranking `whale` before the second call only,
+/// and returns the three callers seen by `echo` (20 bytes each)
+fn prank_wat(echo: [u8; 20], whale: [u8; 20]) -> String {
+    let echo: String = echo.iter().map(|b| format!("\\{:02x}", b)).collect();
+    let whale: String = whale.iter().map(|b| format!("\\{:02x}", b)).collect();
+    format!(
+        r#"
+(module
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "returnDataCopy" (func $returnDataCopy (param i32 i32 i32)))
+  (import "cheats" "prank" (func $prank (param i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: echo address, 32..64: zero call value, 64..84: whale address, 96..156: callers
+  (data (i32.const 0) "{echo}")
+  (data (i32.const 64) "{whale}")
+  (func $callEcho (param $result i32)
+    (drop (call $callContract (i64.const -1) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))
+    (call $returnDataCopy (local.get $result) (i32.const 0) (i32.const 20)))
+  (func (export "deploy"))
+  (func (export "call")
+    (call $callEcho (i32.const 96))
+    (call $prank (i32.const 64))
+    (call $callEcho (i32.const 116))
+    (call $callEcho (i32.const 136))
+    (call $finish (i32.const 96) (i32.const 60))))
+"#
+    )
+}
+
+/// Deals `amount` to `account` and returns its balance read back through
+/// getExternalBalance (32 bytes)
+fn deal_wat(account: [u8; 20], amount: [u8; 32]) -> String {
+    let account: String = account.iter().map(|b| format!("\\{:02x}", b)).collect();
+    let amount: String = amount.iter().map(|b| format!("\\{:02x}", b)).collect();
+    format!(
+        r#"
+(module
+  (import "cheats" "deal" (func $deal (param i32 i32)))
+  (import "env" "getExternalBalance" (func $getExternalBalance (param i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: account address, 32..64: amount, 64..96: balance read back
+  (data (i32.const 0) "{account}")
+  (data (i32.const 32) "{amount}")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $deal (i32.const 0) (i32.const 32))
+    (call $getExternalBalance (i32.const 0) (i32.const 64))
+    (call $finish (i32.const 64) (i32.const 32))))
+"#
+    )
+}
+
+fn reading(data: &[u8], index: usize) -> i64 {
+    i64::from_le_bytes(data[index * 8..index * 8 + 8].try_into().unwrap())
+}
+
+#[test]
+fn test_warp_changes_block_timestamp() {
+    let (result, context) = run_wat_with_context(
+        WARP_WAT,
+        MockContext::builder().with_block_timestamp(TEST_BLOCK_TIMESTAMP),
+    );
+    assert!(result.success, "Warp call should succeed");
+
+    assert_eq!(reading(&result.return_data, 0), TEST_BLOCK_TIMESTAMP);
+    assert_eq!(reading(&result.return_data, 1), TEST_WARPED_TIMESTAMP);
+    assert_eq!(context.get_block_timestamp(), TEST_WARPED_TIMESTAMP);
+}
+
+#[test]
+fn test_roll_changes_block_number_and_hash_window() {
+    let (result, context) = run_wat_with_context(
+        ROLL_WAT,
+        MockContext::builder().with_block_number(TEST_BLOCK_NUMBER),
+    );
+    assert!(result.success, "Roll call should succeed");
+
+    assert_eq!(reading(&result.return_data, 0), TEST_BLOCK_NUMBER);
+    assert_eq!(
+        reading(&result.return_data, 1),
+        1,
+        "Block {} should have a hash",
+        TEST_HASHED_BLOCK
+    );
+    assert_eq!(reading(&result.return_data, 2), TEST_ROLLED_BLOCK_NUMBER);
+    assert_eq!(
+        reading(&result.return_data, 3),
+        0,
+        "Block {} should be outside the hash window after rolling",
+        TEST_HASHED_BLOCK
+    );
+    assert_eq!(context.get_block_number(), TEST_ROLLED_BLOCK_NUMBER);
+}
+
+#[test]
+fn test_prank_overrides_caller_of_next_call_only() {
+    let echo = random_test_address(TEST_ECHO_ADDRESS_ID);
+    let whale = random_test_address(TEST_WHALE_ADDRESS_ID);
+
+    let result = run_wat(
+        &prank_wat(echo, whale),
+        MockContext::builder().with_contract(
+            echo,
+            "Echo",
+            wat::parse_str(ECHO_CALLER_WAT).expect("Failed to parse echo WAT"),
+        ),
+    );
+    assert!(result.success, "Prank call should succeed");
+
+    let callers: Vec<&[u8]> = result.return_data.chunks(20).collect();
+    assert_eq!(
+        callers[1], &whale,
+        "Pranked call should see the whale as caller"
+    );
+    assert_ne!(callers[0], &whale);
+    assert_eq!(
+        callers[2], callers[0],
+        "Calls after the pranked one should see the real caller again"
+    );
+}
+
+#[test]
+fn test_deal_sets_balance_immediately() {
+    let account = random_test_address(TEST_FUNDED_ADDRESS_ID);
+    let mut amount = [0u8; 32];
+    amount[16..].copy_from_slice(&TEST_DEAL_AMOUNT_WEI.to_be_bytes());
+
+    let (result, context) =
+        run_wat_with_context(&deal_wat(account, amount), MockContext::builder());
+    assert!(result.success, "Deal call should succeed");
+
+    assert_eq!(
+        result.return_data, amount,
+        "getExternalBalance should return the dealt amount"
+    );
+    assert_eq!(context.get_external_balance(&account), amount);
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ABI-aware assertions for contract return data
+//!
+//! The `assert_returns!` macro decodes the return data of a contract call
+//! against the expected ABI types and compares every value, e.g.
+//!
+//! ```ignore
+//! assert_returns!(result, (uint256 => 100u64), (address => owner));
+//! ```
+//!
+//! Supported kinds: `uint256`, `address`, `bool`, `bytes32`, `bytes`, `string`.
+//!
+//! Negative paths use `expect_revert`, which checks that a call reverted and
+//! optionally checks its decoded revert reason.
+
+#![allow(dead_code)]
+
+use ethabi::{ParamType, Token};
+use evm_example::contract_executor::ContractExecutionResult;
+
+/// Expected return value paired with its ABI type
+pub type ExpectedValue = (ParamType, Token);
+
+/// Expect a uint256 return value
+pub fn uint256<T: Into<ethabi::Uint>>(value: T) -> ExpectedValue {
+    (ParamType::Uint(256), Token::Uint(value.into()))
+}
+
+/// Expect an address return value
+pub fn address(value: [u8; 20]) -> ExpectedValue {
+    (ParamType::Address, Token::Address(value.into()))
+}
+
+/// Expect a bool return value
+pub fn bool(value: bool) -> ExpectedValue {
+    (ParamType::Bool, Token::Bool(value))
+}
+
+/// Expect a bytes32 return value
+pub fn bytes32(value: [u8; 32]) -> ExpectedValue {
+    (ParamType::FixedBytes(32), Token::FixedBytes(value.to_vec()))
+}
+
+/// Expect a dynamic bytes return value
+pub fn bytes<T: AsRef<[u8]>>(value: T) -> ExpectedValue {
+    (ParamType::Bytes, Token::Bytes(value.as_ref().to_vec()))
+}
+
+/// Expect a string return value
+pub fn string<T: Into<String>>(value: T) -> ExpectedValue {
+    (ParamType::String, Token::String(value.into()))
+}
+
+/// Format a token for assertion messages (decimal integers, 0x-prefixed hex bytes)
+fn format_token(token: &Token) -> String {
+    match token {
+        Token::Uint(value) => value.to_string(),
+        Token::Address(addr) => format!("0x{}", hex::encode(addr.as_bytes())),
+        Token::FixedBytes(data) | Token::Bytes(data) => format!("0x{}", hex::encode(data)),
+        Token::Bool(value) => value.to_string(),
+        Token::String(value) => format!("{:?}", value),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Decode the return data of `result` and assert it matches `expected`
+///
+/// Panics with a message naming every mismatching return index, its ABI type
+/// and the expected/actual values.
+pub fn check_returns(result: &ContractExecutionResult, expected: Vec<ExpectedValue>) {
+    assert!(
+        result.success,
+        "Expected successful call, got failure: {}",
+        result
+            .error_message
+            .as_deref()
+            .unwrap_or("no error message")
+    );
+
+    let (types, expected_tokens): (Vec<ParamType>, Vec<Token>) = expected.into_iter().unzip();
+
+    let actual_tokens = ethabi::decode(&types, &result.return_data).unwrap_or_else(|e| {
+        panic!(
+            "Failed to decode return data as ({}): {} (data: 0x{})",
+            types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            e,
+            hex::encode(&result.return_data)
+        )
+    });
+
+    let mismatches: Vec<String> = types
+        .iter()
+        .zip(expected_tokens.iter().zip(actual_tokens.iter()))
+        .enumerate()
+        .filter(|(_, (_, (expected, actual)))| expected != actual)
+        .map(|(index, (param_type, (expected, actual)))| {
+            format!(
+                "  return[{}] ({}): expected {}, got {}",
+                index,
+                param_type,
+                format_token(expected),
+                format_token(actual)
+            )
+        })
+        .collect();
+
+    assert!(
+        mismatches.is_empty(),
+        "Return value mismatch:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+/// Assert that `result` reverted, and that its reason is `expected_reason` if given
+///
+/// The reason is decoded by `ContractExecutionResult::revert_reason`.
+pub fn expect_revert(result: &ContractExecutionResult, expected_reason: Option<&str>) {
+    assert!(
+        result.is_reverted,
+        "Expected the call to revert, got {} (data: 0x{})",
+        if result.success {
+            "success"
+        } else {
+            "a non-revert failure"
+        },
+        hex::encode(&result.return_data)
+    );
+
+    let Some(expected_reason) = expected_reason else {
+        return;
+    };
+
+    let reason = result.revert_reason().unwrap_or_else(|| {
+        panic!(
+            "Revert data has no decodable reason: 0x{}",
+            hex::encode(&result.return_data)
+        )
+    });
+    assert_eq!(reason, expected_reason, "Revert reason mismatch");
+}
+
+/// Assert that a `ContractExecutionResult` returns the given ABI values
+#[macro_export]
+macro_rules! assert_returns {
+    ($result:expr, $( ($kind:ident => $value:expr) ),+ $(,)?) => {
+        $crate::common::assert::check_returns(
+            &$result,
+            vec![$( $crate::common::assert::$kind($value) ),+],
+        )
+    };
+}
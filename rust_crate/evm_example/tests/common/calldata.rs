@@ -5,7 +5,7 @@
 
 use crate::calculate_selector;
 use crate::random_test_address;
-use ethabi::{encode, Token};
+use ethabi::{encode, Int, Token};
 
 /// Flexible parameter builder for constructing Vec<Token> in a fluent way
 /// Users can chain method calls to build complex parameter lists
@@ -56,9 +56,28 @@ impl ParamBuilder {
         self
     }
 
-    /// Add an int256 parameter
-    pub fn int256(mut self, value: i64) -> Self {
-        self.tokens.push(Token::Int(value.into()));
+    /// Add an int256 parameter, encoded as 32-byte two's complement
+    pub fn int256(self, value: i64) -> Self {
+        self.int256_i128(value.into())
+    }
+
+    /// Add an int256 parameter from an i128, encoded as 32-byte two's complement
+    pub fn int256_i128(mut self, value: i128) -> Self {
+        let fill = if value < 0 { 0xff } else { 0x00 };
+        let mut word = [fill; 32];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        self.tokens.push(Token::Int(Int::from_big_endian(&word)));
+        self
+    }
+
+    /// Add a bytesN parameter (bytes1 to bytes32), right-padded to 32 bytes
+    pub fn bytes_n(mut self, data: &[u8]) -> Self {
+        assert!(
+            (1..=32).contains(&data.len()),
+            "bytesN must be 1 to 32 bytes long, got {}",
+            data.len()
+        );
+        self.tokens.push(Token::FixedBytes(data.to_vec()));
         self
     }
 
@@ -112,14 +131,27 @@ impl ParamBuilder {
     }
 }
 
-/// Convenience functions to build common parameter patterns
-/// Users can use these for simple cases or use ParamBuilder for complex cases
+// Convenience functions to build common parameter patterns
+// Users can use these for simple cases or use ParamBuilder for complex cases
 
 /// Build no parameters
 pub fn params_none() -> Vec<Token> {
     ParamBuilder::new().build()
 }
 
+/// Build call data for `signature` (e.g. "transfer(address,uint256)")
+/// The 4-byte selector is followed by the ABI-encoded `tokens`
+pub fn encode_call(signature: &str, tokens: &[Token]) -> Vec<u8> {
+    let mut call_data = calculate_selector(signature).to_vec();
+    call_data.extend_from_slice(&encode(tokens));
+    call_data
+}
+
+/// Build constructor arguments, which are ABI-encoded without a selector
+pub fn encode_constructor(tokens: &[Token]) -> Vec<u8> {
+    encode(tokens)
+}
+
 /// Unified function to set call data with selector and tokens
 /// This is the main function users should use - pass Vec<Token> directly
 pub fn set_call_data_with_params(
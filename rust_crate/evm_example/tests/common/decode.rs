@@ -105,3 +105,13 @@ pub fn decode_call_result(data: &[u8]) -> Result<(bool, Vec<u8>), String> {
 
     Ok((success, bytes_data))
 }
+
+/// Helper function to split custom-error revert data into its selector and ABI-encoded arguments
+pub fn decode_custom_error(data: &[u8]) -> Result<([u8; 4], Vec<u8>), String> {
+    if data.len() < 4 {
+        return Err("Data too short for custom error selector".to_string());
+    }
+
+    let selector: [u8; 4] = data[0..4].try_into().map_err(|_| "Invalid selector")?;
+    Ok((selector, data[4..].to_vec()))
+}
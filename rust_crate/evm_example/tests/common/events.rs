@@ -0,0 +1,135 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(dead_code)]
+
+use dtvmcore_rust::LogEvent;
+use ethabi::{decode, encode, ParamType, Token};
+use sha3::{Digest, Keccak256};
+
+/// Construct expected log events from an event signature and ABI tokens
+pub trait LogEventBuilder {
+    /// Build a log event as emitted by `address` for the event `signature`
+    ///
+    /// topic0 is keccak256(signature), each indexed param becomes a topic
+    /// (dynamic values are hashed) and the remaining params are ABI-encoded as data.
+    fn build(address: [u8; 20], signature: &str, indexed: &[Token], data: &[Token]) -> Self;
+}
+
+impl LogEventBuilder for LogEvent {
+    fn build(address: [u8; 20], signature: &str, indexed: &[Token], data: &[Token]) -> Self {
+        let mut topics = Vec::with_capacity(indexed.len() + 1);
+        topics.push(keccak(signature.as_bytes()));
+        topics.extend(indexed.iter().map(encode_topic));
+
+        LogEvent {
+            contract_address: address,
+            data: encode(data),
+            topics,
+        }
+    }
+}
+
+/// Decode emitted log events back into ABI tokens
+pub trait LogEventDecoder {
+    /// Decode a log event for the event `signature`
+    ///
+    /// Checks that topic0 is keccak256(signature) and that there is one topic per
+    /// indexed param, then returns the indexed tokens followed by the data tokens.
+    /// Dynamic indexed params are only stored as a hash and decode to that hash.
+    fn decode(
+        &self,
+        signature: &str,
+        indexed: &[ParamType],
+        data_params: &[ParamType],
+    ) -> Result<Vec<Token>, String>;
+}
+
+impl LogEventDecoder for LogEvent {
+    fn decode(
+        &self,
+        signature: &str,
+        indexed: &[ParamType],
+        data_params: &[ParamType],
+    ) -> Result<Vec<Token>, String> {
+        let (topic0, topics) = self.topics.split_first().ok_or("Log event has no topics")?;
+        if *topic0 != keccak(signature.as_bytes()) {
+            return Err(format!("topic0 does not match {}", signature));
+        }
+        if topics.len() != indexed.len() {
+            return Err(format!(
+                "Expected {} indexed params, found {} topics",
+                indexed.len(),
+                topics.len()
+            ));
+        }
+
+        let mut tokens = indexed
+            .iter()
+            .zip(topics)
+            .map(|(param, topic)| decode_topic(param, topic))
+            .collect::<Result<Vec<_>, _>>()?;
+        tokens.extend(
+            decode(data_params, &self.data)
+                .map_err(|e| format!("Failed to decode log data: {}", e))?,
+        );
+        Ok(tokens)
+    }
+}
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Encode an indexed event parameter as a topic
+fn encode_topic(token: &Token) -> [u8; 32] {
+    match token {
+        // Dynamic values are stored as the hash of their contents
+        Token::String(value) => keccak(value.as_bytes()),
+        Token::Bytes(value) => keccak(value),
+        Token::Array(_) | Token::FixedArray(_) | Token::Tuple(_) => keccak(&encode_in_place(token)),
+        // Static values are stored as their 32-byte ABI encoding
+        _ => {
+            let mut topic = [0u8; 32];
+            topic.copy_from_slice(&encode(std::slice::from_ref(token)));
+            topic
+        }
+    }
+}
+
+/// Encode an indexed array or struct element as Solidity hashes it into a topic
+///
+/// Unlike ABI encoding there are no head, offset or length words: arrays and
+/// structs are the concatenation of their encoded elements, and strings and
+/// bytes are their contents padded to a multiple of 32 bytes.
+fn encode_in_place(token: &Token) -> Vec<u8> {
+    match token {
+        Token::String(value) => pad_to_word(value.as_bytes()),
+        Token::Bytes(value) => pad_to_word(value),
+        Token::Array(tokens) | Token::FixedArray(tokens) | Token::Tuple(tokens) => {
+            tokens.iter().flat_map(encode_in_place).collect()
+        }
+        _ => encode(std::slice::from_ref(token)),
+    }
+}
+
+fn pad_to_word(data: &[u8]) -> Vec<u8> {
+    let mut padded = data.to_vec();
+    padded.resize(data.len().div_ceil(32) * 32, 0);
+    padded
+}
+
+/// Decode a topic as an indexed event parameter
+fn decode_topic(param: &ParamType, topic: &[u8; 32]) -> Result<Token, String> {
+    match param {
+        // Dynamic values are stored as the hash of their contents
+        ParamType::String
+        | ParamType::Bytes
+        | ParamType::Array(_)
+        | ParamType::FixedArray(..)
+        | ParamType::Tuple(_) => Ok(Token::FixedBytes(topic.to_vec())),
+        _ => decode(std::slice::from_ref(param), topic)
+            .map(|mut tokens| tokens.remove(0))
+            .map_err(|e| format!("Failed to decode topic as {}: {}", param, e)),
+    }
+}
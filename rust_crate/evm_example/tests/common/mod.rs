@@ -7,14 +7,19 @@
 #![allow(unused_imports)]
 #![allow(dead_code)]
 
+pub mod assert;
 pub mod calldata;
 pub mod decode;
+pub mod events;
 
-pub use evm_example::contract_executor::ContractExecutor;
-pub use evm_example::mock_context::{BlockInfo, ContractInfo, MockContext};
+pub use dtvmcore_rust::LogEvent;
+pub use evm_example::contract_executor::{ContractExecutionResult, ContractExecutor};
+pub use evm_example::mock_context::{BlockInfo, ContractInfo, MockContext, MockContextBuilder};
 
+pub use assert::expect_revert;
 pub use calldata::*;
 pub use decode::*;
+pub use events::{LogEventBuilder, LogEventDecoder};
 
 use std::fs;
 
@@ -34,3 +39,23 @@ pub fn random_test_address(byte: u8) -> [u8; 20] {
     addr[19] = byte; // Set the last byte to distinguish addresses
     addr
 }
+
+/// Run the `call` export of a hand-written WAT contract in a context built by `builder`
+pub fn run_wat(wat: &str, builder: MockContextBuilder) -> ContractExecutionResult {
+    run_wat_with_context(wat, builder).0
+}
+
+/// Like `run_wat`, but also returns the context so its state can be inspected
+pub fn run_wat_with_context(
+    wat: &str,
+    builder: MockContextBuilder,
+) -> (ContractExecutionResult, MockContext) {
+    let executor = ContractExecutor::new().expect("Failed to create executor");
+    let mut context = builder
+        .with_code(wat::parse_str(wat).expect("Failed to parse WAT"))
+        .build();
+    let result = executor
+        .call_contract_function("WatContract", &mut context)
+        .expect("Failed to call contract");
+    (result, context)
+}
@@ -8,12 +8,19 @@
 //! - Static calls (read-only operations)
 //! - Delegate calls (execution in caller's context)
 //! - Contract creation (CREATE and CREATE2)
+//!
+//! Call semantics that need exact control over each frame (gas forwarding,
+//! msg.sender, depth, revert and static isolation, value transfer) are checked
+//! with hand-written WAT contracts run by `run_wat`, one module per behaviour.
 
 mod common;
 
 use common::calldata::{set_call_data_with_params, ParamBuilder};
 use common::*;
+use dtvmcore_rust::evm::traits::{create2_address, create_address};
 use ethabi::encode;
+use evm_example::scenario::Scenario;
+use sha3::{Digest, Keccak256};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -25,8 +32,8 @@ const TEST_CREATE2_VALUE: u64 = 456;
 const TEST_OWNER_ADDRESS_ID: u8 = 1;
 const TEST_CALLS_CONTRACT_ADDRESS_ID: u8 = 10;
 const TEST_TARGET_CONTRACT_ADDRESS_ID: u8 = 20;
-const TEST_CREATE_RESULT_ADDRESS_ID: u8 = 9;
-const TEST_CREATE2_RESULT_ADDRESS_ID: u8 = 99;
+const TEST_ORACLE_ADDRESS_ID: u8 = 30;
+const TEST_ORACLE_PRICE: u64 = 2_000;
 
 const TEST_CREATE2_SALT: [u8; 32] = [
     0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
@@ -73,6 +80,19 @@ mod selectors {
     pub fn get_value() -> [u8; 4] {
         calculate_selector("getValue()")
     }
+
+    pub fn get_state() -> [u8; 4] {
+        calculate_selector("getState()")
+    }
+
+    // Oracle operations
+    pub fn get_oracle_price() -> [u8; 4] {
+        calculate_selector("getOraclePrice(address)")
+    }
+
+    pub fn latest_price() -> [u8; 4] {
+        calculate_selector("latestPrice()")
+    }
 }
 
 /// Test fixture for ContractCalls integration tests
@@ -173,9 +193,12 @@ fn test_contract_calls() {
     // Run all test cases with fresh contexts for each test
     test_call(&fixture);
     test_static_call(&fixture);
+    test_static_call_rejects_state_change(&fixture);
     test_delegate_call(&fixture);
     test_create(&fixture);
     test_create2(&fixture);
+    test_target_state(&fixture);
+    test_mocked_oracle_call(&fixture);
 }
 
 /// Test regular contract call functionality
@@ -185,13 +208,10 @@ fn test_call(fixture: &ContractCallsTestFixture) {
         .expect("Failed to create fresh context");
 
     // Prepare call data for setValue(100) on the target contract
-    let target_call_data = {
-        let mut data = selectors::set_value().to_vec();
-        data.extend_from_slice(&encode(
-            &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
-        ));
-        data
-    };
+    let target_call_data = encode_call(
+        "setValue(uint256)",
+        &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
+    );
 
     let target_address = random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID);
     let params = ParamBuilder::new()
@@ -215,6 +235,139 @@ fn test_call(fixture: &ContractCallsTestFixture) {
         EXPECTED_CALL_RETURN_DATA, return_data
     );
 }
+
+/// The regular call scenario expressed through `Scenario` must match the
+/// hand-written fixture
+#[test]
+fn test_call_via_scenario() {
+    let fixture = ContractCallsTestFixture::new().expect("Failed to create test fixture");
+
+    let owner_address = random_test_address(TEST_OWNER_ADDRESS_ID);
+    let calls_address = random_test_address(TEST_CALLS_CONTRACT_ADDRESS_ID);
+    let target_address = random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID);
+
+    let target_call_data = encode_call(
+        "setValue(uint256)",
+        &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
+    );
+    let params = ParamBuilder::new()
+        .address(&target_address)
+        .bytes(&target_call_data)
+        .build();
+
+    // Hand-written setup
+    let mut context = fixture
+        .create_fresh_context()
+        .expect("Failed to create fresh context");
+    let expected = fixture
+        .call_function(&mut context, &selectors::test_call(), params.clone())
+        .expect("Failed to call testCall()");
+
+    // Same setup through the declarative spec
+    let results = Scenario::new()
+        .deployer(owner_address)
+        .contract(
+            "SimpleTarget.wasm",
+            target_address,
+            fixture.simple_target_wasm.clone(),
+        )
+        .contract(
+            "ContractCalls.wasm",
+            calls_address,
+            fixture.contract_calls_wasm.clone(),
+        )
+        .call(
+            owner_address,
+            calls_address,
+            encode_call("testCall(address,bytes)", &params),
+        )
+        .run()
+        .expect("Scenario should run");
+
+    assert_eq!(results.len(), 1);
+    let actual = &results[0];
+    assert!(actual.success, "testCall() should succeed in the scenario");
+    assert_eq!(actual.success, expected.success);
+    assert_eq!(actual.return_data, expected.return_data);
+
+    let (call_success, return_data) =
+        decode_call_result(&actual.return_data).expect("Failed to decode call result");
+    assert!(call_success, "Contract call should succeed");
+    assert_eq!(return_data, EXPECTED_CALL_RETURN_DATA);
+}
+
+/// Contracts registered through the builder are callable from each other and
+/// land in a registry shared via `with_contract_registry`
+#[test]
+fn test_builder_registers_contracts() {
+    let fixture = ContractCallsTestFixture::new().expect("Failed to create test fixture");
+
+    let owner_address = random_test_address(TEST_OWNER_ADDRESS_ID);
+    let calls_address = random_test_address(TEST_CALLS_CONTRACT_ADDRESS_ID);
+    let target_address = random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID);
+    let storage = Rc::new(RefCell::new(HashMap::new()));
+    let registry = Rc::new(RefCell::new(HashMap::new()));
+
+    let mut target_context = MockContext::builder()
+        .with_storage(storage.clone())
+        .with_contract_registry(registry.clone())
+        .with_contracts([
+            (
+                target_address,
+                "SimpleTarget.wasm",
+                fixture.simple_target_wasm.clone(),
+            ),
+            (
+                calls_address,
+                "ContractCalls.wasm",
+                fixture.contract_calls_wasm.clone(),
+            ),
+        ])
+        .with_code(fixture.simple_target_wasm.clone())
+        .with_caller(owner_address)
+        .with_address(target_address)
+        .build();
+    assert_eq!(
+        registry.borrow().len(),
+        2,
+        "Builder should fill the shared registry"
+    );
+    fixture
+        .executor
+        .deploy_contract("simple_target", &mut target_context)
+        .expect("Failed to deploy target");
+
+    let mut context = MockContext::builder()
+        .with_storage(storage)
+        .with_contract_registry(registry.clone())
+        .with_code(fixture.contract_calls_wasm.clone())
+        .with_caller(owner_address)
+        .with_address(calls_address)
+        .build();
+    fixture
+        .executor
+        .deploy_contract("contract_calls", &mut context)
+        .expect("Failed to deploy caller");
+
+    let target_call_data = encode_call(
+        "setValue(uint256)",
+        &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
+    );
+    let params = ParamBuilder::new()
+        .address(&target_address)
+        .bytes(&target_call_data)
+        .build();
+    let result = fixture
+        .call_function(&mut context, &selectors::test_call(), params)
+        .expect("Failed to call testCall()");
+
+    assert!(result.success, "testCall() should succeed");
+    let (call_success, return_data) =
+        decode_call_result(&result.return_data).expect("Failed to decode call result");
+    assert!(call_success, "Contract call should succeed");
+    assert_eq!(return_data, EXPECTED_CALL_RETURN_DATA);
+}
+
 /// Test static call functionality (read-only operations)
 fn test_static_call(fixture: &ContractCallsTestFixture) {
     let mut context = fixture
@@ -222,13 +375,10 @@ fn test_static_call(fixture: &ContractCallsTestFixture) {
         .expect("Failed to create fresh context");
 
     // First, set a value using regular call so we have something to read
-    let set_call_data = {
-        let mut data = selectors::set_value().to_vec();
-        data.extend_from_slice(&encode(
-            &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
-        ));
-        data
-    };
+    let set_call_data = encode_call(
+        "setValue(uint256)",
+        &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
+    );
 
     let target_address = random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID);
     let set_params = ParamBuilder::new()
@@ -266,6 +416,47 @@ fn test_static_call(fixture: &ContractCallsTestFixture) {
         EXPECTED_CALL_RETURN_DATA, return_data
     );
 }
+/// Test that the static call frame rejects state changes while a regular call allows them
+fn test_static_call_rejects_state_change(fixture: &ContractCallsTestFixture) {
+    let mut context = fixture
+        .create_fresh_context()
+        .expect("Failed to create fresh context");
+
+    // setValue() writes storage and emits an event
+    let set_call_data = encode_call(
+        "setValue(uint256)",
+        &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
+    );
+
+    let target_address = random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID);
+    let params = ParamBuilder::new()
+        .address(&target_address)
+        .bytes(&set_call_data)
+        .build();
+
+    let result = fixture
+        .call_function(&mut context, &selectors::test_static_call(), params.clone())
+        .expect("Failed to call testStaticCall()");
+
+    assert!(result.success, "testStaticCall() itself should succeed");
+
+    let (call_success, _) =
+        decode_call_result(&result.return_data).expect("Failed to decode static call result");
+    assert!(
+        !call_success,
+        "State-changing call inside a static frame should fail"
+    );
+
+    // The same call outside a static frame is allowed
+    let result = fixture
+        .call_function(&mut context, &selectors::test_call(), params)
+        .expect("Failed to call testCall()");
+
+    let (call_success, _) =
+        decode_call_result(&result.return_data).expect("Failed to decode call result");
+    assert!(call_success, "State-changing regular call should succeed");
+}
+
 /// Test delegate call functionality (execution in caller's context)
 fn test_delegate_call(fixture: &ContractCallsTestFixture) {
     let mut context = fixture
@@ -273,13 +464,10 @@ fn test_delegate_call(fixture: &ContractCallsTestFixture) {
         .expect("Failed to create fresh context");
 
     // Prepare call data for setValue(100) on the target contract
-    let target_call_data = {
-        let mut data = selectors::set_value().to_vec();
-        data.extend_from_slice(&encode(
-            &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
-        ));
-        data
-    };
+    let target_call_data = encode_call(
+        "setValue(uint256)",
+        &ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
+    );
 
     let target_address = random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID);
     let params = ParamBuilder::new()
@@ -320,7 +508,9 @@ fn test_create(fixture: &ContractCallsTestFixture) {
     let created_address =
         decode_address(&result.return_data).expect("Failed to decode created contract address");
 
-    let expected_address = random_test_address(TEST_CREATE_RESULT_ADDRESS_ID);
+    // First CREATE from the calls contract uses its initial nonce 0
+    let creator_address = random_test_address(TEST_CALLS_CONTRACT_ADDRESS_ID);
+    let expected_address = create_address(&creator_address, 0);
     assert_eq!(
         created_address, expected_address,
         "Created contract address mismatch: expected {:?}, got {:?}",
@@ -348,10 +538,951 @@ fn test_create2(fixture: &ContractCallsTestFixture) {
     let created_address =
         decode_address(&result.return_data).expect("Failed to decode CREATE2 contract address");
 
-    let expected_address = random_test_address(TEST_CREATE2_RESULT_ADDRESS_ID);
+    // The contract passes the constructor arguments as part of the init code,
+    // which is registered as the new contract's code
+    let init_code = context
+        .get_contract_info(&created_address)
+        .expect("CREATE2 contract should be registered")
+        .code;
+    let init_code_hash: [u8; 32] = Keccak256::digest(&init_code).into();
+    let creator_address = random_test_address(TEST_CALLS_CONTRACT_ADDRESS_ID);
+    let expected_address = create2_address(&creator_address, &TEST_CREATE2_SALT, &init_code_hash);
     assert_eq!(
         created_address, expected_address,
         "CREATE2 contract address mismatch: expected {:?}, got {:?}",
         expected_address, created_address
     );
 }
+
+/// Test a multi-value (uint256, address) return using ABI-aware assertions
+fn test_target_state(fixture: &ContractCallsTestFixture) {
+    let owner_address = random_test_address(TEST_OWNER_ADDRESS_ID);
+
+    let mut context = MockContext::builder()
+        .with_storage(Rc::new(RefCell::new(HashMap::new())))
+        .with_code(fixture.simple_target_wasm.clone())
+        .with_caller(owner_address)
+        .with_address(random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID))
+        .build();
+
+    fixture
+        .executor
+        .deploy_contract("simple_target", &mut context)
+        .expect("Failed to deploy SimpleTarget");
+
+    // setValue(100) records both the value and msg.sender
+    set_call_data_with_params(
+        &mut context,
+        &selectors::set_value(),
+        ParamBuilder::new().uint256(TEST_SET_VALUE).build(),
+    );
+    let result = fixture
+        .executor
+        .call_contract_function("simple_target", &mut context)
+        .expect("Failed to call setValue()");
+    assert!(result.success, "setValue() should succeed");
+
+    set_call_data_with_params(&mut context, &selectors::get_state(), vec![]);
+    let result = fixture
+        .executor
+        .call_contract_function("simple_target", &mut context)
+        .expect("Failed to call getState()");
+
+    assert_returns!(
+        result,
+        (uint256 => TEST_SET_VALUE),
+        (address => owner_address)
+    );
+}
+
+/// Test that a mocked external call returns the canned response without executing code
+fn test_mocked_oracle_call(fixture: &ContractCallsTestFixture) {
+    let mut context = fixture
+        .create_fresh_context()
+        .expect("Failed to create fresh context");
+
+    // The oracle has no registered code; only the mock answers latestPrice()
+    let oracle_address = random_test_address(TEST_ORACLE_ADDRESS_ID);
+    context.mock_call(
+        oracle_address,
+        selectors::latest_price(),
+        (
+            true,
+            encode(&ParamBuilder::new().uint256(TEST_ORACLE_PRICE).build()),
+        ),
+    );
+
+    let params = ParamBuilder::new().address(&oracle_address).build();
+    let result = fixture
+        .call_function(&mut context, &selectors::get_oracle_price(), params)
+        .expect("Failed to call getOraclePrice()");
+
+    assert_returns!(result, (uint256 => TEST_ORACLE_PRICE));
+}
+
+#[test]
+fn test_encode_call_matches_manual_encoding() {
+    let params = ParamBuilder::new()
+        .address(&random_test_address(TEST_TARGET_CONTRACT_ADDRESS_ID))
+        .bytes(&[0xde, 0xad, 0xbe, 0xef])
+        .build();
+
+    let mut expected = selectors::test_call().to_vec();
+    expected.extend_from_slice(&encode(&params));
+    assert_eq!(encode_call("testCall(address,bytes)", &params), expected);
+
+    // Functions without arguments encode to just the selector
+    assert_eq!(
+        encode_call("getValue()", &[]),
+        selectors::get_value().to_vec()
+    );
+
+    // Constructor arguments carry no selector
+    assert_eq!(encode_constructor(&params), encode(&params));
+}
+
+/// Nested Call Gas
+///
+/// Verifies that gas forwarded to nested calls follows the 63/64 rule and that,
+/// when the innermost call reverts, every frame up the stack gets its unused
+/// gas back. Uses hand-written contracts so the gas used by each frame is exact:
+///
+/// - Outer calls Middle, then returns its own remaining gas
+/// - Middle records its gas before and after calling Inner in storage
+/// - Inner executes a GAS opcode and reverts
+mod call_gas {
+    use super::*;
+    use dtvmcore_rust::evm::gas::max_call_gas;
+    use dtvmcore_rust::evm::traits::EvmHost;
+    use dtvmcore_rust::evm::GAS_OPCODE_COST;
+
+    const TEST_GAS_LIMIT: u64 = 1_000_000;
+    const TEST_MIDDLE_ADDRESS_ID: u8 = 2;
+    const TEST_INNER_ADDRESS_ID: u8 = 3;
+
+    // Storage slots written by the middle frame
+    const MIDDLE_GAS_BEFORE_SLOT: u8 = 1;
+    const MIDDLE_GAS_AFTER_SLOT: u8 = 2;
+
+    /// Reads the remaining gas, then reverts without data
+    const INNER_WAT: &str = r#"
+(module
+  (import "env" "getGasLeft" (func $getGasLeft (result i64)))
+  (import "env" "revert" (func $revert (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (drop (call $getGasLeft))
+    (call $revert (i32.const 0) (i32.const 0))))
+"#;
+
+    /// Calls `target` with all available gas (a negative request forwards the maximum)
+    /// `before` runs ahead of the call and `after` once it returns
+    fn caller_wat(target: [u8; 20], before: &str, after: &str) -> String {
+        let target: String = target.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "getGasLeft" (func $getGasLeft (result i64)))
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "storageStore" (func $storageStore (param i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: target address, 32..64: zero call value
+  (data (i32.const 0) "{target}")
+  (func $record (param $slot i32)
+    ;; 64..96: storage key, 96..128: remaining gas (little-endian in the last 8 bytes)
+    (i32.store8 (i32.const 95) (local.get $slot))
+    (i64.store (i32.const 120) (call $getGasLeft))
+    (call $storageStore (i32.const 64) (i32.const 96)))
+  (func (export "deploy"))
+  (func (export "call")
+    {before}
+    (drop (call $callContract (i64.const -1) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))
+    {after}))
+"#
+        )
+    }
+
+    fn slot_key(slot: u8) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        key[31] = slot;
+        key
+    }
+
+    fn recorded_gas(context: &MockContext, slot: u8) -> u64 {
+        let value = context.storage_load(&slot_key(slot));
+        u64::from_le_bytes(value[24..32].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_revert_returns_gas_up_the_stack() {
+        let middle_address = random_test_address(TEST_MIDDLE_ADDRESS_ID);
+        let inner_address = random_test_address(TEST_INNER_ADDRESS_ID);
+
+        let outer_wat = caller_wat(
+        middle_address,
+        "",
+        "(i64.store (i32.const 128) (call $getGasLeft)) (call $finish (i32.const 128) (i32.const 8))",
+    );
+        let middle_wat = caller_wat(
+            inner_address,
+            &format!("(call $record (i32.const {}))", MIDDLE_GAS_BEFORE_SLOT),
+            &format!("(call $record (i32.const {}))", MIDDLE_GAS_AFTER_SLOT),
+        );
+
+        let (result, context) = run_wat_with_context(
+            &outer_wat,
+            MockContext::builder()
+                .with_gas_limit(TEST_GAS_LIMIT as i64)
+                .with_contract(
+                    middle_address,
+                    "Middle",
+                    wat::parse_str(middle_wat).expect("Failed to parse middle WAT"),
+                )
+                .with_contract(
+                    inner_address,
+                    "Inner",
+                    wat::parse_str(INNER_WAT).expect("Failed to parse inner WAT"),
+                ),
+        );
+        assert!(result.success, "Outer call should succeed");
+
+        // Middle receives 63/64 of the outer gas and reads it after one GAS opcode
+        let middle_gas = max_call_gas(TEST_GAS_LIMIT);
+        let middle_before = middle_gas - GAS_OPCODE_COST;
+        assert_eq!(
+            recorded_gas(&context, MIDDLE_GAS_BEFORE_SLOT),
+            middle_before
+        );
+
+        // Inner reverts after one GAS opcode; everything else it was given returns to Middle
+        let inner_used = GAS_OPCODE_COST;
+        let middle_after = middle_before - inner_used - GAS_OPCODE_COST;
+        assert_eq!(recorded_gas(&context, MIDDLE_GAS_AFTER_SLOT), middle_after);
+
+        // Outer only pays for what Middle (and Inner through it) actually used
+        let middle_used = middle_gas - middle_after;
+        let outer_after = TEST_GAS_LIMIT - middle_used - GAS_OPCODE_COST;
+        let outer_gas_left = u64::from_le_bytes(result.return_data[..8].try_into().unwrap());
+        assert_eq!(outer_gas_left, outer_after);
+    }
+
+    /// Reads the remaining gas twice, reverts, and never reaches the third read
+    const PARTIAL_REVERT_WAT: &str = r#"
+(module
+  (import "env" "getGasLeft" (func $getGasLeft (result i64)))
+  (import "env" "revert" (func $revert (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (drop (call $getGasLeft))
+    (drop (call $getGasLeft))
+    (call $revert (i32.const 0) (i32.const 0))
+    (drop (call $getGasLeft))))
+"#;
+
+    #[test]
+    fn test_parent_recovers_gas_after_reverted_call() {
+        let inner_address = random_test_address(TEST_INNER_ADDRESS_ID);
+
+        // The parent ignores the failed call and returns normally
+        let parent_wat = caller_wat(inner_address, "", "");
+
+        let result = run_wat(
+            &parent_wat,
+            MockContext::builder()
+                .with_gas_limit(TEST_GAS_LIMIT as i64)
+                .with_contract(
+                    inner_address,
+                    "Inner",
+                    wat::parse_str(PARTIAL_REVERT_WAT).expect("Failed to parse inner WAT"),
+                ),
+        );
+        assert!(
+            result.success,
+            "A reverted sub-call should not revert the parent"
+        );
+
+        // Only the two GAS opcodes executed before the revert are charged
+        let inner_used = 2 * GAS_OPCODE_COST;
+        assert_eq!(result.gas_used, inner_used as i64);
+        assert_eq!(result.gas_left, TEST_GAS_LIMIT - inner_used);
+    }
+}
+
+/// Delegate Call
+///
+/// Verifies that code run through DELEGATECALL observes the msg.sender of
+/// the delegating frame, not the delegating contract's address, and that a
+/// contract reached through CALL observes the calling contract as msg.sender.
+mod delegate_call {
+    use super::*;
+
+    const TEST_OWNER_ADDRESS_ID: u8 = 1;
+    const TEST_PROXY_ADDRESS_ID: u8 = 10;
+    const TEST_LOGIC_ADDRESS_ID: u8 = 20;
+    const TEST_ENTRY_ADDRESS_ID: u8 = 30;
+
+    /// Returns the 20-byte msg.sender it observes
+    const GET_CALLER_WAT: &str = r#"
+(module
+  (import "env" "getCaller" (func $getCaller (param i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (call $getCaller (i32.const 0))
+    (call $finish (i32.const 0) (i32.const 20))))
+"#;
+
+    /// Delegatecalls `logic` and returns its return data
+    fn proxy_wat(logic: [u8; 20]) -> String {
+        let logic: String = logic.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "callDelegate" (func $callDelegate (param i64 i32 i32 i32) (result i32)))
+  (import "env" "returnDataCopy" (func $returnDataCopy (param i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: logic address, 32..52: delegated return data
+  (data (i32.const 0) "{logic}")
+  (func (export "deploy"))
+  (func (export "call")
+    (drop (call $callDelegate (i64.const 100000) (i32.const 0) (i32.const 0) (i32.const 0)))
+    (call $returnDataCopy (i32.const 32) (i32.const 0) (i32.const 20))
+    (call $finish (i32.const 32) (i32.const 20))))
+"#
+        )
+    }
+
+    /// Calls `target` and returns the first 20 bytes of its return data
+    fn entry_wat(target: [u8; 20]) -> String {
+        let target: String = target.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "returnDataCopy" (func $returnDataCopy (param i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: target address, 32..64: zero call value, 64..84: returned data
+  (data (i32.const 0) "{target}")
+  (func (export "deploy"))
+  (func (export "call")
+    (drop (call $callContract (i64.const 100000) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))
+    (call $returnDataCopy (i32.const 64) (i32.const 0) (i32.const 20))
+    (call $finish (i32.const 64) (i32.const 20))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_delegate_call_preserves_caller() {
+        let owner_address = random_test_address(TEST_OWNER_ADDRESS_ID);
+        let proxy_address = random_test_address(TEST_PROXY_ADDRESS_ID);
+        let logic_address = random_test_address(TEST_LOGIC_ADDRESS_ID);
+
+        let result = run_wat(
+            &proxy_wat(logic_address),
+            MockContext::builder()
+                .with_caller(owner_address)
+                .with_address(proxy_address)
+                .with_gas_limit(1000000)
+                .with_contract(
+                    logic_address,
+                    "Logic",
+                    wat::parse_str(GET_CALLER_WAT).expect("Failed to parse logic WAT"),
+                ),
+        );
+        assert!(result.success, "the proxy should succeed");
+
+        assert_eq!(
+            result.return_data, owner_address,
+            "delegated code must see the proxy's msg.sender"
+        );
+        assert_ne!(
+            result.return_data, proxy_address,
+            "delegated code must not see the proxy as msg.sender"
+        );
+    }
+
+    #[test]
+    fn test_call_sees_calling_contract_as_caller() {
+        let owner_address = random_test_address(TEST_OWNER_ADDRESS_ID);
+        let entry_address = random_test_address(TEST_ENTRY_ADDRESS_ID);
+        let logic_address = random_test_address(TEST_LOGIC_ADDRESS_ID);
+
+        let result = run_wat(
+            &entry_wat(logic_address),
+            MockContext::builder()
+                .with_caller(owner_address)
+                .with_address(entry_address)
+                .with_gas_limit(1000000)
+                .with_contract(
+                    logic_address,
+                    "Logic",
+                    wat::parse_str(GET_CALLER_WAT).expect("Failed to parse logic WAT"),
+                ),
+        );
+        assert!(result.success, "the entry contract should succeed");
+
+        assert_eq!(
+            result.return_data, entry_address,
+            "the callee must see the calling contract as msg.sender"
+        );
+    }
+
+    #[test]
+    fn test_delegate_call_behind_call_sees_calling_contract() {
+        let owner_address = random_test_address(TEST_OWNER_ADDRESS_ID);
+        let entry_address = random_test_address(TEST_ENTRY_ADDRESS_ID);
+        let proxy_address = random_test_address(TEST_PROXY_ADDRESS_ID);
+        let logic_address = random_test_address(TEST_LOGIC_ADDRESS_ID);
+
+        let result = run_wat(
+            &entry_wat(proxy_address),
+            MockContext::builder()
+                .with_caller(owner_address)
+                .with_address(entry_address)
+                .with_gas_limit(1000000)
+                .with_contract(
+                    proxy_address,
+                    "Proxy",
+                    wat::parse_str(proxy_wat(logic_address)).expect("Failed to parse proxy WAT"),
+                )
+                .with_contract(
+                    logic_address,
+                    "Logic",
+                    wat::parse_str(GET_CALLER_WAT).expect("Failed to parse logic WAT"),
+                ),
+        );
+        assert!(result.success, "the entry contract should succeed");
+
+        // The proxy's msg.sender is the entry contract, and delegated code keeps it
+        assert_eq!(
+            result.return_data, entry_address,
+            "delegated code must see the proxy's msg.sender, the entry contract"
+        );
+        assert_ne!(
+            result.return_data, owner_address,
+            "the transaction origin is not the proxy's msg.sender"
+        );
+    }
+}
+
+/// Dispatch Observability
+///
+/// Verifies that a contract can report which entry point it dispatched a call
+/// to through `recordDispatch`, and that tests can read it back with
+/// `MockContext::last_dispatch`. A hand-written contract mirrors Solidity's
+/// dispatcher: empty call data runs `receive()`, call data too short for a
+/// selector runs `fallback()` and anything else is dispatched by selector.
+mod dispatch {
+    use super::*;
+    use dtvmcore_rust::evm::traits::Dispatch;
+
+    const TEST_CALL_VALUE: u8 = 100;
+
+    /// Dispatches on the call data and records the path taken
+    const DISPATCH_WAT: &str = r#"
+(module
+  (import "env" "getCallDataSize" (func $getCallDataSize (result i32)))
+  (import "env" "callDataCopy" (func $callDataCopy (param i32 i32 i32)))
+  (import "env" "recordDispatch" (func $recordDispatch (param i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..4: selector
+  (func (export "deploy"))
+  (func (export "call")
+    (local $size i32)
+    (local.set $size (call $getCallDataSize))
+    (if (i32.eqz (local.get $size))
+      (then (call $recordDispatch (i32.const 2) (i32.const 0)))
+      (else
+        (if (i32.lt_u (local.get $size) (i32.const 4))
+          (then (call $recordDispatch (i32.const 1) (i32.const 0)))
+          (else
+            (call $callDataCopy (i32.const 0) (i32.const 0) (i32.const 4))
+            (call $recordDispatch (i32.const 0) (i32.const 0))))))
+    (call $finish (i32.const 0) (i32.const 0))))
+"#;
+
+    #[test]
+    fn test_value_transfer_with_empty_call_data_takes_receive_path() {
+        let mut value = [0u8; 32];
+        value[31] = TEST_CALL_VALUE;
+
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        let mut context = MockContext::builder()
+            .with_code(wat::parse_str(DISPATCH_WAT).expect("Failed to parse WAT"))
+            .with_call_value(value)
+            .build();
+        assert_eq!(context.last_dispatch(), None);
+
+        let result = executor
+            .call_contract_function("Dispatch", &mut context)
+            .expect("Failed to call contract");
+        assert!(result.success, "Value transfer should succeed");
+        assert_eq!(context.last_dispatch(), Some(Dispatch::Receive));
+    }
+
+    #[test]
+    fn test_dispatch_by_selector_and_fallback() {
+        let selector = calculate_selector("transfer(address,uint256)");
+
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        let mut context = MockContext::builder()
+            .with_code(wat::parse_str(DISPATCH_WAT).expect("Failed to parse WAT"))
+            .build();
+
+        context.set_call_data(selector.to_vec());
+        let result = executor
+            .call_contract_function("Dispatch", &mut context)
+            .expect("Failed to call contract");
+        assert!(result.success, "Function call should succeed");
+        assert_eq!(context.last_dispatch(), Some(Dispatch::Function(selector)));
+
+        // Call data shorter than a selector cannot match any function
+        context.set_call_data(vec![0xAB, 0xCD]);
+        let result = executor
+            .call_contract_function("Dispatch", &mut context)
+            .expect("Failed to call contract");
+        assert!(result.success, "Fallback call should succeed");
+        assert_eq!(context.last_dispatch(), Some(Dispatch::Fallback));
+    }
+}
+
+/// Gas Stipend
+///
+/// Verifies that gas reserved by the host as a stipend is hidden from the gas
+/// left a contract observes through `getGasLeft`.
+mod gas_stipend {
+    use super::*;
+
+    const TEST_GAS_LIMIT: i64 = 1_000_000;
+    const TEST_GAS_STIPEND: i64 = 100;
+
+    /// Returns the remaining gas as i64
+    const GAS_LEFT_WAT: &str = r#"
+(module
+  (import "env" "getGasLeft" (func $getGasLeft (result i64)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    (i64.store (i32.const 0) (call $getGasLeft))
+    (call $finish (i32.const 0) (i32.const 8))))
+"#;
+
+    fn observed_gas_left(stipend: Option<i64>) -> i64 {
+        let mut builder = MockContext::builder().with_gas_limit(TEST_GAS_LIMIT);
+        if let Some(stipend) = stipend {
+            builder = builder.with_gas_stipend(stipend);
+        }
+
+        let result = run_wat(GAS_LEFT_WAT, builder);
+        assert!(result.success, "getGasLeft should succeed");
+        i64::from_le_bytes(result.return_data[..8].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_gas_stipend_is_hidden_from_gas_left() {
+        let gas_left = observed_gas_left(None);
+        let gas_left_with_stipend = observed_gas_left(Some(TEST_GAS_STIPEND));
+        assert_eq!(gas_left_with_stipend, gas_left - TEST_GAS_STIPEND);
+
+        // A stipend larger than the remaining gas reads as zero
+        assert_eq!(observed_gas_left(Some(TEST_GAS_LIMIT * 2)), 0);
+    }
+}
+
+/// Recursive Call
+///
+/// Verifies that a contract calling its own address re-enters its own code in a
+/// fresh frame, even though the address is only set via the builder and not in
+/// the contract registry. The hand-written contract reads a level `n` from its
+/// call data, calls itself with `n - 1` and returns the child's result plus one.
+/// A contract that recurses without bound must stop at the EVM call depth
+/// limit with a failed call instead of overflowing the native stack.
+mod recursion {
+    use super::*;
+    use evm_example::mock_context::MAX_CALL_DEPTH;
+
+    const TEST_GAS_LIMIT: i64 = 1_000_000;
+    const TEST_SELF_ADDRESS_ID: u8 = 7;
+    const TEST_RECURSION_LEVELS: u8 = 4;
+    /// Enough gas to survive 1024 levels of 63/64 forwarding
+    const TEST_UNBOUNDED_GAS_LIMIT: i64 = 1_000_000_000_000_000;
+    /// Native stack for running 1024 nested frames
+    const TEST_UNBOUNDED_STACK_SIZE: usize = 1 << 30;
+
+    /// Recurses `n` levels deep and returns `n` as a single byte
+    fn recursive_wat(self_address: [u8; 20]) -> String {
+        let self_address: String = self_address
+            .iter()
+            .map(|b| format!("\\{:02x}", b))
+            .collect();
+        format!(
+            r#"
+(module
+  (import "env" "callDataCopy" (func $callDataCopy (param i32 i32 i32)))
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "returnDataCopy" (func $returnDataCopy (param i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: own address, 32..64: zero call value, 64: level, 96: result
+  (data (i32.const 0) "{self_address}")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $callDataCopy (i32.const 64) (i32.const 0) (i32.const 1))
+    (if (i32.load8_u (i32.const 64))
+      (then
+        (i32.store8 (i32.const 64) (i32.sub (i32.load8_u (i32.const 64)) (i32.const 1)))
+        (drop (call $callContract (i64.const -1) (i32.const 0) (i32.const 32) (i32.const 64) (i32.const 1)))
+        (call $returnDataCopy (i32.const 96) (i32.const 0) (i32.const 1))
+        (i32.store8 (i32.const 96) (i32.add (i32.load8_u (i32.const 96)) (i32.const 1)))))
+    (call $finish (i32.const 96) (i32.const 1))))
+"#
+        )
+    }
+
+    /// Calls itself until a call fails and returns the number of calls that
+    /// succeeded below it as a little-endian i32
+    fn unbounded_recursive_wat(self_address: [u8; 20]) -> String {
+        let self_address: String = self_address
+            .iter()
+            .map(|b| format!("\\{:02x}", b))
+            .collect();
+        format!(
+            r#"
+(module
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "returnDataCopy" (func $returnDataCopy (param i32 i32 i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: own address, 32..64: zero call value, 64..68: result
+  (data (i32.const 0) "{self_address}")
+  (func (export "deploy"))
+  (func (export "call")
+    (if (call $callContract (i64.const -1) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0))
+      (then
+        (call $returnDataCopy (i32.const 64) (i32.const 0) (i32.const 4))
+        (i32.store (i32.const 64) (i32.add (i32.load (i32.const 64)) (i32.const 1)))))
+    (call $finish (i32.const 64) (i32.const 4))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_contract_recursively_calls_itself() {
+        let self_address = random_test_address(TEST_SELF_ADDRESS_ID);
+
+        let executor = ContractExecutor::new().expect("Failed to create executor");
+        let mut context = MockContext::builder()
+            .with_code(wat::parse_str(recursive_wat(self_address)).expect("Failed to parse WAT"))
+            .with_address(self_address)
+            .with_call_data(vec![TEST_RECURSION_LEVELS])
+            .with_gas_limit(TEST_GAS_LIMIT)
+            .build();
+        assert!(context.get_contract_info(&self_address).is_none());
+
+        let result = executor
+            .call_contract_function("Recursive", &mut context)
+            .expect("Failed to call recursive contract");
+
+        assert!(result.success, "Recursive call should succeed");
+        assert_eq!(
+            result.return_data,
+            vec![TEST_RECURSION_LEVELS],
+            "Each level should add one to its child's result"
+        );
+        assert_eq!(context.get_call_depth(), 0);
+        assert_eq!(
+            context.get_max_call_depth(),
+            TEST_RECURSION_LEVELS as usize,
+            "Each level should run one frame deeper"
+        );
+    }
+
+    #[test]
+    fn test_unbounded_recursion_stops_at_call_depth_limit() {
+        // Each level nests a full contract execution on the native stack
+        let handle = std::thread::Builder::new()
+            .stack_size(TEST_UNBOUNDED_STACK_SIZE)
+            .spawn(|| {
+                let self_address = random_test_address(TEST_SELF_ADDRESS_ID);
+
+                let (result, context) = run_wat_with_context(
+                    &unbounded_recursive_wat(self_address),
+                    MockContext::builder()
+                        .with_address(self_address)
+                        .with_gas_limit(TEST_UNBOUNDED_GAS_LIMIT),
+                );
+                (result, context.get_max_call_depth())
+            })
+            .expect("Failed to spawn test thread");
+        let (result, max_call_depth) = handle.join().expect("Recursion should not panic");
+
+        assert!(result.success, "Only the call beyond the limit should fail");
+        assert_eq!(
+            result.return_data,
+            (MAX_CALL_DEPTH as i32).to_le_bytes(),
+            "Every level down to the limit should have run"
+        );
+        assert_eq!(max_call_depth, MAX_CALL_DEPTH);
+    }
+}
+
+/// Revert Isolation
+///
+/// Verifies that storage written by a nested call that reverts is rolled
+/// back, so the calling frame still reads the value it had before the call.
+mod revert_isolation {
+    use super::*;
+
+    const TEST_CALLEE_ADDRESS_ID: u8 = 7;
+
+    /// Overwrites storage slot 0 with 2, then reverts
+    const OVERWRITE_AND_REVERT_WAT: &str = r#"
+(module
+  (import "env" "storageStore" (func $storageStore (param i32 i32)))
+  (import "env" "revert" (func $revert (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: slot 0, 32..64: value 2
+  (data (i32.const 63) "\02")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $storageStore (i32.const 0) (i32.const 32))
+    (call $revert (i32.const 0) (i32.const 0))))
+"#;
+
+    /// Stores 1 in slot 0, calls `callee`, then returns slot 0 and the call result
+    fn caller_wat(callee: [u8; 20]) -> String {
+        let callee: String = callee.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "storageStore" (func $storageStore (param i32 i32)))
+  (import "env" "storageLoad" (func $storageLoad (param i32 i32)))
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: callee, 32..64: zero call value, 64..96: slot 0,
+  ;; 96..128: value 1, 128..160: loaded value, 160: call result
+  (data (i32.const 0) "{callee}")
+  (data (i32.const 127) "\01")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $storageStore (i32.const 64) (i32.const 96))
+    (i32.store8 (i32.const 160)
+      (call $callContract (i64.const 100000) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))
+    (call $storageLoad (i32.const 64) (i32.const 128))
+    (call $finish (i32.const 128) (i32.const 33))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_reverted_call_storage_is_rolled_back() {
+        let callee_address = random_test_address(TEST_CALLEE_ADDRESS_ID);
+
+        let result = run_wat(
+            &caller_wat(callee_address),
+            MockContext::builder()
+                .with_gas_limit(1000000)
+                .with_contract(
+                    callee_address,
+                    "Reverter",
+                    wat::parse_str(OVERWRITE_AND_REVERT_WAT).expect("Failed to parse callee WAT"),
+                ),
+        );
+        assert!(result.success, "the caller should succeed");
+
+        let (slot, call_result) = result.return_data.split_at(32);
+        assert_eq!(call_result, [0], "the nested call should revert");
+        let mut expected = [0u8; 32];
+        expected[31] = 1;
+        assert_eq!(
+            slot, expected,
+            "the reverted call's storage write must be rolled back"
+        );
+    }
+}
+
+/// Static Call
+///
+/// Verifies that a storage write inside a STATICCALL frame is a static call
+/// violation: the nested call fails and leaves storage untouched.
+mod static_call {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    const TEST_CALLEE_ADDRESS_ID: u8 = 7;
+
+    /// Writes 1 to storage slot 0
+    const WRITER_WAT: &str = r#"
+(module
+  (import "env" "storageStore" (func $storageStore (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..32: slot 0, 32..64: value 1
+  (data (i32.const 63) "\01")
+  (func (export "deploy"))
+  (func (export "call")
+    (call $storageStore (i32.const 0) (i32.const 32))))
+"#;
+
+    /// Static-calls `callee` and returns the call's success flag as a single byte
+    fn static_caller_wat(callee: [u8; 20]) -> String {
+        let callee: String = callee.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "callStatic" (func $callStatic (param i64 i32 i32 i32) (result i32)))
+  (import "env" "finish" (func $finish (param i32 i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: callee address, 32: call result
+  (data (i32.const 0) "{callee}")
+  (func (export "deploy"))
+  (func (export "call")
+    (i32.store8 (i32.const 32)
+      (call $callStatic (i64.const 100000) (i32.const 0) (i32.const 0) (i32.const 0)))
+    (call $finish (i32.const 32) (i32.const 1))))
+"#
+        )
+    }
+
+    #[test]
+    fn test_storage_write_in_static_call_fails() {
+        let callee_address = random_test_address(TEST_CALLEE_ADDRESS_ID);
+
+        let shared_storage = Rc::new(RefCell::new(HashMap::new()));
+        let result = run_wat(
+            &static_caller_wat(callee_address),
+            MockContext::builder()
+                .with_storage(shared_storage.clone())
+                .with_gas_limit(1000000)
+                .with_contract(
+                    callee_address,
+                    "Writer",
+                    wat::parse_str(WRITER_WAT).expect("Failed to parse writer WAT"),
+                ),
+        );
+
+        assert!(result.success, "the static caller itself should succeed");
+        assert_eq!(
+            result.return_data,
+            vec![0],
+            "storage write inside a static call should fail the call"
+        );
+        assert!(
+            shared_storage.borrow().is_empty(),
+            "the rejected write must not reach storage"
+        );
+    }
+}
+
+/// Value Transfer
+///
+/// Verifies that calls move their value between the MockContext balances. A
+/// hand-written contract calls an account (which has no code) with a value.
+mod value_transfer {
+    use super::*;
+    use dtvmcore_rust::evm::traits::EvmHost;
+
+    const TEST_SENDER_ADDRESS_ID: u8 = 11;
+    const TEST_RECIPIENT_ADDRESS_ID: u8 = 12;
+    const TEST_SENDER_BALANCE: u8 = 100;
+    const TEST_CALL_VALUE: u8 = 30;
+
+    /// Calls `recipient` with a value of 30 wei
+    fn value_call_wat(recipient: [u8; 20]) -> String {
+        let recipient: String = recipient.iter().map(|b| format!("\\{:02x}", b)).collect();
+        format!(
+            r#"
+(module
+  (import "env" "callContract" (func $callContract (param i64 i32 i32 i32 i32) (result i32)))
+  (memory (export "memory") 1)
+  ;; 0..20: recipient address, 32..64: call value
+  (data (i32.const 0) "{recipient}")
+  (data (i32.const 63) "\1e")
+  (func (export "deploy"))
+  (func (export "call")
+    (drop (call $callContract (i64.const -1) (i32.const 0) (i32.const 32) (i32.const 0) (i32.const 0)))))
+"#
+        )
+    }
+
+    fn word(value: u8) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[31] = value;
+        word
+    }
+
+    /// Runs the value call from a sender holding `sender_balance` wei
+    /// The recipient is funded with `recipient_balance` wei, or never funded if None
+    fn call_with_value(sender_balance: u8, recipient_balance: Option<[u8; 32]>) -> MockContext {
+        let sender = random_test_address(TEST_SENDER_ADDRESS_ID);
+        let recipient = random_test_address(TEST_RECIPIENT_ADDRESS_ID);
+
+        let mut builder = MockContext::builder()
+            .with_address(sender)
+            .with_balance(sender, word(sender_balance));
+        if let Some(balance) = recipient_balance {
+            builder = builder.with_balance(recipient, balance);
+        }
+
+        let (result, context) = run_wat_with_context(&value_call_wat(recipient), builder);
+        assert!(result.success, "Contract call should succeed");
+
+        context
+    }
+
+    #[test]
+    fn test_call_moves_value_between_balances() {
+        // A never funded recipient holds exactly the transferred value
+        let context = call_with_value(TEST_SENDER_BALANCE, None);
+
+        assert_eq!(
+            context.get_external_balance(&random_test_address(TEST_SENDER_ADDRESS_ID)),
+            word(TEST_SENDER_BALANCE - TEST_CALL_VALUE)
+        );
+        assert_eq!(
+            context.get_external_balance(&random_test_address(TEST_RECIPIENT_ADDRESS_ID)),
+            word(TEST_CALL_VALUE)
+        );
+    }
+
+    #[test]
+    fn test_call_with_insufficient_balance_fails() {
+        let context = call_with_value(TEST_CALL_VALUE - 1, Some(word(0)));
+
+        assert_eq!(
+            context.get_external_balance(&random_test_address(TEST_SENDER_ADDRESS_ID)),
+            word(TEST_CALL_VALUE - 1),
+            "A failed call should not move any value"
+        );
+        assert_eq!(
+            context.get_external_balance(&random_test_address(TEST_RECIPIENT_ADDRESS_ID)),
+            word(0)
+        );
+    }
+
+    #[test]
+    fn test_call_overflowing_recipient_balance_fails() {
+        let context = call_with_value(TEST_SENDER_BALANCE, Some([0xff; 32]));
+
+        assert_eq!(
+            context.get_external_balance(&random_test_address(TEST_SENDER_ADDRESS_ID)),
+            word(TEST_SENDER_BALANCE),
+            "A credit past 2^256 - 1 should not move any value"
+        );
+        assert_eq!(
+            context.get_external_balance(&random_test_address(TEST_RECIPIENT_ADDRESS_ID)),
+            [0xff; 32]
+        );
+    }
+}
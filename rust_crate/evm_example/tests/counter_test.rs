@@ -14,7 +14,9 @@ mod common;
 
 use common::calldata::set_call_data_with_params;
 use common::*;
-use std::cell::RefCell;
+use dtvmcore_rust::gas_metering::GasMeter;
+use evm_example::mock_context::StorageBackend;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -23,6 +25,15 @@ const COUNT_SELECTOR: [u8; 4] = [0x06, 0x66, 0x1a, 0xbd]; // count()
 const INCREASE_SELECTOR: [u8; 4] = [0xe8, 0x92, 0x7f, 0xbc]; // increase()
 const DECREASE_SELECTOR: [u8; 4] = [0xd7, 0x32, 0xd9, 0x55]; // decrease()
 
+const INCREASE_GAS_LABEL: &str = "counter_increase";
+/// Checked-in gas baseline of increase(), written when missing or when
+/// UPDATE_GAS_SNAPSHOTS is set
+const GAS_SNAPSHOT_FIXTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/counter_gas_snapshots.txt"
+);
+const METERED_GAS_LIMIT: i64 = 10_000_000;
+
 #[test]
 fn test_counter_contract() {
     // Load counter WASM module
@@ -52,6 +63,137 @@ fn test_counter_contract() {
     test_decrease_counter(&executor, &mut context);
 }
 
+/// Storage backend that records the number of reads and writes
+#[derive(Default)]
+struct CountingBackend {
+    slots: HashMap<String, Vec<u8>>,
+    reads: Cell<usize>,
+    writes: usize,
+}
+
+impl StorageBackend for CountingBackend {
+    fn get(&self, address: &[u8; 20], key: &[u8; 32]) -> [u8; 32] {
+        self.reads.set(self.reads.get() + 1);
+        StorageBackend::get(&self.slots, address, key)
+    }
+
+    fn set(&mut self, address: &[u8; 20], key: &[u8; 32], value: &[u8; 32]) {
+        self.writes += 1;
+        StorageBackend::set(&mut self.slots, address, key, value);
+    }
+}
+
+#[test]
+fn test_counter_with_counting_storage_backend() {
+    let counter_wasm_bytes =
+        load_wasm_file("../example/counter.wasm").expect("Failed to load counter.wasm");
+
+    let backend = Rc::new(RefCell::new(CountingBackend::default()));
+    let executor = ContractExecutor::new().expect("Failed to create contract executor");
+
+    let mut context = MockContext::builder()
+        .with_code(counter_wasm_bytes)
+        .with_storage_backend(backend.clone())
+        .with_address([0x42; 20])
+        .build();
+
+    executor
+        .deploy_contract("counter", &mut context)
+        .expect("Failed to deploy contract");
+
+    // increase() reads the counter and writes it back
+    let (reads_before, writes_before) = (backend.borrow().reads.get(), backend.borrow().writes);
+    set_call_data_with_params(&mut context, &INCREASE_SELECTOR, vec![]);
+    let result = executor
+        .call_contract_function("counter", &mut context)
+        .expect("Failed to call increase()");
+    assert!(result.success, "increase() should succeed");
+    assert!(backend.borrow().reads.get() > reads_before);
+    assert_eq!(backend.borrow().writes, writes_before + 1);
+
+    // count() only reads
+    let (reads_before, writes_before) = (backend.borrow().reads.get(), backend.borrow().writes);
+    set_call_data_with_params(&mut context, &COUNT_SELECTOR, vec![]);
+    let result = executor
+        .call_contract_function("counter", &mut context)
+        .expect("Failed to call count()");
+    assert_eq!(decode_uint256(&result.return_data).unwrap(), 1);
+    assert!(backend.borrow().reads.get() > reads_before);
+    assert_eq!(backend.borrow().writes, writes_before);
+}
+
+/// Deploy a fresh counter and snapshot the gas of its first increase()
+fn snapshot_increase_gas(executor: &ContractExecutor, counter_wasm_bytes: Vec<u8>) -> u64 {
+    let mut context = MockContext::builder()
+        .with_code(counter_wasm_bytes)
+        .with_storage(Rc::new(RefCell::new(HashMap::new())))
+        .with_address([0x42; 20])
+        .build();
+    executor
+        .deploy_contract("counter", &mut context)
+        .expect("Failed to deploy contract");
+
+    set_call_data_with_params(&mut context, &INCREASE_SELECTOR, vec![]);
+    executor
+        .gas_snapshot("counter", &mut context, INCREASE_GAS_LABEL)
+        .expect("Failed to snapshot increase()")
+}
+
+#[test]
+fn test_increase_gas_snapshot() {
+    let counter_wasm_bytes =
+        load_wasm_file("../example/counter.wasm").expect("Failed to load counter.wasm");
+
+    let executor = ContractExecutor::new().expect("Failed to create contract executor");
+    snapshot_increase_gas(&executor, counter_wasm_bytes);
+
+    // Record a new baseline instead of comparing against the checked-in one,
+    // and bootstrap the baseline on the first run after counter.wasm is built
+    let fixture_missing = !std::path::Path::new(GAS_SNAPSHOT_FIXTURE).exists();
+    if fixture_missing || std::env::var_os("UPDATE_GAS_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))
+            .expect("Failed to create fixtures directory");
+        executor
+            .write_gas_snapshots(GAS_SNAPSHOT_FIXTURE)
+            .expect("Failed to write gas snapshots");
+        return;
+    }
+
+    // The gas used must stay within 5% of the checked-in baseline
+    let baselines = ContractExecutor::read_gas_snapshots(GAS_SNAPSHOT_FIXTURE)
+        .expect("Failed to read gas snapshots");
+    let baseline = baselines[INCREASE_GAS_LABEL];
+    executor.assert_gas_within(INCREASE_GAS_LABEL, baseline, baseline / 20);
+}
+
+#[test]
+fn test_metered_counter_reports_gas_used() {
+    let counter_wasm_bytes =
+        load_wasm_file("../example/counter.wasm").expect("Failed to load counter.wasm");
+    let metered_wasm_bytes =
+        GasMeter::transform_default(&counter_wasm_bytes).expect("Failed to inject gas metering");
+
+    let executor = ContractExecutor::new().expect("Failed to create contract executor");
+    let mut context = MockContext::builder()
+        .with_code(metered_wasm_bytes)
+        .with_storage(Rc::new(RefCell::new(HashMap::new())))
+        .with_address([0x42; 20])
+        .with_gas_limit(METERED_GAS_LIMIT)
+        .build();
+    executor
+        .deploy_contract("counter", &mut context)
+        .expect("Failed to deploy contract");
+
+    set_call_data_with_params(&mut context, &INCREASE_SELECTOR, vec![]);
+    let result = executor
+        .call_contract_function("counter", &mut context)
+        .expect("Failed to call increase()");
+
+    assert!(result.success, "increase() should succeed");
+    assert!(result.gas_used > 0, "Metered increase() should use gas");
+    assert_eq!(result.gas_used, METERED_GAS_LIMIT - result.gas_left as i64);
+}
+
 fn test_initial_count(executor: &ContractExecutor, context: &mut MockContext) {
     // Use new simplified API with no parameters
     set_call_data_with_params(context, &COUNT_SELECTOR, vec![]);
@@ -0,0 +1,44 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Debug Host Function Integration Test
+//!
+//! Verifies the test-only debugging host functions with hand-written
+//! contracts, which import them from the `debug` host module. Requires the
+//! `debug` feature:
+//!
+//! ```text
+//! cargo test --features debug --test debug_test
+//! ```
+
+#![cfg(feature = "debug")]
+
+mod common;
+
+use common::*;
+
+/// Writes a selector and one ABI word, then dumps both
+const DUMP_WAT: &str = r#"
+(module
+  (import "debug" "dumpMemory" (func $dumpMemory (param i32 i32)))
+  (memory (export "memory") 1)
+  (func (export "deploy"))
+  (func (export "call")
+    ;; selector a9059cbb at 16..20, word 0x2a at 20..52
+    (i32.store (i32.const 16) (i32.const 0xbb9c05a9))
+    (i32.store8 (i32.const 51) (i32.const 0x2a))
+    (call $dumpMemory (i32.const 16) (i32.const 36))))
+"#;
+
+#[test]
+fn test_dump_memory_captures_region() {
+    let (result, context) =
+        run_wat_with_context(DUMP_WAT, MockContext::builder().with_gas_limit(1000000));
+    assert!(result.success, "dumping memory should not affect execution");
+
+    let dumps = context.memory_dumps();
+    assert_eq!(dumps.len(), 1);
+    let (offset, bytes) = &dumps[0];
+    assert_eq!(*offset, 16);
+    assert_eq!(hex::encode(bytes), format!("a9059cbb{}2a", "00".repeat(31)));
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Indexed Event Topic Test
+//!
+//! Verifies that `LogEventBuilder` hashes indexed arrays and structs into the
+//! same topics Solidity emits: the in-place encoding of their elements, without
+//! ABI head, offset or length words.
+
+mod common;
+
+use common::*;
+use ethabi::{Token, Uint};
+
+const TEST_EMITTER_ADDRESS_ID: u8 = 50;
+
+fn topic(hex_str: &str) -> [u8; 32] {
+    hex::decode(hex_str)
+        .expect("Invalid hex")
+        .try_into()
+        .expect("A topic is 32 bytes")
+}
+
+fn indexed_topic(signature: &str, indexed: Token) -> [u8; 32] {
+    let event = LogEvent::build(
+        random_test_address(TEST_EMITTER_ADDRESS_ID),
+        signature,
+        &[indexed],
+        &[],
+    );
+    event.topics[1]
+}
+
+#[test]
+fn test_indexed_uint_array_topic() {
+    // emit Values([1, 2]) for `event Values(uint256[] indexed values)`
+    let values = Token::Array(vec![Token::Uint(Uint::from(1)), Token::Uint(Uint::from(2))]);
+    assert_eq!(
+        indexed_topic("Values(uint256[])", values),
+        topic("e90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0")
+    );
+}
+
+#[test]
+fn test_indexed_fixed_array_topic_matches_dynamic_array() {
+    // Static and dynamic arrays have the same in-place encoding
+    let elements = vec![Token::Uint(Uint::from(1)), Token::Uint(Uint::from(2))];
+    assert_eq!(
+        indexed_topic("Values(uint256[2])", Token::FixedArray(elements.clone())),
+        indexed_topic("Values(uint256[])", Token::Array(elements))
+    );
+}
+
+#[test]
+fn test_indexed_string_array_topic() {
+    // Each string element is padded to a multiple of 32 bytes
+    let names = Token::Array(vec![
+        Token::String("a".to_string()),
+        Token::String("bc".to_string()),
+    ]);
+    assert_eq!(
+        indexed_topic("Names(string[])", names),
+        topic("c67bd33d6cde3ae6fb96523422d6f7251674afefdeec3f634f52284c86af11b8")
+    );
+}
+
+#[test]
+fn test_indexed_tuple_topic() {
+    // emit Entry(Entry(1, "a")) for `event Entry(Entry indexed entry)`
+    let entry = Token::Tuple(vec![
+        Token::Uint(Uint::from(1)),
+        Token::String("a".to_string()),
+    ]);
+    assert_eq!(
+        indexed_topic("Entry((uint256,string))", entry),
+        topic("7dfeb332565b59eb614ab1602ea3784a7ced80b422979bca7c8f4083927ac7e7")
+    );
+}
+
+#[test]
+fn test_indexed_string_topic_hashes_raw_contents() {
+    assert_eq!(
+        indexed_topic("Name(string)", Token::String("a".to_string())),
+        topic("3ac225168df54212a25c1c01fd35bebfea408fdac2e31ddd6f80a4bbf9a5f1cb")
+    );
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host Function Manifest Test
+//!
+//! Verifies that the host function manifest lists every registered EVM host
+//! function descriptor, in order, with the same signature, and that the
+//! catalog gives each of them a category.
+
+use dtvmcore_rust::core::types::ZenValueType;
+use evm_example::mock_evm_bridge::{
+    create_complete_evm_host_functions, create_complete_evm_host_functions_manifest,
+    evm_host_function_catalog, host_function_category, HostFunctionCategory,
+};
+use std::collections::HashSet;
+
+fn type_codes(types: &[ZenValueType]) -> Vec<i32> {
+    types.iter().map(ZenValueType::to_i32).collect()
+}
+
+#[test]
+fn test_manifest_matches_descriptors() {
+    let descriptors = create_complete_evm_host_functions();
+    let manifest = create_complete_evm_host_functions_manifest();
+    assert_eq!(manifest.len(), descriptors.len());
+
+    for ((name, arg_types, ret_types), desc) in manifest.iter().zip(&descriptors) {
+        assert_eq!(name, &desc.name);
+        assert_eq!(
+            type_codes(arg_types),
+            type_codes(&desc.arg_types),
+            "{} arguments",
+            name
+        );
+        assert_eq!(
+            type_codes(ret_types),
+            type_codes(&desc.ret_types),
+            "{} returns",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_catalog_covers_every_descriptor() {
+    let descriptors = create_complete_evm_host_functions();
+    let catalog = evm_host_function_catalog();
+    assert_eq!(
+        catalog.len(),
+        descriptors.len(),
+        "Every descriptor needs a category"
+    );
+
+    let names: HashSet<&str> = catalog.iter().map(|meta| meta.name.as_str()).collect();
+    assert_eq!(
+        names.len(),
+        catalog.len(),
+        "Host function names must be unique"
+    );
+
+    for (meta, desc) in catalog.iter().zip(&descriptors) {
+        assert_eq!(meta.arity, desc.arg_types.len(), "{} arity", meta.name);
+        assert_eq!(meta.returns_value, !desc.ret_types.is_empty());
+    }
+
+    let get_gas_left = catalog
+        .iter()
+        .find(|meta| meta.name == "getGasLeft")
+        .unwrap();
+    assert_eq!(get_gas_left.category, HostFunctionCategory::Gas);
+    assert!(get_gas_left.returns_value);
+    assert_eq!(host_function_category("notAHostFunction"), None);
+}
@@ -0,0 +1,703 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! MockContext Unit Tests
+//!
+//! Exercises MockContext state handling (nonces, code, balances, storage,
+//! block and fee info) directly through its builder and `EvmHost` methods,
+//! without executing contract code.
+
+mod common;
+
+use common::*;
+use dtvmcore_rust::evm::host_functions::BLOCK_HASH_WINDOW;
+use dtvmcore_rust::evm::traits::{
+    create2_address, create_address, EvmHost, Hardfork, EMPTY_CODE_HASH,
+};
+use evm_example::mock_context::{
+    AccountState, ContractNotFoundPolicy, StorageBackend, BASE_FEE_HISTORY_SIZE,
+};
+use num_bigint::BigUint;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+#[test]
+fn test_create_advances_nonce() {
+    let creator = [0x42; 20];
+    let context = MockContext::builder().with_address(creator).build();
+
+    assert_eq!(context.get_nonce(&creator), 0);
+
+    for expected_nonce in 0..3u64 {
+        let result =
+            context.create_contract(&creator, &[0u8; 32], &[0x01, 0x02], &[], 0, None, false);
+
+        assert!(result.success, "CREATE should succeed");
+        assert_eq!(
+            result.contract_address,
+            Some(create_address(&creator, expected_nonce)),
+            "CREATE should derive the address from the pre-increment nonce {}",
+            expected_nonce
+        );
+        assert_eq!(context.get_nonce(&creator), expected_nonce + 1);
+    }
+
+    assert_eq!(context.get_nonce(&creator), 3);
+}
+
+#[test]
+fn test_repeated_create_yields_distinct_addresses() {
+    let creator = [0x42; 20];
+    let context = MockContext::builder().with_address(creator).build();
+
+    let addresses: HashSet<[u8; 20]> = (0..3)
+        .map(|_| {
+            context
+                .create_contract(&creator, &[0u8; 32], &[0x01, 0x02], &[], 0, None, false)
+                .contract_address
+                .expect("CREATE should return an address")
+        })
+        .collect();
+
+    assert_eq!(addresses.len(), 3, "Each CREATE should yield a new address");
+}
+
+#[test]
+fn test_set_nonce_controls_next_create_address() {
+    let creator = [0x42; 20];
+    let context = MockContext::builder().with_address(creator).build();
+    context.set_nonce(&creator, 7);
+
+    let result = context.create_contract(&creator, &[0u8; 32], &[0x01, 0x02], &[], 0, None, false);
+
+    assert_eq!(result.contract_address, Some(create_address(&creator, 7)));
+    assert_eq!(context.get_nonce(&creator), 8);
+}
+
+#[test]
+fn test_create2_address_derivation() {
+    let creator = [0x42; 20];
+    let context = MockContext::builder().with_address(creator).build();
+    let init_code = [0x01, 0x02];
+    let init_code_hash = context.keccak256(init_code.to_vec());
+
+    let result = context.create_contract(
+        &creator,
+        &[0u8; 32],
+        &init_code,
+        &[],
+        0,
+        Some([0x07; 32]),
+        true,
+    );
+
+    assert!(result.success, "CREATE2 should succeed");
+    assert_eq!(
+        result.contract_address,
+        Some(create2_address(&creator, &[0x07; 32], &init_code_hash))
+    );
+    assert_eq!(
+        context.get_nonce(&creator),
+        0,
+        "CREATE2 should not use the nonce"
+    );
+}
+
+#[test]
+fn test_call_value_wei() {
+    let one_eth = 10u128.pow(18);
+    let context = MockContext::builder().with_call_value_wei(one_eth).build();
+
+    assert_eq!(context.call_value_u256(), BigUint::from(one_eth));
+    assert_eq!(&context.get_call_value()[24..], &one_eth.to_be_bytes()[8..]);
+    assert!(context.get_call_value()[..24].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_storage_diff() {
+    let context = MockContext::builder().build();
+    let slot = |n: u8| {
+        let mut word = [0u8; 32];
+        word[31] = n;
+        word
+    };
+    context.storage_store(&slot(1), &slot(10));
+    context.storage_store(&slot(1), &slot(11));
+    context.storage_store(&slot(2), &slot(20));
+
+    let diff = context.storage_diff();
+    assert_eq!(
+        diff,
+        vec![
+            (format!("0x{}", hex::encode(slot(1))), [0u8; 32], slot(11)),
+            (format!("0x{}", hex::encode(slot(2))), [0u8; 32], slot(20)),
+        ]
+    );
+
+    context.clear_storage_diff();
+    context.storage_store(&slot(2), &slot(21));
+    assert_eq!(
+        context.storage_diff(),
+        vec![(format!("0x{}", hex::encode(slot(2))), slot(20), slot(21))]
+    );
+}
+
+#[test]
+fn test_last_create2_init_code_hash() {
+    use sha3::{Digest, Keccak256};
+
+    let creator = [0x42; 20];
+    let context = MockContext::builder().with_address(creator).build();
+    assert_eq!(context.last_create2_init_code_hash(), None);
+
+    let init_code = [0x01, 0x02, 0x03];
+    let result = context.create_contract(
+        &creator,
+        &[0u8; 32],
+        &init_code,
+        &[],
+        0,
+        Some([0x07; 32]),
+        true,
+    );
+    assert!(result.success, "CREATE2 should succeed");
+
+    let expected_hash: [u8; 32] = Keccak256::digest(init_code).into();
+    assert_eq!(context.last_create2_init_code_hash(), Some(expected_hash));
+    assert_eq!(
+        result.contract_address,
+        Some(create2_address(&creator, &[0x07; 32], &expected_hash))
+    );
+}
+
+#[test]
+fn test_runtime_code_is_distinct_from_init_code() {
+    let creator = [0x42; 20];
+    let context = MockContext::builder().with_address(creator).build();
+    let init_code = [0x00, 0x00, 0x00, 0x02, 0x01, 0x02];
+
+    let address = context
+        .create_contract(&creator, &[0u8; 32], &init_code, &[], 0, None, false)
+        .contract_address
+        .expect("CREATE should return an address");
+
+    assert_eq!(context.get_runtime_code(&address), Some(vec![0x01, 0x02]));
+    assert_eq!(
+        context.get_contract_info(&address).map(|info| info.code),
+        Some(init_code.to_vec()),
+        "The registry should keep the init code used for CREATE"
+    );
+    assert_eq!(context.get_runtime_code(&[0x10; 20]), None);
+}
+
+#[test]
+fn test_self_code_hash_uses_runtime_code() {
+    let creator = [0x42; 20];
+    let context = MockContext::builder().with_address(creator).build();
+    let init_code = [0x00, 0x00, 0x00, 0x02, 0x01, 0x02];
+
+    let address = context
+        .create_contract(&creator, &[0u8; 32], &init_code, &[], 0, None, false)
+        .contract_address
+        .expect("CREATE should return an address");
+
+    // Execute as the created contract
+    let mut frame = context.clone();
+    frame.set_address(address);
+
+    let runtime_code_hash = context.keccak256(vec![0x01, 0x02]);
+    assert_eq!(frame.get_self_code_hash(), runtime_code_hash);
+    assert_ne!(
+        frame.get_self_code_hash(),
+        context.keccak256(init_code.to_vec()),
+        "The self code hash should not hash the init code"
+    );
+}
+
+#[test]
+fn test_external_code_hash_account_existence() {
+    let contract = [0x43; 20];
+    let empty_contract = [0x44; 20];
+    let funded = [0x45; 20];
+    let unknown = [0x46; 20];
+    let code = vec![0x01, 0x02, 0x03];
+    let context = MockContext::builder()
+        .with_contract(contract, "contract", code.clone())
+        .with_contract(empty_contract, "empty", Vec::new())
+        .with_balance(funded, [0x01; 32])
+        .build();
+
+    assert_eq!(
+        context.get_external_code_hash(&contract),
+        Some(context.keccak256(code))
+    );
+    assert_eq!(
+        context.get_external_code_hash(&empty_contract),
+        Some(EMPTY_CODE_HASH)
+    );
+    assert_eq!(
+        context.get_external_code_hash(&funded),
+        Some(EMPTY_CODE_HASH)
+    );
+    assert_eq!(context.get_external_code_hash(&unknown), None);
+}
+
+#[test]
+fn test_external_code_hash_is_cached() {
+    let target = [0x43; 20];
+    let code = vec![0x01, 0x02, 0x03];
+    let mut context = MockContext::builder()
+        .with_address([0x42; 20])
+        .with_mutable_code(true)
+        .build();
+    context.register_contract(target, "target".to_string(), code.clone());
+
+    let first = context.get_external_code_hash(&target);
+    let second = context.get_external_code_hash(&target);
+
+    assert_eq!(first, Some(context.keccak256(code)));
+    assert_eq!(first, second);
+    assert_eq!(
+        context.code_hash_computations(),
+        1,
+        "Code should be hashed once"
+    );
+
+    context
+        .set_contract_code(&target, vec![0x04])
+        .expect("Code should be mutable");
+    assert_eq!(
+        context.get_external_code_hash(&target),
+        Some(context.keccak256(vec![0x04])),
+        "Upgraded code should be rehashed"
+    );
+    assert_eq!(context.code_hash_computations(), 2);
+}
+
+#[test]
+fn test_base_fee_history_is_bounded() {
+    let mut block_info = BlockInfo::default();
+    for fee in 0..=BASE_FEE_HISTORY_SIZE as u8 {
+        let mut base_fee = [0u8; 32];
+        base_fee[31] = fee + 2;
+        block_info.push_base_fee(base_fee);
+    }
+
+    assert_eq!(block_info.base_fee_history.len(), BASE_FEE_HISTORY_SIZE);
+    assert_eq!(
+        block_info.get_base_fee_at(0).map(|fee| fee[31]),
+        Some(BASE_FEE_HISTORY_SIZE as u8 + 2)
+    );
+    assert_eq!(
+        block_info.get_base_fee_at(1).map(|fee| fee[31]),
+        Some(BASE_FEE_HISTORY_SIZE as u8 + 1)
+    );
+    // The default base fee (1 wei) was the oldest entry and has been dropped
+    assert_eq!(
+        block_info
+            .get_base_fee_at(BASE_FEE_HISTORY_SIZE as u64)
+            .map(|fee| fee[31]),
+        Some(3)
+    );
+    assert_eq!(
+        block_info.get_base_fee_at(BASE_FEE_HISTORY_SIZE as u64 + 1),
+        None
+    );
+}
+
+#[test]
+fn test_self_destruct_credits_beneficiary() {
+    let contract = [0x42; 20];
+    let beneficiary = [0x43; 20];
+    let mut contract_balance = [0u8; 32];
+    contract_balance[31] = 0x64;
+    let mut beneficiary_balance = [0u8; 32];
+    beneficiary_balance[31] = 0x0a;
+    let context = MockContext::builder()
+        .with_address(contract)
+        .with_balance(contract, contract_balance)
+        .with_balance(beneficiary, beneficiary_balance)
+        .build();
+
+    let transferred = context.self_destruct(&beneficiary);
+
+    let mut expected_beneficiary_balance = [0u8; 32];
+    expected_beneficiary_balance[31] = 0x6e;
+    assert_eq!(transferred, contract_balance);
+    assert_eq!(
+        context.get_destruct_amount(&contract),
+        Some(contract_balance)
+    );
+    assert_eq!(
+        context.get_external_balance(&beneficiary),
+        expected_beneficiary_balance
+    );
+    assert_eq!(context.get_external_balance(&contract), [0u8; 32]);
+    assert_eq!(context.get_destruct_amount(&beneficiary), None);
+}
+
+#[test]
+fn test_self_destruct_keeps_balance_when_credit_overflows() {
+    let contract = [0x42; 20];
+    let beneficiary = [0x43; 20];
+    let mut contract_balance = [0u8; 32];
+    contract_balance[31] = 0x64;
+    let context = MockContext::builder()
+        .with_address(contract)
+        .with_balance(contract, contract_balance)
+        .with_balance(beneficiary, [0xff; 32])
+        .with_hardfork(Hardfork::Shanghai)
+        .build();
+
+    let transferred = context.self_destruct(&beneficiary);
+
+    // Nothing is credited past 2^256 - 1, so the contract keeps its balance
+    assert_eq!(transferred, [0u8; 32]);
+    assert_eq!(context.get_destruct_amount(&contract), Some([0u8; 32]));
+    assert_eq!(context.get_external_balance(&beneficiary), [0xff; 32]);
+    assert_eq!(context.get_external_balance(&contract), contract_balance);
+}
+
+/// Storage backend namespaced by address, so one account can be cleared
+#[derive(Default)]
+struct AccountStorage {
+    slots: HashMap<([u8; 20], [u8; 32]), [u8; 32]>,
+}
+
+impl StorageBackend for AccountStorage {
+    fn get(&self, address: &[u8; 20], key: &[u8; 32]) -> [u8; 32] {
+        self.slots
+            .get(&(*address, *key))
+            .copied()
+            .unwrap_or([0u8; 32])
+    }
+
+    fn set(&mut self, address: &[u8; 20], key: &[u8; 32], value: &[u8; 32]) {
+        self.slots.insert((*address, *key), *value);
+    }
+
+    fn clear_account(&mut self, address: &[u8; 20]) {
+        self.slots
+            .retain(|(slot_address, _), _| slot_address != address);
+    }
+}
+
+#[test]
+fn test_self_destruct_clears_storage_before_cancun() {
+    let contract = [0x42; 20];
+    let other = [0x44; 20];
+    let slot = [0x01; 32];
+    let backend = Rc::new(RefCell::new(AccountStorage::default()));
+    backend.borrow_mut().set(&other, &slot, &[0x22; 32]);
+    let context = MockContext::builder()
+        .with_address(contract)
+        .with_storage_backend(backend.clone())
+        .with_hardfork(Hardfork::Shanghai)
+        .build();
+    context.storage_store(&slot, &[0x11; 32]);
+
+    context.self_destruct(&[0x43; 20]);
+
+    assert!(context.is_destructed(&contract));
+    assert_eq!(
+        context.storage_load(&slot),
+        [0u8; 32],
+        "A deleted contract should have no storage"
+    );
+    assert_eq!(
+        backend.borrow().get(&other, &slot),
+        [0x22; 32],
+        "Other accounts keep their storage"
+    );
+}
+
+#[test]
+fn test_self_destruct_keeps_shared_map_storage() {
+    // The default backend is not namespaced by address, so it cannot clear
+    // one account without wiping every other contract's storage
+    let contract = [0x42; 20];
+    let slot = [0x01; 32];
+    let context = MockContext::builder()
+        .with_address(contract)
+        .with_hardfork(Hardfork::Shanghai)
+        .build();
+    context.storage_store(&slot, &[0x11; 32]);
+
+    context.self_destruct(&[0x43; 20]);
+
+    assert!(context.is_destructed(&contract));
+    assert_eq!(context.storage_load(&slot), [0x11; 32]);
+}
+
+#[test]
+fn test_self_destruct_keeps_existing_contract_since_cancun() {
+    let contract = [0x42; 20];
+    let beneficiary = [0x43; 20];
+    let slot = [0x01; 32];
+    let mut balance = [0u8; 32];
+    balance[31] = 0x64;
+    let context = MockContext::builder()
+        .with_address(contract)
+        .with_balance(contract, balance)
+        .with_hardfork(Hardfork::Cancun)
+        .build();
+    context.storage_store(&slot, &[0x11; 32]);
+
+    let transferred = context.self_destruct(&beneficiary);
+
+    // EIP-6780: only the balance moves
+    assert_eq!(transferred, balance);
+    assert_eq!(context.get_external_balance(&contract), [0u8; 32]);
+    assert!(!context.is_destructed(&contract));
+    assert_eq!(context.storage_load(&slot), [0x11; 32]);
+}
+
+#[test]
+fn test_self_destruct_deletes_contract_created_in_transaction_since_cancun() {
+    let creator = [0x42; 20];
+    let slot = [0x01; 32];
+    let context = MockContext::builder()
+        .with_address(creator)
+        .with_hardfork(Hardfork::Cancun)
+        .build();
+    let created = context
+        .create_contract(&creator, &[0u8; 32], &[0x01, 0x02], &[], 0, None, false)
+        .contract_address
+        .expect("CREATE should return an address");
+
+    // Execute as the created contract
+    let mut frame = context.clone();
+    frame.set_address(created);
+    frame.storage_store(&slot, &[0x11; 32]);
+    frame.self_destruct(&creator);
+
+    assert!(context.is_destructed(&created));
+    assert!(context.get_contract_info(&created).is_none());
+    assert_eq!(context.storage_load(&slot), [0u8; 32]);
+}
+
+#[test]
+fn test_set_contract_code_on_immutable_context_fails() {
+    let mut context = MockContext::builder().build();
+    context.register_contract([0x10; 20], "Proxy".to_string(), vec![0x01]);
+
+    assert!(context.set_contract_code(&[0x10; 20], vec![0x02]).is_err());
+    assert_eq!(
+        context.get_contract_info(&[0x10; 20]).map(|info| info.code),
+        Some(vec![0x01])
+    );
+}
+
+#[test]
+fn test_set_contract_code_on_mutable_context() {
+    let mut context = MockContext::builder().with_mutable_code(true).build();
+    context.register_contract([0x10; 20], "Proxy".to_string(), vec![0x01]);
+
+    context
+        .set_contract_code(&[0x10; 20], vec![0x02])
+        .expect("Upgrade should be allowed");
+    assert_eq!(
+        context.get_contract_info(&[0x10; 20]).map(|info| info.code),
+        Some(vec![0x02])
+    );
+    assert!(context.set_contract_code(&[0x11; 20], vec![0x03]).is_err());
+}
+
+#[test]
+fn test_with_genesis() {
+    let eoa = [0x01; 20];
+    let contract = [0x02; 20];
+
+    let mut eoa_balance = [0u8; 32];
+    eoa_balance[31] = 100;
+    let mut contract_balance = [0u8; 32];
+    contract_balance[30] = 1;
+    let mut contract_storage = HashMap::new();
+    contract_storage.insert([0x0a; 32], [0x0b; 32]);
+
+    let mut genesis = HashMap::new();
+    genesis.insert(
+        eoa,
+        AccountState {
+            balance: eoa_balance,
+            nonce: 5,
+            ..Default::default()
+        },
+    );
+    genesis.insert(
+        contract,
+        AccountState {
+            balance: contract_balance,
+            nonce: 1,
+            code: vec![0x00, 0x61, 0x73, 0x6d],
+            storage: contract_storage,
+        },
+    );
+
+    let context = MockContext::builder().with_genesis(genesis).build();
+
+    assert_eq!(context.get_external_balance(&eoa), eoa_balance);
+    assert_eq!(context.get_external_balance(&contract), contract_balance);
+    assert_eq!(context.get_nonce(&eoa), 5);
+    assert_eq!(context.get_nonce(&contract), 1);
+    assert!(context.get_contract_info(&eoa).is_none());
+    assert_eq!(
+        context.get_contract_info(&contract).map(|info| info.code),
+        Some(vec![0x00, 0x61, 0x73, 0x6d])
+    );
+    assert_eq!(context.storage_load(&[0x0a; 32]), [0x0b; 32]);
+}
+
+#[test]
+fn test_can_afford_base_fee() {
+    let mut base_fee = [0u8; 32];
+    base_fee[31] = 10;
+
+    let context = MockContext::builder()
+        .with_base_fee(base_fee)
+        .with_gas_price_wei(10)
+        .build();
+    assert!(context.can_afford_base_fee());
+
+    let context = MockContext::builder()
+        .with_base_fee(base_fee)
+        .with_gas_price_wei(9)
+        .build();
+    assert!(
+        !context.can_afford_base_fee(),
+        "Max fee below the base fee should not be includable"
+    );
+}
+
+#[test]
+fn test_gas_price_is_clamped_when_read() {
+    let context = MockContext::builder()
+        .with_gas_price_wei(500)
+        .with_gas_price_bounds_wei(10, 100)
+        .build();
+    assert_eq!(context.get_tx_gas_price()[24..], 100u64.to_be_bytes());
+
+    let context = MockContext::builder()
+        .with_gas_price_wei(5)
+        .with_gas_price_bounds_wei(10, 100)
+        .build();
+    assert_eq!(context.get_tx_gas_price()[24..], 10u64.to_be_bytes());
+
+    let context = MockContext::builder().with_gas_price_wei(50).build();
+    assert_eq!(context.get_tx_gas_price()[24..], 50u64.to_be_bytes());
+}
+
+#[test]
+fn test_context_is_not_static_by_default() {
+    let context = MockContext::builder().build();
+    assert!(!context.is_static());
+}
+
+#[test]
+fn test_call_unregistered_address_returns_empty_success() {
+    // The caller's code is not valid WASM, so re-running it would fail
+    let context = MockContext::builder()
+        .with_code(vec![0xde, 0xad, 0xbe, 0xef])
+        .with_address([0x42; 20])
+        .build();
+
+    let result = context.call_contract(&[0x99; 20], &[0x42; 20], &[0u8; 32], &[0x01], 50000);
+
+    assert!(result.success, "Call to an empty account should succeed");
+    assert!(result.return_data.is_empty());
+    assert!(!context.has_return_data());
+}
+
+#[test]
+fn test_call_unregistered_address_with_fail_policy() {
+    let context = MockContext::builder()
+        .with_code(vec![0xde, 0xad, 0xbe, 0xef])
+        .with_contract_not_found_policy(ContractNotFoundPolicy::Fail)
+        .build();
+
+    let result = context.call_contract(&[0x99; 20], &[0x42; 20], &[0u8; 32], &[0x01], 50000);
+
+    assert!(
+        !result.success,
+        "Call to an unregistered address should fail"
+    );
+    assert_eq!(
+        context.last_call_error(),
+        Some(format!(
+            "No contract registered at 0x{}",
+            hex::encode([0x99; 20])
+        ))
+    );
+}
+
+#[test]
+fn test_snapshot_restore_storage() {
+    let context = MockContext::builder().build();
+    let key = [0x01; 32];
+
+    context.storage_store(&key, &[0x11; 32]);
+    let snapshot = context.snapshot();
+
+    context.storage_store(&key, &[0x22; 32]);
+    context.storage_store(&[0x02; 32], &[0x33; 32]);
+    context.restore(snapshot);
+
+    assert_eq!(context.storage_load(&key), [0x11; 32]);
+    assert_eq!(context.storage_load(&[0x02; 32]), [0u8; 32]);
+}
+
+#[test]
+fn test_keccak_override_matches_default() {
+    fn one_shot_keccak(data: &[u8]) -> [u8; 32] {
+        use sha3::{Digest, Keccak256};
+        Keccak256::digest(data).into()
+    }
+
+    let default_context = MockContext::builder().build();
+    let accelerated_context = MockContext::builder().with_keccak(one_shot_keccak).build();
+
+    let inputs: [&[u8]; 4] = [
+        b"",
+        b"abc",
+        b"transfer(address,uint256)",
+        &[0xab; 200], // Spans more than one 136-byte block
+    ];
+    for input in inputs {
+        assert_eq!(
+            accelerated_context.keccak256(input.to_vec()),
+            default_context.keccak256(input.to_vec())
+        );
+    }
+
+    // The override is what actually hashes
+    fn sentinel_keccak(_data: &[u8]) -> [u8; 32] {
+        [0xab; 32]
+    }
+    let sentinel_context = MockContext::builder().with_keccak(sentinel_keccak).build();
+    assert_eq!(sentinel_context.keccak256(b"abc".to_vec()), [0xab; 32]);
+    assert_eq!(sentinel_context.keccak256_slice(b"abc"), [0xab; 32]);
+}
+
+#[test]
+fn test_block_hash_window() {
+    let known_hash = [0x11; 32];
+    let context = MockContext::builder()
+        .with_block_number(1000)
+        .with_block_hash(999, known_hash)
+        .with_block_hash(700, [0x22; 32])
+        .build();
+
+    // In-window blocks return their known hash, or the mock hash otherwise
+    assert_eq!(context.get_block_hash(999), Some(known_hash));
+    assert!(context.get_block_hash(1000 - BLOCK_HASH_WINDOW).is_some());
+
+    // Blocks older than the window have no hash, even if one is known
+    assert_eq!(context.get_block_hash(999 - BLOCK_HASH_WINDOW), None);
+    assert_eq!(context.get_block_hash(700), None);
+
+    // The current block and future blocks have no hash yet
+    assert_eq!(context.get_block_hash(1000), None);
+    assert_eq!(context.get_block_hash(1001), None);
+    assert_eq!(context.get_block_hash(-1), None);
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ParamBuilder Encoding Test
+//!
+//! Verifies that signed integers and fixed-size byte arrays built with
+//! `ParamBuilder` encode to the ABI words Solidity expects and decode back.
+
+mod common;
+
+use common::*;
+use ethabi::{decode, encode, ParamType, Token};
+
+#[test]
+fn test_int256_minus_one_is_twos_complement() {
+    let encoded = encode(&ParamBuilder::new().int256(-1).build());
+    assert_eq!(encoded, vec![0xff; 32], "-1 should encode as all ones");
+
+    let decoded = decode(&[ParamType::Int(256)], &encoded).expect("Failed to decode int256");
+    let Token::Int(value) = &decoded[0] else {
+        panic!("expected an int256 token, got {:?}", decoded[0]);
+    };
+    // Two's complement: -1 + 1 wraps to zero
+    assert!(value.overflowing_add(1.into()).0.is_zero());
+}
+
+#[test]
+fn test_int256_negative_i64_is_sign_extended() {
+    let encoded = encode(&ParamBuilder::new().int256(i64::MIN).build());
+    assert_eq!(encoded[..24], [0xff; 24]);
+    assert_eq!(encoded[24..], i64::MIN.to_be_bytes());
+}
+
+#[test]
+fn test_int256_positive_is_zero_extended() {
+    let encoded = encode(&ParamBuilder::new().int256_i128(i128::MAX).build());
+    assert_eq!(encoded[..16], [0u8; 16]);
+    assert_eq!(encoded[16..], i128::MAX.to_be_bytes());
+}
+
+#[test]
+fn test_bytes4_is_right_padded() {
+    let selector = [0xa9, 0x05, 0x9c, 0xbb];
+    let encoded = encode(&ParamBuilder::new().bytes_n(&selector).build());
+    assert_eq!(encoded[..4], selector);
+    assert_eq!(encoded[4..], [0u8; 28], "bytes4 should be right-padded");
+
+    let decoded = decode(&[ParamType::FixedBytes(4)], &encoded).expect("Failed to decode bytes4");
+    assert_eq!(decoded, vec![Token::FixedBytes(selector.to_vec())]);
+}
+
+#[test]
+#[should_panic(expected = "bytesN must be 1 to 32 bytes long")]
+fn test_bytes_n_rejects_more_than_32_bytes() {
+    ParamBuilder::new().bytes_n(&[0u8; 33]);
+}
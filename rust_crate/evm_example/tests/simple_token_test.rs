@@ -13,6 +13,10 @@
 mod common;
 
 use common::*;
+use dtvmcore_rust::evm::gas::intrinsic_gas;
+use dtvmcore_rust::evm::traits::EvmHost;
+use ethabi::{ParamType, Token};
+use sha3::{Digest, Keccak256};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -29,6 +33,10 @@ const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3]; // approve(address,u
 const TRANSFER_FROM_SELECTOR: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd]; // transferFrom(address,address,uint256)
 const ALLOWANCE_SELECTOR: [u8; 4] = [0xdd, 0x62, 0xed, 0x3e]; // allowance(address,address)
 
+/// Intrinsic gas of transfer(random_test_address(3), 1000): the base cost plus
+/// 7 non-zero bytes (selector, address and amount) and 61 zero bytes
+const TRANSFER_INTRINSIC_GAS: u64 = 21_000 + 7 * 16 + 61 * 4;
+
 #[test]
 fn test_simple_token_contract() {
     // Load SimpleToken WASM module
@@ -70,6 +78,103 @@ fn test_simple_token_contract() {
     test_balance_of(&executor, &mut context);
     test_mint(&executor, &mut context);
     test_transfer(&executor, &mut context);
+    test_transfer_gas(&executor, &mut context);
+    test_transfer_event_by_topic0(&executor, &mut context);
+    test_transfer_insufficient_balance(&executor, &mut context);
+}
+
+/// Storage slot of the `balances` mapping in SimpleToken
+const BALANCES_SLOT: u8 = 4;
+
+/// Storage key of `balances[account]`: keccak256(account ++ slot)
+fn balance_key(account: &[u8; 20]) -> [u8; 32] {
+    let mut preimage = [0u8; 64];
+    preimage[12..32].copy_from_slice(account);
+    preimage[63] = BALANCES_SLOT;
+    Keccak256::digest(preimage).into()
+}
+
+#[test]
+fn test_transfer_access_list() {
+    let token_wasm_bytes =
+        load_wasm_file("../example/SimpleToken.wasm").expect("Failed to load SimpleToken.wasm");
+    let executor = ContractExecutor::new().expect("Failed to create contract executor");
+    let token_address = [0x55; 20];
+    let sender = random_test_address(1);
+    let recipient = random_test_address(2);
+
+    let mut context = MockContext::builder()
+        .with_code(token_wasm_bytes)
+        .with_address(token_address)
+        .with_caller(sender)
+        .with_call_data(encode_constructor(
+            &ParamBuilder::new().uint256(1000000u64).build(),
+        ))
+        .build();
+    executor
+        .deploy_contract("simple_token", &mut context)
+        .expect("Failed to deploy contract");
+
+    let params = ParamBuilder::new()
+        .address(&recipient)
+        .uint256(1000u64)
+        .build();
+    set_call_data_with_params(&mut context, &TRANSFER_SELECTOR, params);
+
+    let access_list = executor
+        .generate_access_list("simple_token", &mut context)
+        .expect("Failed to generate access list");
+
+    let (_, token_slots) = access_list
+        .iter()
+        .find(|(address, _)| *address == token_address)
+        .expect("The token contract should be in the access list");
+    assert!(token_slots.contains(&balance_key(&sender)));
+    assert!(token_slots.contains(&balance_key(&recipient)));
+}
+
+#[test]
+fn test_decode_transfer_log() {
+    let from = random_test_address(1);
+    let to = random_test_address(2);
+    let event = LogEvent::build(
+        [0x55; 20],
+        "Transfer(address,address,uint256)",
+        &ParamBuilder::new().address(&from).address(&to).build(),
+        &ParamBuilder::new().uint256(1000u64).build(),
+    );
+
+    let tokens = event
+        .decode(
+            "Transfer(address,address,uint256)",
+            &[ParamType::Address, ParamType::Address],
+            &[ParamType::Uint(256)],
+        )
+        .expect("Failed to decode Transfer event");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Address(from.into()),
+            Token::Address(to.into()),
+            Token::Uint(1000u64.into()),
+        ]
+    );
+
+    // A different signature or indexed layout is rejected
+    assert!(event
+        .decode(
+            "Approval(address,address,uint256)",
+            &[ParamType::Address, ParamType::Address],
+            &[ParamType::Uint(256)]
+        )
+        .is_err());
+    assert!(event
+        .decode(
+            "Transfer(address,address,uint256)",
+            &[ParamType::Address],
+            &[ParamType::Uint(256)]
+        )
+        .is_err());
 }
 
 fn test_total_supply(executor: &ContractExecutor, context: &mut MockContext) {
@@ -169,6 +274,26 @@ fn test_transfer(executor: &ContractExecutor, context: &mut MockContext) {
         .call_contract_function("simple_token", context)
         .expect("Failed to call transfer()");
     assert!(result.success, "transfer() should succeed");
+
+    let expected_event = LogEvent::build(
+        [0x55; 20],
+        "Transfer(address,address,uint256)",
+        &ParamBuilder::new()
+            .address(&random_test_address(1))
+            .address(&spender_address)
+            .build(),
+        &ParamBuilder::new().uint256(1000u64).build(),
+    );
+    assert_eq!(
+        context.take_events().last(),
+        Some(&expected_event),
+        "transfer() should emit the expected Transfer event"
+    );
+    assert!(
+        context.get_events().is_empty(),
+        "take_events() should clear the emitted events"
+    );
+
     let params = ParamBuilder::new().address(&spender_address).build();
     set_call_data_with_params(context, &BALANCE_OF_SELECTOR, params);
 
@@ -185,3 +310,87 @@ fn test_transfer(executor: &ContractExecutor, context: &mut MockContext) {
         count_value
     );
 }
+
+fn test_transfer_gas(executor: &ContractExecutor, context: &mut MockContext) {
+    let spender_address = random_test_address(3);
+    let params = ParamBuilder::new()
+        .address(&spender_address)
+        .uint256(1000u64)
+        .build();
+    set_call_data_with_params(context, &TRANSFER_SELECTOR, params);
+    assert_eq!(
+        intrinsic_gas(context.call_data_copy()),
+        TRANSFER_INTRINSIC_GAS
+    );
+
+    // Both balances are already non-zero, so repeating the transfer runs the
+    // same code and uses the same execution gas
+    let result = executor
+        .call_contract_function("simple_token", context)
+        .expect("Failed to call transfer()");
+    assert!(result.success, "transfer() should succeed");
+    let execution_gas = result.gas_used as u64;
+
+    let total = executor
+        .total_transaction_gas("simple_token", context)
+        .expect("Failed to run transfer() transaction");
+
+    // Neither balance is cleared, so there is no refund to subtract
+    assert_eq!(
+        total,
+        TRANSFER_INTRINSIC_GAS + execution_gas,
+        "transfer() should be charged its intrinsic and execution gas exactly"
+    );
+}
+
+fn test_transfer_event_by_topic0(executor: &ContractExecutor, context: &mut MockContext) {
+    let recipient_address = random_test_address(5);
+    let params = ParamBuilder::new()
+        .address(&recipient_address)
+        .uint256(250u64)
+        .build();
+    set_call_data_with_params(context, &TRANSFER_SELECTOR, params);
+
+    context.take_events();
+    let result = executor
+        .call_contract_function("simple_token", context)
+        .expect("Failed to call transfer()");
+    assert!(result.success, "transfer() should succeed");
+
+    let transfer_topic: [u8; 32] = Keccak256::digest(b"Transfer(address,address,uint256)").into();
+    let transfers = context.events_matching_topic0(&transfer_topic);
+    assert_eq!(
+        transfers.len(),
+        1,
+        "transfer() should emit one Transfer event"
+    );
+
+    let event = &transfers[0];
+    assert_eq!(event.topics.len(), 3, "Transfer should be a LOG3 event");
+    assert_eq!(&event.topics[2][12..], &recipient_address);
+    assert_eq!(decode_uint256(&event.data).unwrap(), 250);
+
+    assert!(
+        context.events_matching_topic0(&[0u8; 32]).is_empty(),
+        "No event should match an unrelated topic0"
+    );
+}
+
+fn test_transfer_insufficient_balance(executor: &ContractExecutor, context: &mut MockContext) {
+    // A holder with no tokens cannot transfer any
+    let owner_address = *context.get_caller();
+    context.set_caller(random_test_address(4));
+
+    let params = ParamBuilder::new()
+        .address(&random_test_address(3))
+        .uint256(1u64)
+        .build();
+    set_call_data_with_params(context, &TRANSFER_SELECTOR, params);
+
+    let result = executor
+        .call_contract_function("simple_token", context)
+        .expect("Failed to call transfer()");
+    expect_revert(&result, Some("Insufficient balance"));
+
+    context.set_caller(owner_address);
+}
@@ -53,6 +53,11 @@ contract BaseHostFunctions {
         return gasleft();
     }
 
+    function getGasleftTwice() public view returns (uint256 first, uint256 second) {
+        first = gasleft();
+        second = gasleft();
+    }
+
     /**
      * @dev Get chain ID
      */
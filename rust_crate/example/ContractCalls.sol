@@ -167,6 +167,15 @@ contract ContractCalls {
         return (success, returnData);
     }
     
+    /**
+     * @dev Read the latest price from an external oracle
+     */
+    function getOraclePrice(address oracle) public view returns (uint256) {
+        (bool success, bytes memory data) = oracle.staticcall(abi.encodeWithSignature("latestPrice()"));
+        require(success, "Oracle call failed");
+        return abi.decode(data, (uint256));
+    }
+    
     /**
      * @dev Fallback function to receive calls
      */
@@ -22,6 +22,10 @@ contract SimpleTarget {
         return value;
     }
     
+    function getState() public view returns (uint256, address) {
+        return (value, caller);
+    }
+    
     function revertWithMessage() public pure {
         revert("Target contract reverted");
     }
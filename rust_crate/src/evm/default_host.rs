@@ -0,0 +1,268 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Default EVM host
+//!
+//! `DefaultHost` implements every required `EvmHost` method with an inert
+//! default, so a new host implementation can start from it and fill in
+//! behavior incrementally. The defaults are:
+//!
+//! - Addresses, words, balances and storage values read as zero
+//! - Call data, code and return data are empty; block hashes and external
+//!   code lookups return `None`
+//! - `get_gas_left` returns the gas it is given
+//! - Stores, logs and execution status updates are ignored
+//! - Calls and contract creations fail without return data
+//!
+//! With warnings enabled, each call to one of these methods logs a warning
+//! naming it (through `log` when that feature is enabled, stderr otherwise).
+
+use crate::evm::traits::{ContractCallResult, ContractCreateResult, EvmHost, LogEvent};
+
+/// EVM host that returns a documented default from every required method
+#[derive(Clone, Debug, Default)]
+pub struct DefaultHost {
+    /// Whether calls to unimplemented methods log a warning
+    warn_unimplemented: bool,
+}
+
+impl DefaultHost {
+    /// Create a host that silently returns defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log a warning whenever an unimplemented method is called
+    pub fn with_warnings(mut self, enabled: bool) -> Self {
+        self.warn_unimplemented = enabled;
+        self
+    }
+
+    fn unimplemented(&self, method: &str) {
+        if !self.warn_unimplemented {
+            return;
+        }
+
+        #[cfg(feature = "log")]
+        log::warn!(
+            "DefaultHost::{} is not implemented, returning a default",
+            method
+        );
+        #[cfg(not(feature = "log"))]
+        eprintln!(
+            "DefaultHost::{} is not implemented, returning a default",
+            method
+        );
+    }
+}
+
+impl EvmHost for DefaultHost {
+    fn get_address(&self) -> &[u8; 20] {
+        self.unimplemented("get_address");
+        &[0u8; 20]
+    }
+
+    fn get_block_hash(&self, _block_number: i64) -> Option<[u8; 32]> {
+        self.unimplemented("get_block_hash");
+        None
+    }
+
+    fn call_data_copy(&self) -> &[u8] {
+        self.unimplemented("call_data_copy");
+        &[]
+    }
+
+    fn get_caller(&self) -> &[u8; 20] {
+        self.unimplemented("get_caller");
+        &[0u8; 20]
+    }
+
+    fn get_call_value(&self) -> &[u8; 32] {
+        self.unimplemented("get_call_value");
+        &[0u8; 32]
+    }
+
+    fn get_chain_id(&self) -> &[u8; 32] {
+        self.unimplemented("get_chain_id");
+        &[0u8; 32]
+    }
+
+    fn get_gas_left(&self, gas_left: i64) -> i64 {
+        self.unimplemented("get_gas_left");
+        gas_left
+    }
+
+    fn get_block_gas_limit(&self) -> i64 {
+        self.unimplemented("get_block_gas_limit");
+        0
+    }
+
+    fn get_block_number(&self) -> i64 {
+        self.unimplemented("get_block_number");
+        0
+    }
+
+    fn get_tx_origin(&self) -> &[u8; 20] {
+        self.unimplemented("get_tx_origin");
+        &[0u8; 20]
+    }
+
+    fn get_block_timestamp(&self) -> i64 {
+        self.unimplemented("get_block_timestamp");
+        0
+    }
+
+    fn storage_store(&self, _key: &[u8; 32], _value: &[u8; 32]) {
+        self.unimplemented("storage_store");
+    }
+
+    fn storage_load(&self, _key: &[u8; 32]) -> [u8; 32] {
+        self.unimplemented("storage_load");
+        [0u8; 32]
+    }
+
+    fn transient_store(&self, _key: &[u8; 32], _value: &[u8; 32]) {
+        self.unimplemented("transient_store");
+    }
+
+    fn transient_load(&self, _key: &[u8; 32]) -> [u8; 32] {
+        self.unimplemented("transient_load");
+        [0u8; 32]
+    }
+
+    fn emit_log_event(&self, _event: LogEvent) {
+        self.unimplemented("emit_log_event");
+    }
+
+    fn code_copy(&self) -> &[u8] {
+        self.unimplemented("code_copy");
+        &[]
+    }
+
+    fn get_base_fee(&self) -> &[u8; 32] {
+        self.unimplemented("get_base_fee");
+        &[0u8; 32]
+    }
+
+    fn get_blob_base_fee(&self) -> &[u8; 32] {
+        self.unimplemented("get_blob_base_fee");
+        &[0u8; 32]
+    }
+
+    fn get_block_coinbase(&self) -> &[u8; 20] {
+        self.unimplemented("get_block_coinbase");
+        &[0u8; 20]
+    }
+
+    fn get_tx_gas_price(&self) -> &[u8; 32] {
+        self.unimplemented("get_tx_gas_price");
+        &[0u8; 32]
+    }
+
+    fn get_external_balance(&self, _address: &[u8; 20]) -> [u8; 32] {
+        self.unimplemented("get_external_balance");
+        [0u8; 32]
+    }
+
+    fn get_external_code_size(&self, _address: &[u8; 20]) -> Option<i32> {
+        self.unimplemented("get_external_code_size");
+        None
+    }
+
+    fn get_external_code_hash(&self, _address: &[u8; 20]) -> Option<[u8; 32]> {
+        self.unimplemented("get_external_code_hash");
+        None
+    }
+
+    fn external_code_copy(&self, _address: &[u8; 20]) -> Option<Vec<u8>> {
+        self.unimplemented("external_code_copy");
+        None
+    }
+
+    fn get_block_prev_randao(&self) -> &[u8; 32] {
+        self.unimplemented("get_block_prev_randao");
+        &[0u8; 32]
+    }
+
+    fn self_destruct(&self, _recipient: &[u8; 20]) -> [u8; 32] {
+        self.unimplemented("self_destruct");
+        [0u8; 32]
+    }
+
+    fn call_contract(
+        &self,
+        _target: &[u8; 20],
+        _caller: &[u8; 20],
+        _value: &[u8; 32],
+        _data: &[u8],
+        _gas: i64,
+    ) -> ContractCallResult {
+        self.unimplemented("call_contract");
+        ContractCallResult::simple_failure()
+    }
+
+    fn call_code(
+        &self,
+        _target: &[u8; 20],
+        _caller: &[u8; 20],
+        _value: &[u8; 32],
+        _data: &[u8],
+        _gas: i64,
+    ) -> ContractCallResult {
+        self.unimplemented("call_code");
+        ContractCallResult::simple_failure()
+    }
+
+    fn call_delegate(
+        &self,
+        _target: &[u8; 20],
+        _caller: &[u8; 20],
+        _data: &[u8],
+        _gas: i64,
+    ) -> ContractCallResult {
+        self.unimplemented("call_delegate");
+        ContractCallResult::simple_failure()
+    }
+
+    fn call_static(
+        &self,
+        _target: &[u8; 20],
+        _caller: &[u8; 20],
+        _data: &[u8],
+        _gas: i64,
+    ) -> ContractCallResult {
+        self.unimplemented("call_static");
+        ContractCallResult::simple_failure()
+    }
+
+    fn create_contract(
+        &self,
+        _creator: &[u8; 20],
+        _value: &[u8; 32],
+        _code: &[u8],
+        _data: &[u8],
+        _gas: i64,
+        _salt: Option<[u8; 32]>,
+        _is_create2: bool,
+    ) -> ContractCreateResult {
+        self.unimplemented("create_contract");
+        ContractCreateResult::simple_failure()
+    }
+
+    fn finish(&self, _data: Vec<u8>) {
+        self.unimplemented("finish");
+    }
+
+    fn return_data_copy(&self) -> Vec<u8> {
+        self.unimplemented("return_data_copy");
+        vec![]
+    }
+
+    fn revert(&self, _revert_data: Vec<u8>) {
+        self.unimplemented("revert");
+    }
+
+    fn invalid(&self) {
+        self.unimplemented("invalid");
+    }
+}
@@ -33,6 +33,7 @@
 //! // }
 //! ```
 
+use crate::core::instance::ZenInstance;
 use std::fmt;
 
 /// Result type for host function operations
@@ -92,6 +93,8 @@ pub enum HostFunctionError {
         function: String,
         operation: String,
     },
+    /// State modification attempted inside a static call (STATICCALL)
+    StaticViolation { message: String, function: String },
 }
 
 impl fmt::Display for HostFunctionError {
@@ -193,6 +196,9 @@ impl fmt::Display for HostFunctionError {
                     function, operation, message
                 )
             }
+            HostFunctionError::StaticViolation { message, function } => {
+                write!(f, "[{}] Static call violation: {}", function, message)
+            }
         }
     }
 }
@@ -328,6 +334,14 @@ pub fn arithmetic_error(message: &str, function: &str, operation: &str) -> HostF
     }
 }
 
+/// Helper function to create a static call violation error
+pub fn static_violation_error(function: &str) -> HostFunctionError {
+    HostFunctionError::StaticViolation {
+        message: "state modification is not allowed in a static call".to_string(),
+        function: function.to_string(),
+    }
+}
+
 impl HostFunctionError {
     /// Get the function name where this error occurred
     pub fn function(&self) -> &str {
@@ -342,6 +356,7 @@ impl HostFunctionError {
             HostFunctionError::CallError { function, .. } => function,
             HostFunctionError::CryptoError { function, .. } => function,
             HostFunctionError::ArithmeticError { function, .. } => function,
+            HostFunctionError::StaticViolation { function, .. } => function,
         }
     }
 
@@ -358,6 +373,7 @@ impl HostFunctionError {
             HostFunctionError::CallError { message, .. } => message,
             HostFunctionError::CryptoError { message, .. } => message,
             HostFunctionError::ArithmeticError { message, .. } => message,
+            HostFunctionError::StaticViolation { message, .. } => message,
         }
     }
 
@@ -374,8 +390,62 @@ impl HostFunctionError {
             HostFunctionError::CallError { .. } => "call",
             HostFunctionError::CryptoError { .. } => "crypto",
             HostFunctionError::ArithmeticError { .. } => "arithmetic",
+            HostFunctionError::StaticViolation { .. } => "static",
         }
     }
+
+    /// Get the EVMC-style status code of this error
+    ///
+    /// Each category maps to its own code so the embedder can tell failures apart.
+    /// These are not VM error codes; use `raise` to abort execution.
+    ///
+    /// | Category   | Code |
+    /// |------------|------|
+    /// | memory     | 2    |
+    /// | gas        | 3    |
+    /// | parameter  | 4    |
+    /// | storage    | 5    |
+    /// | context    | 6    |
+    /// | call       | 7    |
+    /// | crypto     | 8    |
+    /// | execution  | 9    |
+    /// | arithmetic | 10   |
+    /// | static     | 11   |
+    pub fn to_status_code(&self) -> i32 {
+        match self {
+            HostFunctionError::OutOfBounds { .. } => 2,
+            HostFunctionError::MemoryAccessError { .. } => 2,
+            HostFunctionError::GasError { .. } => 3,
+            HostFunctionError::InvalidParameter { .. } => 4,
+            HostFunctionError::StorageError { .. } => 5,
+            HostFunctionError::ContextNotFound { .. } => 6,
+            HostFunctionError::CallError { .. } => 7,
+            HostFunctionError::CryptoError { .. } => 8,
+            HostFunctionError::ExecutionError { .. } => 9,
+            HostFunctionError::ArithmeticError { .. } => 10,
+            HostFunctionError::StaticViolation { .. } => 11,
+        }
+    }
+
+    /// Raise the VM exception for this error on `instance`
+    ///
+    /// Gas errors abort execution as out of gas and memory errors as out-of-bounds
+    /// memory accesses; every other category aborts as a host environment error.
+    pub fn raise<T>(&self, instance: &ZenInstance<T>) {
+        match self {
+            HostFunctionError::GasError { .. } => instance.raise_out_of_gas_error(),
+            HostFunctionError::OutOfBounds { .. } | HostFunctionError::MemoryAccessError { .. } => {
+                instance.raise_out_of_bounds_memory_error()
+            }
+            _ => instance.raise_abort_error(),
+        }
+    }
+}
+
+impl From<HostFunctionError> for i32 {
+    fn from(error: HostFunctionError) -> Self {
+        error.to_status_code()
+    }
 }
 
 #[cfg(test)]
@@ -399,6 +469,36 @@ mod tests {
         assert_eq!(error.category(), "storage");
     }
 
+    #[test]
+    fn test_error_status_codes() {
+        let cases = [
+            (out_of_bounds_error(0, 10, "test"), 2),
+            (memory_access_error("bad access", "test"), 2),
+            (gas_error("insufficient gas", "test", None, None), 3),
+            (invalid_parameter_error("param", "value", "test"), 4),
+            (storage_error("key not found", "test", None), 5),
+            (context_not_found_error("test"), 6),
+            (call_error("call failed", "test", None), 7),
+            (crypto_error("hash failed", "test", "SHA256"), 8),
+            (execution_error("execution failed", "test"), 9),
+            (arithmetic_error("division by zero", "test", "div"), 10),
+            (static_violation_error("test"), 11),
+        ];
+
+        for (error, code) in cases {
+            assert_eq!(error.to_status_code(), code, "{} error", error.category());
+            assert_eq!(i32::from(error), code);
+        }
+    }
+
+    #[test]
+    fn test_static_violation_error() {
+        let error = static_violation_error("storage_store");
+        assert_eq!(error.function(), "storage_store");
+        assert_eq!(error.category(), "static");
+        assert!(format!("{}", error).contains("static call"));
+    }
+
     #[test]
     fn test_error_display() {
         let error = crypto_error("hash computation failed", "sha256", "SHA256");
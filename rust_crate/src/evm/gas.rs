@@ -0,0 +1,556 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! EVM gas model for host functions
+//!
+//! WASM execution and memory growth are metered by the runtime. This module
+//! covers the EVM-specific costs that host functions charge on top of that,
+//! such as the quadratic memory-expansion cost of copy operations, the
+//! cold/warm account-access cost of calls, the net-metered cost of SSTORE
+//! and the gas forwarded to and returned from nested calls, along with the
+//! transaction-level intrinsic gas and refund rules and the cost of the
+//! precompiles.
+
+use crate::core::instance::ZenInstance;
+use crate::evm::error::{gas_error, HostFunctionResult};
+use crate::evm::traits::{EvmHost, Hardfork};
+use num_bigint::BigUint;
+use std::collections::HashMap;
+
+/// Base cost of every transaction (G_transaction)
+pub const TX_BASE_GAS: u64 = 21_000;
+
+/// Cost per zero byte of transaction call data (G_txdatazero)
+pub const TX_DATA_ZERO_GAS: u64 = 4;
+
+/// Cost per non-zero byte of transaction call data (G_txdatanonzero)
+pub const TX_DATA_NONZERO_GAS: u64 = 16;
+
+/// Refund for clearing a storage slot to zero (EIP-3529)
+pub const SSTORE_CLEARS_REFUND: u64 = 4_800;
+
+/// SSTORE fails when no more than this much gas is left (EIP-2200)
+pub const SSTORE_SENTRY_GAS: u64 = 2_300;
+
+/// Refunds are capped at gas used divided by this quotient (EIP-3529)
+pub const MAX_REFUND_QUOTIENT: u64 = 5;
+
+/// Cost of the first access to an account in a transaction (EIP-2929)
+pub const COLD_ACCOUNT_ACCESS_COST: u64 = 2_600;
+
+/// Cost of accessing an account that is already warm (EIP-2929)
+pub const WARM_ACCOUNT_ACCESS_COST: u64 = 100;
+
+/// Cost of the first access to a storage slot in a transaction (EIP-2929)
+pub const COLD_SLOAD_COST: u64 = 2_100;
+
+/// Linear memory cost per 32-byte word (G_memory)
+pub const MEMORY_WORD_COST: u64 = 3;
+
+/// Divisor of the quadratic memory cost term
+pub const MEMORY_QUADRATIC_DIVISOR: u64 = 512;
+
+/// Cost of an ecrecover precompile call
+pub const ECRECOVER_GAS: u64 = 3_000;
+
+/// Minimum cost of a modexp precompile call (EIP-2565)
+pub const MODEXP_MIN_GAS: u64 = 200;
+
+/// Divisor of the modexp complexity (EIP-2565)
+pub const MODEXP_GAS_DIVISOR: u64 = 3;
+
+/// Storage gas schedule of net-metered SSTORE (EIP-2200)
+/// Defaults to the values of the default hardfork (see `GasCosts::for_hardfork`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasCosts {
+    /// Cost of an SSTORE that leaves the slot unchanged or touches a dirty slot (SLOAD_GAS)
+    pub sload: u64,
+    /// Extra cost of the first SSTORE to a slot in a transaction (COLD_SLOAD_COST)
+    pub cold_sload: u64,
+    /// Cost of setting a clean zero slot to non-zero (SSTORE_SET_GAS)
+    pub sstore_set: u64,
+    /// Cost of changing a clean non-zero slot (SSTORE_RESET_GAS)
+    pub sstore_reset: u64,
+    /// Refund for clearing a non-zero slot (SSTORE_CLEARS_SCHEDULE)
+    pub sstore_clears_refund: u64,
+}
+
+impl GasCosts {
+    /// Storage gas schedule in effect at `hardfork`
+    ///
+    /// Istanbul and earlier use the EIP-2200 values, Berlin adds cold slot
+    /// access and warm reads (EIP-2929), and London lowers the clears refund
+    /// (EIP-3529).
+    pub fn for_hardfork(hardfork: Hardfork) -> Self {
+        let istanbul = Self {
+            sload: 800,
+            cold_sload: 0,
+            sstore_set: 20_000,
+            sstore_reset: 5_000,
+            sstore_clears_refund: 15_000,
+        };
+        if hardfork < Hardfork::Berlin {
+            return istanbul;
+        }
+
+        let berlin = Self {
+            sload: WARM_ACCOUNT_ACCESS_COST,
+            cold_sload: COLD_SLOAD_COST,
+            sstore_reset: 5_000 - COLD_SLOAD_COST,
+            ..istanbul
+        };
+        if hardfork < Hardfork::London {
+            return berlin;
+        }
+
+        Self {
+            sstore_clears_refund: SSTORE_CLEARS_REFUND,
+            ..berlin
+        }
+    }
+}
+
+impl Default for GasCosts {
+    fn default() -> Self {
+        Self::for_hardfork(Hardfork::default())
+    }
+}
+
+/// Gas charged for calling host functions, by host function name
+/// (e.g. `storage_store`, `keccak256`, `call_contract`)
+/// Functions without a cost are free
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostGasSchedule {
+    costs: HashMap<String, u64>,
+}
+
+impl HostGasSchedule {
+    /// Create a schedule in which every host function is free
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Charge `cost` for every call to the host function `name`
+    pub fn with_cost(mut self, name: &str, cost: u64) -> Self {
+        self.costs.insert(name.to_string(), cost);
+        self
+    }
+
+    /// Gas charged for a call to the host function `name`
+    pub fn cost(&self, name: &str) -> u64 {
+        self.costs.get(name).copied().unwrap_or(0)
+    }
+}
+
+/// Cost and refund change of an SSTORE under net gas metering (EIP-2200)
+///
+/// `original` is the slot value at the start of the transaction, `current`
+/// its value before this SSTORE and `new` the value being stored. Returns
+/// the gas cost and the change to the refund counter, which is negative when
+/// an earlier refund is taken back.
+pub fn sstore_net_gas(
+    costs: &GasCosts,
+    original: &[u8; 32],
+    current: &[u8; 32],
+    new: &[u8; 32],
+) -> (u64, i64) {
+    let zero = [0u8; 32];
+    let clears_refund = costs.sstore_clears_refund as i64;
+
+    // No-op
+    if current == new {
+        return (costs.sload, 0);
+    }
+
+    // Clean slot: first change in this transaction
+    if original == current {
+        if *original == zero {
+            return (costs.sstore_set, 0);
+        }
+        let refund = if *new == zero { clears_refund } else { 0 };
+        return (costs.sstore_reset, refund);
+    }
+
+    // Dirty slot: already changed in this transaction
+    let mut refund = 0;
+    if *original != zero {
+        if *current == zero {
+            refund -= clears_refund;
+        }
+        if *new == zero {
+            refund += clears_refund;
+        }
+    }
+    if original == new {
+        let first_change_cost = if *original == zero {
+            costs.sstore_set
+        } else {
+            costs.sstore_reset
+        };
+        refund += first_change_cost.saturating_sub(costs.sload) as i64;
+    }
+    (costs.sload, refund)
+}
+
+/// Total EVM memory cost for a memory of `size` bytes
+/// cost = G_memory * words + words^2 / 512, with words = ceil(size / 32)
+pub fn memory_cost(size: u64) -> u64 {
+    let words = size.div_ceil(32);
+    MEMORY_WORD_COST
+        .saturating_mul(words)
+        .saturating_add(words.saturating_mul(words) / MEMORY_QUADRATIC_DIVISOR)
+}
+
+/// Cost of expanding memory from `current_size` to `new_size` bytes
+/// Returns 0 when the new size does not exceed the current one
+pub fn memory_expansion_cost(current_size: u64, new_size: u64) -> u64 {
+    if new_size <= current_size {
+        return 0;
+    }
+    memory_cost(new_size) - memory_cost(current_size)
+}
+
+/// Intrinsic gas of a transaction carrying `call_data`
+/// Charged before execution: the base cost plus the call data cost
+pub fn intrinsic_gas(call_data: &[u8]) -> u64 {
+    call_data.iter().fold(TX_BASE_GAS, |gas, byte| {
+        gas + if *byte == 0 {
+            TX_DATA_ZERO_GAS
+        } else {
+            TX_DATA_NONZERO_GAS
+        }
+    })
+}
+
+/// Refund actually granted for a transaction that used `gas_used` gas
+pub fn capped_refund(gas_used: u64, refund: u64) -> u64 {
+    refund.min(gas_used / MAX_REFUND_QUOTIENT)
+}
+
+/// Cost of a modexp precompile call (EIP-2565)
+/// `exp_head` holds the first (at most 32) bytes of the exponent, big-endian
+///
+/// cost = max(200, ceil(max(base_len, mod_len) / 8)^2 * iterations / 3), where
+/// iterations is derived from the exponent length and the bit length of its head
+pub fn modexp_gas(base_len: u64, exp_len: u64, mod_len: u64, exp_head: &[u8]) -> u64 {
+    let words = base_len.max(mod_len).div_ceil(8) as u128;
+    let complexity = words * words;
+
+    let head_bits = BigUint::from_bytes_be(exp_head).bits() as u128;
+    let iterations = if exp_len <= 32 {
+        head_bits.saturating_sub(1)
+    } else {
+        8 * (exp_len as u128 - 32) + head_bits.saturating_sub(1)
+    }
+    .max(1);
+
+    let cost = complexity.saturating_mul(iterations) / MODEXP_GAS_DIVISOR as u128;
+    u64::try_from(cost).unwrap_or(u64::MAX).max(MODEXP_MIN_GAS)
+}
+
+/// Maximum gas that can be forwarded to a nested call (EIP-150)
+/// All but one 64th of the available gas
+pub fn max_call_gas(available: u64) -> u64 {
+    available - available / 64
+}
+
+/// Gas to forward to a nested call requesting `requested` gas
+/// The request is capped by the 63/64 rule; a negative request forwards the maximum
+pub fn call_gas_limit<T>(instance: &ZenInstance<T>, requested: i64) -> i64
+where
+    T: EvmHost,
+{
+    let max_gas = max_call_gas(instance.get_gas_left()) as i64;
+    if requested < 0 {
+        max_gas
+    } else {
+        requested.min(max_gas)
+    }
+}
+
+/// Charge the gas consumed by a nested call to the calling frame
+/// Gas forwarded but not used by the callee (including after a revert) stays with the caller
+pub fn charge_call_gas<T>(instance: &ZenInstance<T>, gas_used: i64)
+where
+    T: EvmHost,
+{
+    let gas_used = gas_used.max(0) as u64;
+    instance.set_gas_left(instance.get_gas_left().saturating_sub(gas_used));
+}
+
+/// Deduct `cost` from the remaining gas, raising out-of-gas when it is insufficient
+fn deduct_gas<T>(
+    instance: &ZenInstance<T>,
+    cost: u64,
+    message: &str,
+    function: &str,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let gas_left = instance.get_gas_left();
+    if gas_left < cost {
+        instance.set_gas_left(0);
+        instance.raise_out_of_gas_error();
+        return Err(gas_error(
+            message,
+            function,
+            Some(cost as i64),
+            Some(gas_left as i64),
+        ));
+    }
+
+    instance.set_gas_left(gas_left - cost);
+    Ok(())
+}
+
+/// Charge account-access gas for a call to `address`
+///
+/// Does nothing unless the host enables account-access charging. The account
+/// is marked warm; the first access in a transaction costs
+/// `COLD_ACCOUNT_ACCESS_COST`, later ones `WARM_ACCOUNT_ACCESS_COST`.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - address: Accessed account address
+/// - function: Name of the calling host function (for error reporting)
+pub fn charge_account_access<T>(
+    instance: &ZenInstance<T>,
+    address: &[u8; 20],
+    function: &str,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    if !evmhost.charges_account_access() {
+        return Ok(());
+    }
+
+    let cost = if evmhost.mark_account_warm(address) {
+        COLD_ACCOUNT_ACCESS_COST
+    } else {
+        WARM_ACCOUNT_ACCESS_COST
+    };
+    deduct_gas(
+        instance,
+        cost,
+        "insufficient gas for account access",
+        function,
+    )
+}
+
+/// Charge net-metered SSTORE gas (EIP-2200) for storing `new` at `key`
+///
+/// Fails when no more than `SSTORE_SENTRY_GAS` is left. The refund counter
+/// is adjusted by the refund change of the store.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - costs: Storage gas schedule
+/// - key: Storage key being written
+/// - new: Value being stored
+/// - function: Name of the calling host function (for error reporting)
+pub fn charge_sstore<T>(
+    instance: &ZenInstance<T>,
+    costs: &GasCosts,
+    key: &[u8; 32],
+    new: &[u8; 32],
+    function: &str,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+
+    let gas_left = instance.get_gas_left();
+    if gas_left <= SSTORE_SENTRY_GAS {
+        instance.set_gas_left(0);
+        instance.raise_out_of_gas_error();
+        return Err(gas_error(
+            "SSTORE requires more than the call stipend",
+            function,
+            Some(SSTORE_SENTRY_GAS as i64 + 1),
+            Some(gas_left as i64),
+        ));
+    }
+
+    let original = evmhost.original_storage_value(key);
+    let current = evmhost.storage_load(key);
+    let (mut cost, refund) = sstore_net_gas(costs, &original, &current, new);
+    if !evmhost.access_storage(evmhost.get_address(), key) {
+        cost += costs.cold_sload;
+    }
+    deduct_gas(instance, cost, "insufficient gas for SSTORE", function)?;
+
+    if refund > 0 {
+        evmhost.add_gas_refund(refund as u64);
+    } else if refund < 0 {
+        evmhost.remove_gas_refund(refund.unsigned_abs());
+    }
+
+    Ok(())
+}
+
+/// Charge memory-expansion gas for a copy touching `[offset, offset + length)`
+///
+/// Does nothing unless the host enables memory-expansion charging or when
+/// `length` is 0. On success, the host's touched memory size is updated.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - offset: Memory offset where the copy starts
+/// - length: Number of bytes written by the copy
+/// - function: Name of the calling host function (for error reporting)
+pub fn charge_memory_expansion<T>(
+    instance: &ZenInstance<T>,
+    offset: u32,
+    length: u32,
+    function: &str,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    if !evmhost.charges_memory_expansion() || length == 0 {
+        return Ok(());
+    }
+
+    let current_size = evmhost.get_memory_size();
+    let new_size = offset as u64 + length as u64;
+    let cost = memory_expansion_cost(current_size, new_size);
+    if cost == 0 {
+        return Ok(());
+    }
+
+    deduct_gas(
+        instance,
+        cost,
+        "insufficient gas for memory expansion",
+        function,
+    )?;
+    evmhost.set_memory_size(new_size);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_cost() {
+        assert_eq!(memory_cost(0), 0);
+        assert_eq!(memory_cost(1), 3);
+        assert_eq!(memory_cost(32), 3);
+        assert_eq!(memory_cost(33), 6);
+        // 1024 words: 3 * 1024 + 1024^2 / 512
+        assert_eq!(memory_cost(32 * 1024), 3072 + 2048);
+    }
+
+    #[test]
+    fn test_sstore_net_gas() {
+        let costs = GasCosts::for_hardfork(Hardfork::Istanbul);
+        let zero = [0u8; 32];
+        let one = word(1);
+        let two = word(2);
+
+        // No-op and clean-slot changes
+        assert_eq!(sstore_net_gas(&costs, &zero, &zero, &zero), (800, 0));
+        assert_eq!(sstore_net_gas(&costs, &zero, &zero, &one), (20_000, 0));
+        assert_eq!(sstore_net_gas(&costs, &one, &one, &two), (5_000, 0));
+        assert_eq!(sstore_net_gas(&costs, &one, &one, &zero), (5_000, 15_000));
+
+        // Dirty slots: restoring the original refunds the first change
+        assert_eq!(sstore_net_gas(&costs, &zero, &one, &zero), (800, 19_200));
+        assert_eq!(sstore_net_gas(&costs, &one, &two, &one), (800, 4_200));
+        // Un-clearing takes the clears refund back
+        assert_eq!(sstore_net_gas(&costs, &one, &zero, &two), (800, -15_000));
+        assert_eq!(
+            sstore_net_gas(&costs, &one, &zero, &one),
+            (800, -15_000 + 4_200)
+        );
+    }
+
+    #[test]
+    fn test_gas_costs_for_hardfork() {
+        let istanbul = GasCosts::for_hardfork(Hardfork::Istanbul);
+        assert_eq!(istanbul.sload, 800);
+        assert_eq!(istanbul.cold_sload, 0);
+        assert_eq!(istanbul.sstore_clears_refund, 15_000);
+
+        let berlin = GasCosts::for_hardfork(Hardfork::Berlin);
+        assert_eq!(berlin.sload, 100);
+        assert_eq!(berlin.cold_sload, 2_100);
+        assert_eq!(berlin.sstore_reset, 2_900);
+        assert_eq!(berlin.sstore_clears_refund, 15_000);
+
+        let london = GasCosts::for_hardfork(Hardfork::London);
+        assert_eq!(london.sstore_clears_refund, 4_800);
+        assert_eq!(london.sstore_set, 20_000);
+        assert_eq!(
+            GasCosts::default(),
+            GasCosts::for_hardfork(Hardfork::Cancun)
+        );
+        assert_eq!(GasCosts::default(), london);
+
+        // Berlin: restoring a dirty slot refunds the reset cost minus the warm read
+        assert_eq!(
+            sstore_net_gas(&berlin, &word(1), &word(2), &word(1)),
+            (100, 2_800)
+        );
+    }
+
+    fn word(value: u8) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[31] = value;
+        word
+    }
+
+    #[test]
+    fn test_intrinsic_gas() {
+        assert_eq!(intrinsic_gas(&[]), 21_000);
+        assert_eq!(intrinsic_gas(&[0, 0, 1, 0xff]), 21_000 + 2 * 4 + 2 * 16);
+    }
+
+    #[test]
+    fn test_capped_refund() {
+        assert_eq!(capped_refund(50_000, 4_800), 4_800);
+        assert_eq!(capped_refund(21_000, 10_000), 4_200);
+        assert_eq!(capped_refund(0, 4_800), 0);
+    }
+
+    #[test]
+    fn test_max_call_gas() {
+        assert_eq!(max_call_gas(0), 0);
+        assert_eq!(max_call_gas(63), 63);
+        assert_eq!(max_call_gas(64), 63);
+        assert_eq!(max_call_gas(1_000_000), 984_375);
+    }
+
+    #[test]
+    fn test_modexp_gas() {
+        // EIP-198 example: 3^(p-2) mod p for the secp256k1 field prime
+        let exponent =
+            hex::decode("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2d")
+                .unwrap();
+        assert_eq!(modexp_gas(1, 32, 32, &exponent), 16 * 255 / 3);
+
+        // Small operands are charged the minimum
+        assert_eq!(modexp_gas(1, 1, 1, &[3]), MODEXP_MIN_GAS);
+
+        // Exponents longer than 32 bytes add 8 iterations per extra byte
+        assert_eq!(modexp_gas(64, 64, 64, &[0u8; 32]), 64 * (8 * 32) / 3);
+    }
+
+    #[test]
+    fn test_memory_expansion_cost() {
+        assert_eq!(memory_expansion_cost(64, 32), 0);
+        assert_eq!(memory_expansion_cost(64, 64), 0);
+        assert_eq!(memory_expansion_cost(0, 64), 6);
+        assert_eq!(memory_expansion_cost(32, 64), 3);
+
+        // Expanding to a high offset costs more than to a low one
+        assert!(memory_expansion_cost(0, 32 * 1024) > memory_expansion_cost(0, 64));
+    }
+}
@@ -6,29 +6,27 @@
 use crate::core::instance::ZenInstance;
 use crate::evm::error::HostFunctionResult;
 use crate::evm::traits::EvmHost;
-use crate::evm::utils::{validate_address_param, validate_bytes32_param, MemoryAccessor};
+use crate::evm::utils::{
+    validate_address_param, validate_bytes32_param, write_address_result, MemoryAccessor,
+};
 
 /// Get the current contract address
 /// Writes the 20-byte contract address to the specified memory location
 ///
 /// Parameters:
 /// - instance: WASM instance pointer
-/// - result_offset: Memory offset where the 20-byte address should be written
+/// - result_offset: Memory offset where the address (20 bytes or a padded word) should be written
 pub fn get_address<T>(instance: &ZenInstance<T>, result_offset: i32) -> HostFunctionResult<()>
 where
     T: EvmHost,
 {
     let evmhost = &instance.extra_ctx;
-    let memory = MemoryAccessor::new(instance);
-
-    // Validate the result offset
-    let offset = validate_address_param(instance, result_offset)?;
 
     // Get the contract address
     let address = evmhost.get_address();
 
     // Write the address to memory
-    memory.write_address(offset, address)?;
+    write_address_result(instance, result_offset, address)?;
     Ok(())
 }
 
@@ -37,22 +35,18 @@ where
 ///
 /// Parameters:
 /// - instance: WASM instance pointer
-/// - result_offset: Memory offset where the 20-byte address should be written
+/// - result_offset: Memory offset where the address (20 bytes or a padded word) should be written
 pub fn get_caller<T>(instance: &ZenInstance<T>, result_offset: i32) -> HostFunctionResult<()>
 where
     T: EvmHost,
 {
     let evmhost = &instance.extra_ctx;
-    let memory = MemoryAccessor::new(instance);
-
-    // Validate the result offset
-    let offset = validate_address_param(instance, result_offset)?;
 
     // Get the caller address
     let caller = evmhost.get_caller();
 
     // Write the address to memory
-    memory.write_address(offset, caller)?;
+    write_address_result(instance, result_offset, caller)?;
 
     Ok(())
 }
@@ -62,22 +56,18 @@ where
 ///
 /// Parameters:
 /// - instance: WASM instance pointer
-/// - result_offset: Memory offset where the 20-byte address should be written
+/// - result_offset: Memory offset where the address (20 bytes or a padded word) should be written
 pub fn get_tx_origin<T>(instance: &ZenInstance<T>, result_offset: i32) -> HostFunctionResult<()>
 where
     T: EvmHost,
 {
     let evmhost = &instance.extra_ctx;
-    let memory = MemoryAccessor::new(instance);
-
-    // Validate the result offset
-    let offset = validate_address_param(instance, result_offset)?;
 
     // Get the transaction origin address
     let origin = evmhost.get_tx_origin();
 
     // Write the address to memory
-    memory.write_address(offset, origin)?;
+    write_address_result(instance, result_offset, origin)?;
 
     Ok(())
 }
@@ -132,6 +122,38 @@ where
     Ok(())
 }
 
+/// Verify the chain ID against an expected value (EIP-155 replay protection)
+/// Writes a 32-byte boolean word (1 if the chain ID matches, 0 otherwise)
+/// to the specified memory location
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - expected: Expected chain ID
+/// - result_offset: Memory offset where the 32-byte boolean should be written
+pub fn verify_chain_id<T>(
+    instance: &ZenInstance<T>,
+    expected: i64,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate the result offset
+    let offset = validate_bytes32_param(instance, result_offset)?;
+
+    // Compare the chain ID; the expected value is an unsigned 64-bit id
+    let mut result = [0u8; 32];
+    result[31] = evmhost.verify_chain_id(expected as u64) as u8;
+
+    // Write the boolean word to memory
+    memory.write_bytes32(offset, &result)?;
+
+    Ok(())
+}
+
 /// Get the balance of an external account
 /// Writes the 32-byte balance to the specified memory location
 ///
@@ -167,3 +189,54 @@ where
     memory.write_bytes32(result_offset_u32, &balance)?;
     Ok(())
 }
+
+/// Get the balance of the current contract (SELFBALANCE opcode)
+/// Writes the 32-byte balance to the specified memory location
+///
+/// Unlike `get_external_balance`, no address has to be passed in from WASM memory.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - result_offset: Memory offset where the 32-byte balance should be written
+pub fn get_self_balance<T>(instance: &ZenInstance<T>, result_offset: i32) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate the result offset
+    let offset = validate_bytes32_param(instance, result_offset)?;
+
+    // Get the current contract's balance
+    let balance = evmhost.get_self_balance();
+
+    // Write the balance to memory
+    memory.write_bytes32(offset, &balance)?;
+
+    Ok(())
+}
+
+/// Access an account and report whether it was warm (EIP-2929/2930)
+/// The account is warm for the rest of the transaction afterwards
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - addr_offset: Memory offset of the 20-byte address to access
+///
+/// Returns:
+/// - 1 if the account was already warm
+/// - 0 if this was the first (cold) access
+pub fn access_account<T>(instance: &ZenInstance<T>, addr_offset: i32) -> HostFunctionResult<i32>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate and read the address to access
+    let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
+    let address = memory.read_address(addr_offset_u32)?;
+
+    Ok(evmhost.access_account(&address) as i32)
+}
@@ -15,7 +15,9 @@
 //! - [`get_block_gas_limit`] - Maximum gas allowed in this block (GASLIMIT)
 //! - [`get_block_coinbase`] - Address of the block miner/validator (COINBASE)
 //! - [`get_block_prev_randao`] - Previous block's RANDAO value (PREVRANDAO)
+//! - [`get_difficulty`] - Difficulty before the Merge, RANDAO value after (DIFFICULTY)
 //! - [`get_block_hash`] - Hash of a specific block by number (BLOCKHASH)
+//! - [`get_block_info`] - Number, timestamp, gas limit, base fee and coinbase in one call
 //!
 //! # Block Properties
 //!
@@ -65,8 +67,24 @@
 
 use crate::core::instance::ZenInstance;
 use crate::evm::error::HostFunctionResult;
-use crate::evm::traits::EvmHost;
-use crate::evm::utils::{validate_address_param, validate_bytes32_param, MemoryAccessor};
+use crate::evm::traits::{EvmHost, Hardfork};
+use crate::evm::utils::{
+    validate_bytes32_param, validate_offset_for_type, write_address_result, MemoryAccessor,
+};
+
+/// Number of most recent blocks whose hash is available to BLOCKHASH
+pub const BLOCK_HASH_WINDOW: i64 = 256;
+
+/// Whether BLOCKHASH can return the hash of `block_number` during `current_block`
+/// Only the most recent BLOCK_HASH_WINDOW blocks before the current one have a hash
+pub fn is_block_hash_available(block_number: i64, current_block: i64) -> bool {
+    block_number >= 0
+        && block_number < current_block
+        && block_number >= current_block.saturating_sub(BLOCK_HASH_WINDOW)
+}
+
+/// Size in bytes of the packed block info written by [`get_block_info`]
+pub const BLOCK_INFO_SIZE: u32 = 76;
 
 /// Get the current block number
 /// Returns the block number as i64
@@ -109,7 +127,7 @@ where
 ///
 /// Parameters:
 /// - instance: WASM instance pointer
-/// - result_offset: Memory offset where the 20-byte address should be written
+/// - result_offset: Memory offset where the address (20 bytes or a padded word) should be written
 pub fn get_block_coinbase<T>(
     instance: &ZenInstance<T>,
     result_offset: i32,
@@ -118,16 +136,12 @@ where
     T: EvmHost,
 {
     let evmhost = &instance.extra_ctx;
-    let memory = MemoryAccessor::new(instance);
-
-    // Validate the result offset
-    let offset = validate_address_param(instance, result_offset)?;
 
     // Get the coinbase address from block info
     let coinbase = evmhost.get_block_coinbase();
 
     // Write the address to memory
-    memory.write_address(offset, coinbase)?;
+    write_address_result(instance, result_offset, coinbase)?;
 
     Ok(())
 }
@@ -160,6 +174,38 @@ where
     Ok(())
 }
 
+/// Get the current block's difficulty (DIFFICULTY opcode)
+/// Writes the 32-byte value to the specified memory location
+///
+/// DIFFICULTY and PREVRANDAO share an opcode: from the Merge (Paris) onwards this
+/// returns the previous randao, before it the proof-of-work difficulty.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - result_offset: Memory offset where the 32-byte value should be written
+pub fn get_difficulty<T>(instance: &ZenInstance<T>, result_offset: i32) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate the result offset
+    let offset = validate_bytes32_param(instance, result_offset)?;
+
+    // Pick the value according to the active hardfork
+    let difficulty = if evmhost.get_hardfork() >= Hardfork::Paris {
+        evmhost.get_block_prev_randao()
+    } else {
+        evmhost.get_block_difficulty()
+    };
+
+    // Write the value to memory
+    memory.write_bytes32(offset, difficulty)?;
+
+    Ok(())
+}
+
 /// Get a block hash for a specific block number
 /// Writes the 32-byte block hash to the specified memory location
 ///
@@ -190,8 +236,7 @@ where
 
     let current_block = evmhost.get_block_number();
 
-    // Basic validation: block number should be non-negative and less than current block
-    if block_num < 0 || block_num >= current_block {
+    if !is_block_hash_available(block_num, current_block) {
         // Write zero hash for invalid block numbers
         let zero_hash = [0u8; 32];
         memory.write_bytes32(offset, &zero_hash)?;
@@ -216,3 +261,35 @@ where
         }
     }
 }
+
+/// Get the current block's number, timestamp, gas limit, base fee and coinbase in one call
+/// Writes a packed 76-byte structure to the specified memory location
+///
+/// Layout (integers are big-endian):
+/// - bytes 0..8: block number (u64)
+/// - bytes 8..16: block timestamp (u64)
+/// - bytes 16..24: block gas limit (u64)
+/// - bytes 24..56: base fee (32-byte word)
+/// - bytes 56..76: coinbase address (20 bytes)
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - result_offset: Memory offset where the 76-byte structure should be written
+pub fn get_block_info<T>(instance: &ZenInstance<T>, result_offset: i32) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate the whole structure up front so nothing is written on failure
+    let offset = validate_offset_for_type(instance, result_offset, BLOCK_INFO_SIZE, "block info")?;
+
+    memory.write_u64_be(offset, evmhost.get_block_number() as u64)?;
+    memory.write_u64_be(offset + 8, evmhost.get_block_timestamp() as u64)?;
+    memory.write_u64_be(offset + 16, evmhost.get_block_gas_limit() as u64)?;
+    memory.write_bytes32(offset + 24, evmhost.get_base_fee())?;
+    memory.write_address(offset + 56, evmhost.get_block_coinbase())?;
+
+    Ok(())
+}
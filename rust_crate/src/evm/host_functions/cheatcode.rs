@@ -0,0 +1,122 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cheatcode Host Functions
+//!
+//! Test-framework style cheatcodes that let a contract manipulate its own
+//! execution environment, e.g. warping the block timestamp in the middle of
+//! a call to exercise time-sensitive logic.
+//!
+//! These functions are only compiled with the `cheatcodes` feature and must
+//! never be registered in a production host. Hosts register them in a
+//! separate `cheats` host module so contracts import them explicitly.
+//!
+//! # Functions
+//!
+//! - [`warp`] - Set the block timestamp seen by subsequent reads
+//! - [`roll`] - Set the block number seen by subsequent reads
+//! - [`prank`] - Override `msg.sender` for the next contract call
+//! - [`deal`] - Set the balance of an account
+
+use crate::core::instance::ZenInstance;
+use crate::evm::error::{execution_error, HostFunctionResult};
+use crate::evm::traits::EvmHost;
+use crate::evm::utils::{validate_address_param, validate_bytes32_param, MemoryAccessor};
+
+/// Warp the block timestamp (cheatcode)
+/// Subsequent `getBlockTimestamp` reads return the new timestamp
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - new_timestamp: New block timestamp (must not be negative)
+pub fn warp<T>(instance: &ZenInstance<T>, new_timestamp: i64) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+
+    if new_timestamp < 0 {
+        return Err(execution_error("negative block timestamp", "warp"));
+    }
+
+    evmhost.set_block_timestamp(new_timestamp);
+
+    Ok(())
+}
+
+/// Roll the block number (cheatcode)
+/// Subsequent `getBlockNumber` reads return the new number, and the
+/// `getBlockHash` window moves along with it
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - new_block_number: New block number (must not be negative)
+pub fn roll<T>(instance: &ZenInstance<T>, new_block_number: i64) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+
+    if new_block_number < 0 {
+        return Err(execution_error("negative block number", "roll"));
+    }
+
+    evmhost.set_block_number(new_block_number);
+
+    Ok(())
+}
+
+/// Prank the caller of the next contract call (cheatcode)
+/// The next call made by the current frame sees the given address as
+/// `msg.sender`; later calls see the real caller again
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - addr_offset: Memory offset of the 20-byte address to use as caller
+pub fn prank<T>(instance: &ZenInstance<T>, addr_offset: i32) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate and read the pranked caller address
+    let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
+    let caller = memory.read_address(addr_offset_u32)?;
+
+    evmhost.set_prank(&caller);
+
+    Ok(())
+}
+
+/// Deal a balance to an account (cheatcode)
+/// The balance of the account is replaced, and subsequent balance reads
+/// return the new amount
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - addr_offset: Memory offset of the 20-byte account address
+/// - amount_offset: Memory offset of the 32-byte big-endian balance
+pub fn deal<T>(
+    instance: &ZenInstance<T>,
+    addr_offset: i32,
+    amount_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate and read the account address
+    let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
+    let address = memory.read_address(addr_offset_u32)?;
+
+    // Validate and read the new balance
+    let amount_offset_u32 = validate_bytes32_param(instance, amount_offset)?;
+    let amount = memory.read_bytes32(amount_offset_u32)?;
+
+    evmhost.set_balance(&address, &amount);
+
+    Ok(())
+}
@@ -4,7 +4,8 @@
 //! Code related host functions
 
 use crate::core::instance::ZenInstance;
-use crate::evm::error::HostFunctionResult;
+use crate::evm::error::{invalid_parameter_error_with_function, HostFunctionResult};
+use crate::evm::gas::charge_memory_expansion;
 use crate::evm::traits::EvmHost;
 use crate::evm::utils::{
     validate_address_param, validate_bytes32_param, validate_data_param, MemoryAccessor,
@@ -60,40 +61,13 @@ where
         ));
     }
 
-    // Get a mutable buffer to write to
-    let mut buffer = vec![0u8; length_u32 as usize];
+    // Charge EVM memory expansion for the destination range
+    charge_memory_expansion(instance, result_offset_u32, length_u32, "code_copy")?;
 
-    // Copy code using the evmhost's copy_code method
+    // Copy code from the offset, zero-filling past the end of the code
     let code = evmhost.code_copy();
-    let dest_len = buffer.len();
-
-    // Calculate how much we can actually copy
-    let available_from_offset = if (code_offset as usize) < code.len() {
-        code.len() - code_offset as usize
-    } else {
-        0
-    };
-
-    let copied_bytes = std::cmp::min(
-        std::cmp::min(length_u32 as usize, available_from_offset),
-        dest_len,
-    );
-
-    if copied_bytes > 0 {
-        buffer[..copied_bytes]
-            .copy_from_slice(&code[code_offset as usize..code_offset as usize + copied_bytes]);
-    }
-
-    // Fill remaining buffer with zeros if needed
-    if copied_bytes < dest_len && copied_bytes < length_u32 as usize {
-        let zero_fill_len =
-            std::cmp::min(length_u32 as usize - copied_bytes, dest_len - copied_bytes);
-        if zero_fill_len > 0 {
-            buffer[copied_bytes..copied_bytes + zero_fill_len].fill(0);
-        }
-    }
-    // Write the copied data to memory
-    memory.write_bytes(result_offset_u32, &buffer[..copied_bytes])?;
+    let available = code.get(code_offset as usize..).unwrap_or(&[]);
+    memory.write_bytes_padded(result_offset_u32, available, length_u32)?;
     Ok(())
 }
 
@@ -217,39 +191,93 @@ where
         ));
     }
 
+    // Charge EVM memory expansion for the destination range
+    charge_memory_expansion(
+        instance,
+        result_offset_u32,
+        length_u32,
+        "external_code_copy",
+    )?;
+
     // Read the address
     let address = memory.read_address(addr_offset_u32)?;
 
     // Query the external code using the ExternalCodeProvider trait
-    match evmhost.external_code_copy(&address) {
-        Some(external_code) => {
-            let mut buffer = vec![0u8; length_u32 as usize];
-
-            // Copy from external code with bounds checking
-            let code_offset_usize = code_offset as usize;
-            let available_bytes = if code_offset_usize < external_code.len() {
-                external_code.len() - code_offset_usize
-            } else {
-                0
-            };
-
-            let copy_len = std::cmp::min(available_bytes, length_u32 as usize);
-            if copy_len > 0 {
-                buffer[..copy_len].copy_from_slice(
-                    &external_code[code_offset_usize..code_offset_usize + copy_len],
-                );
-            }
-
-            // Write the copied data to memory
-            memory.write_bytes(result_offset_u32, &buffer)?;
+    // Non-existent contracts read as empty code, i.e. all zeros
+    let external_code = evmhost.external_code_copy(&address).unwrap_or_default();
+    let available = external_code.get(code_offset as usize..).unwrap_or(&[]);
+    memory.write_bytes_padded(result_offset_u32, available, length_u32)?;
 
-            Ok(())
-        }
-        None => {
-            // Write zeros for non-existent contracts
-            let buffer = vec![0u8; length_u32 as usize];
-            memory.write_bytes(result_offset_u32, &buffer)?;
-            Ok(())
-        }
-    }
+    Ok(())
+}
+
+/// Get an immutable variable of the current contract
+/// Writes the 32-byte value at the given index of the host's immutables table
+/// to the specified memory location
+///
+/// Solidity bakes immutables into runtime code; hosts can inject them instead
+/// so contracts reading immutables can be tested without a full deployment.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - index: Index of the immutable variable
+/// - result_offset: Memory offset where the 32-byte value should be written
+pub fn get_immutable<T>(
+    instance: &ZenInstance<T>,
+    index: i32,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate the result offset
+    let offset = validate_bytes32_param(instance, result_offset)?;
+
+    // Reading an immutable the host does not provide is an error
+    let value = u32::try_from(index)
+        .ok()
+        .and_then(|index| evmhost.get_immutable(index))
+        .ok_or_else(|| {
+            invalid_parameter_error_with_function(
+                "index",
+                &index.to_string(),
+                "immutable lookup",
+                "get_immutable",
+            )
+        })?;
+
+    // Write the value to memory
+    memory.write_bytes32(offset, &value)?;
+
+    Ok(())
+}
+
+/// Get the code hash of the current contract
+/// Writes keccak256 of the executing contract's runtime code (not its init code)
+/// to the specified memory location
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - result_offset: Memory offset where the 32-byte hash should be written
+pub fn get_self_code_hash<T>(
+    instance: &ZenInstance<T>,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate the result offset
+    let offset = validate_bytes32_param(instance, result_offset)?;
+
+    // Hash the runtime code and write it to memory
+    let code_hash = evmhost.get_self_code_hash();
+    memory.write_bytes32(offset, &code_hash)?;
+
+    Ok(())
 }
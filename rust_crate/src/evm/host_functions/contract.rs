@@ -5,9 +5,11 @@
 
 use crate::core::instance::ZenInstance;
 use crate::evm::error::HostFunctionResult;
-use crate::evm::traits::EvmHost;
+use crate::evm::gas::{call_gas_limit, charge_account_access, charge_call_gas};
+use crate::evm::traits::{create2_address, EvmHost};
 use crate::evm::utils::{
-    validate_address_param, validate_bytes32_param, validate_data_param, MemoryAccessor,
+    validate_address_param, validate_bytes32_param, validate_data_param, validate_not_static,
+    MemoryAccessor,
 };
 
 /// Call another contract (CALL opcode)
@@ -40,6 +42,8 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    evmhost.charge_host_gas("call_contract")?;
+
     // Validate parameters
     let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
     let value_offset_u32 = validate_bytes32_param(instance, value_offset)?;
@@ -52,11 +56,22 @@ where
     // Read the value to send
     let call_value = memory.read_bytes32(value_offset_u32)?;
 
+    // Transferring value is not allowed in a static call
+    if call_value != [0u8; 32] {
+        validate_not_static(instance, "call_contract")?;
+    }
+
     // Read the call data
     let call_data = memory.read_bytes_vec(data_offset_u32, data_length_u32)?;
 
-    // Get the caller address from evmhost
-    let caller_address = evmhost.get_caller();
+    // The calling contract is the callee's msg.sender
+    let caller_address = *evmhost.get_address();
+
+    // Charge the cold/warm access cost of the target account
+    charge_account_access(instance, &target_address, "call_contract")?;
+
+    // Forward at most all but one 64th of the remaining gas
+    let gas = call_gas_limit(instance, gas);
 
     // Execute the contract call using the provider
     let result = evmhost.call_contract(
@@ -67,6 +82,9 @@ where
         gas,
     );
 
+    // Charge the gas used by the callee; unused gas returns to this frame
+    charge_call_gas(instance, result.gas_used);
+
     let success_code = if result.success { 1 } else { 0 };
 
     Ok(success_code)
@@ -102,6 +120,8 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    evmhost.charge_host_gas("call_code")?;
+
     // Validate parameters (same as call_contract)
     let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
     let value_offset_u32 = validate_bytes32_param(instance, value_offset)?;
@@ -115,8 +135,14 @@ where
 
     let call_data = memory.read_bytes_vec(data_offset_u32, data_length_u32)?;
 
-    // Get the caller address from evmhost
-    let caller_address = evmhost.get_caller();
+    // The calling contract is the callee's msg.sender
+    let caller_address = *evmhost.get_address();
+
+    // Charge the cold/warm access cost of the target account
+    charge_account_access(instance, &target_address, "call_code")?;
+
+    // Forward at most all but one 64th of the remaining gas
+    let gas = call_gas_limit(instance, gas);
 
     // Execute the call code using the provider
     let result = evmhost.call_code(
@@ -127,6 +153,9 @@ where
         gas,
     );
 
+    // Charge the gas used by the callee; unused gas returns to this frame
+    charge_call_gas(instance, result.gas_used);
+
     let success_code = if result.success { 1 } else { 0 };
 
     Ok(success_code)
@@ -160,6 +189,8 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    evmhost.charge_host_gas("call_delegate")?;
+
     // Validate parameters
     let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
     let (data_offset_u32, data_length_u32) =
@@ -173,9 +204,18 @@ where
     // Get the caller address from evmhost (for delegate call, this preserves the original caller)
     let caller_address = evmhost.get_caller();
 
+    // Charge the cold/warm access cost of the target account
+    charge_account_access(instance, &target_address, "call_delegate")?;
+
+    // Forward at most all but one 64th of the remaining gas
+    let gas = call_gas_limit(instance, gas);
+
     // Execute the delegate call using the provider
     let result = evmhost.call_delegate(&target_address, &caller_address, &call_data, gas);
 
+    // Charge the gas used by the callee; unused gas returns to this frame
+    charge_call_gas(instance, result.gas_used);
+
     let success_code = if result.success { 1 } else { 0 };
 
     Ok(success_code)
@@ -209,6 +249,8 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    evmhost.charge_host_gas("call_static")?;
+
     // Validate parameters
     let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
     let (data_offset_u32, data_length_u32) =
@@ -219,12 +261,21 @@ where
 
     let call_data = memory.read_bytes_vec(data_offset_u32, data_length_u32)?;
 
-    // Get the caller address from evmhost
-    let caller_address = evmhost.get_caller();
+    // The calling contract is the callee's msg.sender
+    let caller_address = *evmhost.get_address();
+
+    // Charge the cold/warm access cost of the target account
+    charge_account_access(instance, &target_address, "call_static")?;
+
+    // Forward at most all but one 64th of the remaining gas
+    let gas = call_gas_limit(instance, gas);
 
     // Execute the static call using the provider
     let result = evmhost.call_static(&target_address, &caller_address, &call_data, gas);
 
+    // Charge the gas used by the callee; unused gas returns to this frame
+    charge_call_gas(instance, result.gas_used);
+
     let success_code = if result.success { 1 } else { 0 };
 
     Ok(success_code)
@@ -264,6 +315,11 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    evmhost.charge_host_gas("create_contract")?;
+
+    // CREATE/CREATE2 are not allowed in a static call
+    validate_not_static(instance, "create_contract")?;
+
     // Validate parameters
     let value_offset_u32 = validate_bytes32_param(instance, value_offset)?;
     let (code_offset_u32, code_length_u32) = validate_data_param(
@@ -324,3 +380,41 @@ where
 
     Ok(success_code)
 }
+
+/// Compute a CREATE2 contract address without deploying
+/// Writes keccak256(0xff ++ address(this) ++ salt ++ code_hash)[12:] to memory,
+/// matching the address a subsequent CREATE2 from this contract would produce
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - salt_offset: Memory offset of the 32-byte salt
+/// - code_hash_offset: Memory offset of the 32-byte keccak256 hash of the init code
+/// - result_offset: Memory offset where the 20-byte address should be written
+pub fn compute_create2_address<T>(
+    instance: &ZenInstance<T>,
+    salt_offset: i32,
+    code_hash_offset: i32,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate parameters
+    let salt_offset_u32 = validate_bytes32_param(instance, salt_offset)?;
+    let code_hash_offset_u32 = validate_bytes32_param(instance, code_hash_offset)?;
+    let result_offset_u32 = validate_address_param(instance, result_offset)?;
+
+    // Read parameters
+    let salt = memory.read_bytes32(salt_offset_u32)?;
+    let code_hash = memory.read_bytes32(code_hash_offset_u32)?;
+
+    // The deployer is the currently executing contract
+    let address = create2_address(evmhost.get_address(), &salt, &code_hash);
+
+    memory.write_address(result_offset_u32, &address)?;
+
+    Ok(())
+}
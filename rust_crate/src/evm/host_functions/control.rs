@@ -4,9 +4,14 @@
 //! Execution control host functions
 
 use crate::core::instance::ZenInstance;
-use crate::evm::error::HostFunctionResult;
-use crate::evm::traits::EvmHost;
-use crate::evm::utils::{validate_address_param, validate_data_param, MemoryAccessor};
+use crate::evm::error::{
+    invalid_parameter_error_with_function, out_of_bounds_error_with_function, HostFunctionResult,
+};
+use crate::evm::gas::charge_memory_expansion;
+use crate::evm::traits::{Dispatch, EvmHost};
+use crate::evm::utils::{
+    validate_address_param, validate_data_param, validate_not_static, MemoryAccessor,
+};
 
 /// Finish execution and return data (RETURN opcode)
 /// Terminates execution successfully and returns the specified data
@@ -41,6 +46,28 @@ where
     Ok(())
 }
 
+/// Stop execution without return data (STOP opcode)
+/// Terminates execution successfully with empty return data, without reading memory
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+///
+/// Note: This function should cause the WASM execution to terminate
+pub fn stop<T>(instance: &ZenInstance<T>) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    // Finish with no return data, replacing any data from earlier calls
+    let evmhost = &instance.extra_ctx;
+    evmhost.finish(vec![]);
+
+    // Successfully finish execution - exit with code 0 (success)
+    instance.exit(0);
+
+    // This should not be reached, but return Ok for completeness
+    Ok(())
+}
+
 /// Revert execution and return data (REVERT opcode)
 /// Terminates execution with failure and returns the specified error data
 ///
@@ -74,6 +101,48 @@ where
     Ok(())
 }
 
+/// Revert execution with a custom error (REVERT opcode)
+/// Terminates execution with failure and returns the 4-byte selector followed by the
+/// specified data, matching the Solidity encoding of custom errors
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - selector_offset: Memory offset of the 4-byte error selector
+/// - data_offset: Memory offset of the ABI-encoded error arguments
+/// - length: Length of the error arguments
+///
+/// Note: This function should cause the WASM execution to terminate with revert
+pub fn revert_with_selector<T>(
+    instance: &ZenInstance<T>,
+    selector_offset: i32,
+    data_offset: i32,
+    length: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate parameters
+    let (selector_offset_u32, _) =
+        validate_data_param(instance, selector_offset, 4, Some("revert_with_selector"))?;
+    let (data_offset_u32, length_u32) =
+        validate_data_param(instance, data_offset, length, Some("revert_with_selector"))?;
+
+    // Build the revert data: selector ++ arguments
+    let mut revert_data = memory.read_bytes_vec(selector_offset_u32, 4)?;
+    revert_data.extend(memory.read_bytes_vec(data_offset_u32, length_u32)?);
+
+    let evmhost = &instance.extra_ctx;
+    evmhost.revert(revert_data);
+
+    // Revert execution - exit with code 1 (revert)
+    instance.exit(1);
+
+    // This should not be reached, but return Ok for completeness
+    Ok(())
+}
+
 /// Invalid operation (INVALID opcode)
 /// Terminates execution with an invalid operation error
 ///
@@ -96,6 +165,56 @@ where
     Ok(())
 }
 
+/// Dispatch kind passed to `record_dispatch` for a function call
+pub const DISPATCH_FUNCTION: i32 = 0;
+/// Dispatch kind passed to `record_dispatch` for `fallback()`
+pub const DISPATCH_FALLBACK: i32 = 1;
+/// Dispatch kind passed to `record_dispatch` for `receive()`
+pub const DISPATCH_RECEIVE: i32 = 2;
+
+/// Record which entry point the contract dispatched the current call to
+/// Debugging aid that lets tests observe whether a function, `fallback()` or
+/// `receive()` ran; it has no effect on execution
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - kind: `DISPATCH_FUNCTION`, `DISPATCH_FALLBACK` or `DISPATCH_RECEIVE`
+/// - selector_offset: Memory offset of the 4-byte selector (only read for `DISPATCH_FUNCTION`)
+pub fn record_dispatch<T>(
+    instance: &ZenInstance<T>,
+    kind: i32,
+    selector_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    let dispatch = match kind {
+        DISPATCH_FUNCTION => {
+            let (offset, _) =
+                validate_data_param(instance, selector_offset, 4, Some("record_dispatch"))?;
+            let selector = memory.read_bytes(offset, 4)?;
+            Dispatch::Function([selector[0], selector[1], selector[2], selector[3]])
+        }
+        DISPATCH_FALLBACK => Dispatch::Fallback,
+        DISPATCH_RECEIVE => Dispatch::Receive,
+        _ => {
+            return Err(invalid_parameter_error_with_function(
+                "kind",
+                &kind.to_string(),
+                "unknown dispatch kind",
+                "record_dispatch",
+            ))
+        }
+    };
+
+    evmhost.record_dispatch(dispatch);
+
+    Ok(())
+}
+
 /// Self-destruct the contract (SELFDESTRUCT opcode)
 /// Destroys the current contract and sends its balance to the specified address
 ///
@@ -111,6 +230,9 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    // SELFDESTRUCT is not allowed in a static call
+    validate_not_static(instance, "self_destruct")?;
+
     // Validate the address parameter
     let addr_offset_u32 = validate_address_param(instance, addr_offset)?;
 
@@ -152,6 +274,7 @@ where
 ///
 /// This function copies return data that was set by the last contract call or
 /// the current contract's execution (via finish/revert) to the specified memory location.
+/// Copying past the end of the return data fails with an out-of-bounds error.
 ///
 /// Parameters:
 /// - instance: WASM instance pointer
@@ -184,26 +307,27 @@ where
 
     // Get the return data from the evmhost
     let return_data = evmhost.return_data_copy();
-    let data_offset_usize = data_offset as usize;
-
-    // Prepare buffer for copying
-    let mut buffer = vec![0u8; length_u32 as usize];
-
-    // Copy from return data with bounds checking
-    let available_bytes = if data_offset_usize < return_data.len() {
-        return_data.len() - data_offset_usize
-    } else {
-        0
-    };
 
-    let copy_len = std::cmp::min(available_bytes, length_u32 as usize);
-    if copy_len > 0 {
-        buffer[..copy_len]
-            .copy_from_slice(&return_data[data_offset_usize..data_offset_usize + copy_len]);
+    // Unlike the other copy operations, reading past the end of the return data
+    // is an error rather than being zero-filled (EIP-211)
+    let data_offset_u32 = data_offset as u32;
+    let end = data_offset_u32 as u64 + length_u32 as u64;
+    if end > return_data.len() as u64 {
+        return Err(out_of_bounds_error_with_function(
+            data_offset_u32,
+            length_u32,
+            &format!("return data of {} bytes", return_data.len()),
+            "return_data_copy",
+        ));
     }
 
-    // Write the buffer to memory
-    memory.write_bytes(result_offset_u32, &buffer)?;
+    // Charge EVM memory expansion for the destination range
+    charge_memory_expansion(instance, result_offset_u32, length_u32, "return_data_copy")?;
+
+    memory.write_bytes(
+        result_offset_u32,
+        &return_data[data_offset_u32 as usize..end as usize],
+    )?;
 
     Ok(())
 }
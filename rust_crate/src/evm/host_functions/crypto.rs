@@ -11,6 +11,12 @@
 //!
 //! - [`sha256`] - SHA-256 hash function (used in Bitcoin and other systems)
 //! - [`keccak256`] - Keccak-256 hash function (Ethereum's primary hash function)
+//! - [`keccak512`] - Keccak-512 hash function
+//! - [`ripemd160`] - RIPEMD-160 hash function (the 0x03 precompile)
+//!
+//! # Proof Verification
+//!
+//! - [`verify_merkle_proof`] - Keccak-256 Merkle proof check with sorted-pair hashing
 //!
 //! # Hash Function Properties
 //!
@@ -44,10 +50,14 @@
 //! ```
 
 use crate::core::instance::ZenInstance;
-use crate::evm::error::HostFunctionResult;
+use crate::evm::error::{invalid_parameter_error_with_function, HostFunctionResult};
 use crate::evm::traits::EvmHost;
 use crate::evm::utils::{validate_bytes32_param, validate_data_param, MemoryAccessor};
 
+/// Inputs at least this large are hashed straight out of WASM memory instead
+/// of being copied into an owned buffer first
+pub const KECCAK_STREAMING_THRESHOLD: u32 = 64 * 1024;
+
 /// SHA256 hash function implementation
 /// Computes the SHA256 hash of the input data and writes it to the result location
 ///
@@ -70,6 +80,8 @@ where
 {
     let memory = MemoryAccessor::new(instance);
 
+    instance.extra_ctx.charge_host_gas("sha256")?;
+
     // Validate parameters
     let (input_offset_u32, input_length_u32) =
         validate_data_param(instance, input_offset, input_length, Some("sha256"))?;
@@ -110,24 +122,179 @@ where
 {
     let memory = MemoryAccessor::new(instance);
 
+    instance.extra_ctx.charge_host_gas("keccak256")?;
+
     // Validate parameters
     let (input_offset_u32, input_length_u32) =
         validate_data_param(instance, input_offset, input_length, Some("keccak256"))?;
     let result_offset_u32 = validate_bytes32_param(instance, result_offset)?;
 
+    // Large inputs are hashed in place; both paths end in
+    // `EvmHost::keccak256_slice`, so custom implementations see every input
+    let evmhost = &instance.extra_ctx;
+    let hash_bytes: [u8; 32] = if input_length_u32 >= KECCAK_STREAMING_THRESHOLD {
+        hash_memory_slice(evmhost, &memory, input_offset_u32, input_length_u32)?
+    } else {
+        let input_data = memory.read_bytes_vec(input_offset_u32, input_length_u32)?;
+        evmhost.keccak256(input_data)
+    };
+
+    // Write the hash to memory
+    memory.write_bytes32(result_offset_u32, &hash_bytes)?;
+
+    Ok(())
+}
+
+/// Keccak512 hash function implementation
+/// Computes the Keccak-512 hash of the input data and writes it to the result location
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - input_offset: Memory offset of the input data
+/// - input_length: Length of the input data
+/// - result_offset: Memory offset where the 64-byte hash should be written
+pub fn keccak512<T>(
+    instance: &ZenInstance<T>,
+    input_offset: i32,
+    input_length: i32,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let memory = MemoryAccessor::new(instance);
+
+    instance.extra_ctx.charge_host_gas("keccak512")?;
+
+    // Validate parameters
+    let (input_offset_u32, input_length_u32) =
+        validate_data_param(instance, input_offset, input_length, Some("keccak512"))?;
+    let (result_offset_u32, _) =
+        validate_data_param(instance, result_offset, 64, Some("keccak512"))?;
+
     // Read input data
     let input_data = memory.read_bytes_vec(input_offset_u32, input_length_u32)?;
 
-    // Compute Keccak256 hash using the sha3 crate
     let evmhost = &instance.extra_ctx;
-    let hash_bytes: [u8; 32] = evmhost.keccak256(input_data);
+    let hash_bytes: [u8; 64] = evmhost.keccak512(input_data);
 
     // Write the hash to memory
-    memory.write_bytes32(result_offset_u32, &hash_bytes)?;
+    memory.write_bytes(result_offset_u32, &hash_bytes)?;
 
     Ok(())
 }
 
+/// RIPEMD160 hash function implementation
+/// Computes the RIPEMD-160 hash of the input data and writes it to the result location
+///
+/// The 20-byte hash is left-padded with zeros to 32 bytes, matching the output
+/// of the RIPEMD-160 precompile (address 0x03).
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - input_offset: Memory offset of the input data
+/// - input_length: Length of the input data
+/// - result_offset: Memory offset where the 32-byte padded hash should be written
+pub fn ripemd160<T>(
+    instance: &ZenInstance<T>,
+    input_offset: i32,
+    input_length: i32,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let memory = MemoryAccessor::new(instance);
+
+    instance.extra_ctx.charge_host_gas("ripemd160")?;
+
+    // Validate parameters
+    let (input_offset_u32, input_length_u32) =
+        validate_data_param(instance, input_offset, input_length, Some("ripemd160"))?;
+    let result_offset_u32 = validate_bytes32_param(instance, result_offset)?;
+
+    // Read input data
+    let input_data = memory.read_bytes_vec(input_offset_u32, input_length_u32)?;
+
+    let evmhost = &instance.extra_ctx;
+    let hash_bytes: [u8; 20] = evmhost.ripemd160(input_data);
+
+    // Left-pad the hash to a 32-byte word
+    let mut result = [0u8; 32];
+    result[12..].copy_from_slice(&hash_bytes);
+    memory.write_bytes32(result_offset_u32, &result)?;
+
+    Ok(())
+}
+
+/// Keccak-256 hash a region of WASM memory without copying it into an owned buffer
+/// The slice is borrowed from linear memory and passed to the host
+fn hash_memory_slice<T: EvmHost>(
+    evmhost: &T,
+    memory: &MemoryAccessor<T>,
+    offset: u32,
+    length: u32,
+) -> HostFunctionResult<[u8; 32]> {
+    let data = memory.read_bytes(offset, length)?;
+    Ok(evmhost.keccak256_slice(data))
+}
+
+/// Verify a Merkle proof
+/// Checks that the leaf is part of the tree with the given root, hashing each pair
+/// with keccak256 in sorted order (the OpenZeppelin `MerkleProof` convention)
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - leaf_offset: Memory offset of the 32-byte leaf hash
+/// - proof_offset: Memory offset of the proof, a sequence of 32-byte sibling hashes
+/// - proof_count: Number of 32-byte hashes in the proof
+/// - root_offset: Memory offset of the 32-byte expected root
+///
+/// Returns:
+/// - 1 if the proof is valid, 0 otherwise
+pub fn verify_merkle_proof<T>(
+    instance: &ZenInstance<T>,
+    leaf_offset: i32,
+    proof_offset: i32,
+    proof_count: i32,
+    root_offset: i32,
+) -> HostFunctionResult<i32>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate parameters
+    let leaf_offset_u32 = validate_bytes32_param(instance, leaf_offset)?;
+    let root_offset_u32 = validate_bytes32_param(instance, root_offset)?;
+    let proof_length = proof_count.checked_mul(32).ok_or_else(|| {
+        invalid_parameter_error_with_function(
+            "proof_count",
+            &proof_count.to_string(),
+            "proof too long",
+            "verify_merkle_proof",
+        )
+    })?;
+    let (proof_offset_u32, proof_length_u32) = validate_data_param(
+        instance,
+        proof_offset,
+        proof_length,
+        Some("verify_merkle_proof"),
+    )?;
+
+    // Read the leaf, root and proof hashes
+    let leaf = memory.read_bytes32(leaf_offset_u32)?;
+    let root = memory.read_bytes32(root_offset_u32)?;
+    let proof: Vec<[u8; 32]> = memory
+        .read_bytes(proof_offset_u32, proof_length_u32)?
+        .chunks_exact(32)
+        .map(|hash| hash.try_into().unwrap())
+        .collect();
+
+    Ok(evmhost.verify_merkle_proof(leaf, &proof, root) as i32)
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
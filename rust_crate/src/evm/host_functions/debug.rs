@@ -0,0 +1,44 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Debug Host Functions
+//!
+//! Test-only helpers for diagnosing failing contracts, e.g. dumping the
+//! calldata or ABI buffer a contract built to spot off-by-one offsets.
+//!
+//! These functions are only compiled with the `debug` feature and must never
+//! be registered in a production host. Hosts register them in a separate
+//! `debug` host module so contracts import them explicitly.
+//!
+//! # Functions
+//!
+//! - [`dump_memory`] - Hand a region of WASM memory to the host
+
+use crate::core::instance::ZenInstance;
+use crate::evm::error::HostFunctionResult;
+use crate::evm::traits::EvmHost;
+use crate::evm::utils::{validate_data_param, MemoryAccessor};
+
+/// Dump a region of WASM memory (debug)
+/// The region is passed to `EvmHost::dump_memory`, which prints or captures it
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - offset: Memory offset of the region
+/// - length: Length of the region
+pub fn dump_memory<T>(instance: &ZenInstance<T>, offset: i32, length: i32) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate and read the region
+    let (offset_u32, length_u32) =
+        validate_data_param(instance, offset, length, Some("dump_memory"))?;
+    let data = memory.read_bytes(offset_u32, length_u32)?;
+
+    evmhost.dump_memory(offset_u32, data);
+
+    Ok(())
+}
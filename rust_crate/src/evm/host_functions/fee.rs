@@ -8,8 +8,8 @@
 
 use crate::core::instance::ZenInstance;
 use crate::evm::error::HostFunctionResult;
-use crate::evm::traits::EvmHost;
-use crate::evm::utils::{validate_bytes32_param, MemoryAccessor};
+use crate::evm::traits::{EvmHost, Hardfork};
+use crate::evm::utils::{validate_bytes32_param, validate_hardfork, MemoryAccessor};
 
 /// Get the current block's base fee
 /// Writes the 32-byte base fee to the specified memory location
@@ -24,6 +24,9 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    // The base fee was introduced in London
+    validate_hardfork(instance, Hardfork::London, "get_base_fee")?;
+
     // Validate the result offset
     let offset = validate_bytes32_param(instance, result_offset)?;
 
@@ -36,6 +39,43 @@ where
     Ok(())
 }
 
+/// Get the base fee of a previous block
+/// Writes the 32-byte base fee of the block `blocks_ago` blocks before the current one,
+/// or zero if that block is outside the history kept by the host
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - blocks_ago: How many blocks back to look (0 is the current block)
+/// - result_offset: Memory offset where the 32-byte base fee should be written
+pub fn get_base_fee_at<T>(
+    instance: &ZenInstance<T>,
+    blocks_ago: i64,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // The base fee was introduced in London
+    validate_hardfork(instance, Hardfork::London, "get_base_fee_at")?;
+
+    // Validate the result offset
+    let offset = validate_bytes32_param(instance, result_offset)?;
+
+    // Negative offsets point to future blocks, which have no base fee yet
+    let base_fee = u64::try_from(blocks_ago)
+        .ok()
+        .and_then(|blocks_ago| evmhost.get_base_fee_at(blocks_ago))
+        .unwrap_or([0u8; 32]);
+
+    // Write the base fee to memory
+    memory.write_bytes32(offset, &base_fee)?;
+
+    Ok(())
+}
+
 /// Get the current block's blob base fee (EIP-4844)
 /// Writes the 32-byte blob base fee to the specified memory location
 ///
@@ -49,6 +89,9 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    // The blob base fee was introduced in Cancun
+    validate_hardfork(instance, Hardfork::Cancun, "get_blob_base_fee")?;
+
     // Validate the result offset
     let offset = validate_bytes32_param(instance, result_offset)?;
 
@@ -6,7 +6,9 @@
 use crate::core::instance::ZenInstance;
 use crate::evm::error::HostFunctionResult;
 use crate::evm::traits::{EvmHost, LogEvent};
-use crate::evm::utils::{validate_bytes32_param, validate_data_param, MemoryAccessor};
+use crate::evm::utils::{
+    validate_bytes32_param, validate_data_param, validate_not_static, MemoryAccessor,
+};
 
 /// Emit a log event (LOG0, LOG1, LOG2, LOG3, LOG4 opcodes)
 /// Creates a log entry with the specified data and topics
@@ -36,6 +38,9 @@ where
     let memory = MemoryAccessor::new(instance);
     let evmhost = &instance.extra_ctx;
 
+    // LOG is not allowed in a static call
+    validate_not_static(instance, "emit_log_event")?;
+
     // Validate number of topics
     if num_topics < 0 || num_topics > 4 {
         return Err(crate::evm::error::invalid_parameter_error(
@@ -48,6 +53,16 @@ where
     // Validate data parameters
     let (data_offset_u32, length_u32) = validate_data_param(instance, data_offset, length, Some("emit_log_event"))?;
 
+    // Reject oversized payloads before allocating a buffer for them
+    if length_u32 as usize > evmhost.max_log_data() {
+        return Err(crate::evm::error::invalid_parameter_error_with_function(
+            "length",
+            &length.to_string(),
+            &format!("log data exceeds the {} byte limit", evmhost.max_log_data()),
+            "emit_log_event",
+        ));
+    }
+
     // Read the log data
     let log_data = memory.read_bytes_vec(data_offset_u32, length_u32)?;
 
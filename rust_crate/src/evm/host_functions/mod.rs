@@ -17,6 +17,9 @@
 //! - **Control**: Execution control (finish, revert, etc.)
 //! - **Log**: Event logging and emission
 //! - **Fee**: Fee-related operations
+//! - **Precompile**: Precompiled contract operations (ecrecover)
+//! - **Cheatcode**: Test-only environment manipulation (`cheatcodes` feature)
+//! - **Debug**: Test-only contract debugging aids (`debug` feature)
 //!
 //! # Usage
 //!
@@ -37,46 +40,77 @@ pub mod crypto;
 pub mod fee;
 pub mod log;
 pub mod math;
+pub mod precompile;
 pub mod storage;
 pub mod transaction;
 
+#[cfg(feature = "cheatcodes")]
+pub mod cheatcode;
+#[cfg(feature = "debug")]
+pub mod debug;
+
 // Re-export commonly used functions for convenience
 // Account operations
 pub use account::{
-    get_address, get_call_value, get_caller, get_chain_id, get_external_balance, get_tx_origin,
+    access_account, get_address, get_call_value, get_caller, get_chain_id, get_external_balance,
+    get_self_balance, get_tx_origin, verify_chain_id,
 };
 
 // Block operations
 pub use block::{
-    get_block_coinbase, get_block_gas_limit, get_block_hash, get_block_number,
-    get_block_prev_randao, get_block_timestamp,
+    get_block_coinbase, get_block_gas_limit, get_block_hash, get_block_info, get_block_number,
+    get_block_prev_randao, get_block_timestamp, get_difficulty, is_block_hash_available,
+    BLOCK_HASH_WINDOW, BLOCK_INFO_SIZE,
 };
 
 // Transaction operations
-pub use transaction::{call_data_copy, get_call_data_size, get_gas_left, get_tx_gas_price};
+pub use transaction::{
+    call_data_copy, get_blob_hash, get_blob_hash_count, get_call_data_size, get_gas_left,
+    get_tx_gas_price, GAS_OPCODE_COST,
+};
 
 // Storage operations
-pub use storage::{storage_load, storage_store};
+pub use storage::{access_storage, storage_load, storage_store, transient_load, transient_store};
 
 // Code operations
 pub use code::{
     code_copy, external_code_copy, get_code_size, get_external_code_hash, get_external_code_size,
+    get_immutable, get_self_code_hash,
 };
 
 // Crypto operations
-pub use crypto::{keccak256, sha256};
+pub use crypto::{
+    keccak256, keccak512, ripemd160, sha256, verify_merkle_proof, KECCAK_STREAMING_THRESHOLD,
+};
 
 // Math operations
 pub use math::{addmod, expmod, mulmod};
 
 // Contract operations
-pub use contract::{call_code, call_contract, call_delegate, call_static, create_contract};
+pub use contract::{
+    call_code, call_contract, call_delegate, call_static, compute_create2_address, create_contract,
+};
 
 // Control operations
-pub use control::{finish, get_return_data_size, invalid, return_data_copy, revert, self_destruct};
+pub use control::{
+    finish, get_return_data_size, invalid, record_dispatch, return_data_copy, revert,
+    revert_with_selector, self_destruct, stop, DISPATCH_FALLBACK, DISPATCH_FUNCTION,
+    DISPATCH_RECEIVE,
+};
 
 // Log operations
 pub use log::{emit_log0, emit_log1, emit_log2, emit_log3, emit_log4, emit_log_event};
 
 // Fee operations
-pub use fee::{get_base_fee, get_blob_base_fee};
+pub use fee::{get_base_fee, get_base_fee_at, get_blob_base_fee};
+
+// Precompile operations
+pub use precompile::{ec_recover, modexp};
+
+// Cheatcode operations
+#[cfg(feature = "cheatcodes")]
+pub use cheatcode::{deal, prank, roll, warp};
+
+// Debug operations
+#[cfg(feature = "debug")]
+pub use debug::dump_memory;
@@ -0,0 +1,136 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Precompile Host Functions
+//!
+//! Host functions exposing precompiled contracts directly to WASM contracts,
+//! so they do not need to encode a call to the precompile address.
+//!
+//! # Functions
+//!
+//! - [`ec_recover`] - Recover the signer address of an ECDSA signature (0x01)
+//! - [`modexp`] - Variable-length modular exponentiation (0x05, EIP-198)
+
+use crate::core::instance::ZenInstance;
+use crate::evm::error::{invalid_parameter_error_with_function, HostFunctionResult};
+use crate::evm::precompiles::{modexp_lengths, modexp_output};
+use crate::evm::traits::EvmHost;
+use crate::evm::utils::{
+    validate_bytes32_param, validate_data_param, write_address_result, MemoryAccessor,
+    MAX_BUFFER_SIZE,
+};
+
+/// Recover the signer of an ECDSA signature (ecrecover precompile)
+/// Writes the recovered 20-byte address, or the zero address if the signature is invalid
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - hash_offset: Memory offset of the 32-byte message hash
+/// - v_offset: Memory offset of the 32-byte recovery value (27 or 28)
+/// - r_offset: Memory offset of the 32-byte signature r value
+/// - s_offset: Memory offset of the 32-byte signature s value
+/// - result_offset: Memory offset where the address (20 bytes or a padded word) should be written
+pub fn ec_recover<T>(
+    instance: &ZenInstance<T>,
+    hash_offset: i32,
+    v_offset: i32,
+    r_offset: i32,
+    s_offset: i32,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate parameters
+    let hash_offset_u32 = validate_bytes32_param(instance, hash_offset)?;
+    let v_offset_u32 = validate_bytes32_param(instance, v_offset)?;
+    let r_offset_u32 = validate_bytes32_param(instance, r_offset)?;
+    let s_offset_u32 = validate_bytes32_param(instance, s_offset)?;
+
+    // Read the hash and signature
+    let hash = memory.read_bytes32(hash_offset_u32)?;
+    let v = memory.read_bytes32(v_offset_u32)?;
+    let r = memory.read_bytes32(r_offset_u32)?;
+    let s = memory.read_bytes32(s_offset_u32)?;
+
+    // v is a 32-byte word; anything but 27 or 28 is an invalid signature
+    let signer = if v[..31].iter().all(|byte| *byte == 0) {
+        evmhost.ec_recover(&hash, v[31], &r, &s)
+    } else {
+        None
+    };
+
+    write_address_result(instance, result_offset, &signer.unwrap_or([0u8; 20]))?;
+
+    Ok(())
+}
+
+/// Variable-length modular exponentiation (modexp precompile, EIP-198)
+/// Computes `base^exp % modulus` and writes it right-aligned to `result_length` bytes
+///
+/// The input is `base_len`, `exp_len` and `mod_len` as 32-byte big-endian words
+/// followed by the base, exponent and modulus of those lengths; missing input
+/// bytes read as zero. A zero modulus yields zero. A result longer than the
+/// modulus is left-padded with zeros; a shorter one keeps the low-order bytes.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - input_offset: Memory offset of the EIP-198 encoded input
+/// - input_length: Length of the input
+/// - result_offset: Memory offset where the result should be written
+/// - result_length: Number of bytes to write at result_offset
+pub fn modexp<T>(
+    instance: &ZenInstance<T>,
+    input_offset: i32,
+    input_length: i32,
+    result_offset: i32,
+    result_length: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate parameters
+    let (input_offset_u32, input_length_u32) =
+        validate_data_param(instance, input_offset, input_length, Some("modexp"))?;
+    let (result_offset_u32, result_length_u32) =
+        validate_data_param(instance, result_offset, result_length, Some("modexp"))?;
+
+    let input = memory.read_bytes_vec(input_offset_u32, input_length_u32)?;
+
+    // The operands are zero-padded to their declared lengths, so bound those
+    // before allocating them
+    let (base_len, exp_len, mod_len) = modexp_lengths(&input)
+        .filter(|(base_len, exp_len, mod_len)| {
+            base_len
+                .checked_add(*exp_len)
+                .and_then(|total| total.checked_add(*mod_len))
+                .is_some_and(|total| total <= MAX_BUFFER_SIZE as u64)
+        })
+        .ok_or_else(|| {
+            invalid_parameter_error_with_function(
+                "input",
+                &hex::encode(&input[..input.len().min(96)]),
+                "operand lengths exceed the buffer size limit",
+                "modexp",
+            )
+        })?;
+
+    let output = modexp_output(&input, base_len, exp_len, mod_len);
+
+    // Right-align the result in result_length bytes
+    let result_length = result_length_u32 as usize;
+    let mut result = vec![0u8; result_length];
+    if result_length >= output.len() {
+        result[result_length - output.len()..].copy_from_slice(&output);
+    } else {
+        result.copy_from_slice(&output[output.len() - result_length..]);
+    }
+    memory.write_bytes(result_offset_u32, &result)?;
+
+    Ok(())
+}
@@ -19,6 +19,15 @@
 //!
 //! - [`storage_store`] - Store a 32-byte value at a 32-byte key (SSTORE)
 //! - [`storage_load`] - Load a 32-byte value from a 32-byte key (SLOAD)
+//! - [`transient_store`] - Store a 32-byte value in transient storage (TSTORE)
+//! - [`transient_load`] - Load a 32-byte value from transient storage (TLOAD)
+//! - [`access_storage`] - Access a storage slot and report whether it was warm
+//!
+//! # Transient Storage
+//!
+//! Transient storage (EIP-1153) has the same key-value layout as storage, but
+//! it is discarded at the end of every transaction and never touches
+//! persistent storage. Unset transient keys read as zero.
 //!
 //! # Gas Costs
 //!
@@ -26,6 +35,9 @@
 //! - SSTORE: 5,000-20,000 gas depending on the operation type
 //! - SLOAD: 800 gas for warm access, 2,100 gas for cold access
 //!
+//! Clearing a non-zero slot adds [`SSTORE_CLEARS_REFUND`] to the transaction's
+//! gas refund counter.
+//!
 //! # Usage Example
 //!
 //! ```rust
@@ -38,8 +50,11 @@
 
 use crate::core::instance::ZenInstance;
 use crate::evm::error::HostFunctionResult;
-use crate::evm::traits::EvmHost;
-use crate::evm::utils::MemoryAccessor;
+use crate::evm::gas::{charge_sstore, SSTORE_CLEARS_REFUND};
+use crate::evm::traits::{EvmHost, Hardfork};
+use crate::evm::utils::{
+    validate_bytes32_param, validate_hardfork, validate_not_static, MemoryAccessor,
+};
 
 /// Storage store host function implementation
 /// Stores a 32-byte value at a 32-byte key in contract storage
@@ -60,12 +75,28 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    evmhost.charge_host_gas("storage_store")?;
+
+    // SSTORE is not allowed in a static call
+    validate_not_static(instance, "storage_store")?;
+
     // Validate and read the storage key (32 bytes)
     let key_bytes = memory.read_bytes32(key_bytes_offset as u32)?;
 
     // Validate and read the storage value (32 bytes)
     let value_bytes = memory.read_bytes32(value_bytes_offset as u32)?;
 
+    match evmhost.sstore_gas_costs() {
+        // Net gas metering charges and refunds by the slot's original value
+        Some(costs) => charge_sstore(instance, &costs, &key_bytes, &value_bytes, "storage_store")?,
+        // Otherwise only clearing a non-zero slot earns a gas refund
+        None => {
+            if value_bytes == [0u8; 32] && evmhost.storage_load(&key_bytes) != [0u8; 32] {
+                evmhost.add_gas_refund(SSTORE_CLEARS_REFUND);
+            }
+        }
+    }
+
     // Store the value in the evmhost using EVMC-compatible method
     evmhost.storage_store(&key_bytes, &value_bytes);
 
@@ -91,6 +122,8 @@ where
     let evmhost = &instance.extra_ctx;
     let memory = MemoryAccessor::new(instance);
 
+    evmhost.charge_host_gas("storage_load")?;
+
     // Validate and read the storage key (32 bytes)
     let key_bytes = memory.read_bytes32(key_bytes_offset as u32)?;
 
@@ -102,3 +135,102 @@ where
 
     Ok(())
 }
+
+/// Transient storage store host function implementation
+/// Stores a 32-byte value at a 32-byte key in transient storage (EIP-1153)
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - key_bytes_offset: Memory offset of the 32-byte storage key
+/// - value_bytes_offset: Memory offset of the 32-byte storage value
+pub fn transient_store<T>(
+    instance: &ZenInstance<T>,
+    key_bytes_offset: i32,
+    value_bytes_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    evmhost.charge_host_gas("transient_store")?;
+
+    // Transient storage was introduced in Cancun
+    validate_hardfork(instance, Hardfork::Cancun, "transient_store")?;
+
+    // TSTORE is not allowed in a static call
+    validate_not_static(instance, "transient_store")?;
+
+    // Validate and read the storage key (32 bytes)
+    let key_bytes = memory.read_bytes32(key_bytes_offset as u32)?;
+
+    // Validate and read the storage value (32 bytes)
+    let value_bytes = memory.read_bytes32(value_bytes_offset as u32)?;
+
+    evmhost.transient_store(&key_bytes, &value_bytes);
+
+    Ok(())
+}
+
+/// Transient storage load host function implementation
+/// Loads a 32-byte value from transient storage at the given 32-byte key (EIP-1153)
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - key_bytes_offset: Memory offset of the 32-byte storage key
+/// - result_offset: Memory offset where the 32-byte result should be written
+pub fn transient_load<T>(
+    instance: &ZenInstance<T>,
+    key_bytes_offset: i32,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    evmhost.charge_host_gas("transient_load")?;
+
+    // Transient storage was introduced in Cancun
+    validate_hardfork(instance, Hardfork::Cancun, "transient_load")?;
+
+    // Validate and read the storage key (32 bytes)
+    let key_bytes = memory.read_bytes32(key_bytes_offset as u32)?;
+
+    let value_bytes = evmhost.transient_load(&key_bytes);
+
+    // Write the result to memory
+    memory.write_bytes32(result_offset as u32, &value_bytes)?;
+
+    Ok(())
+}
+
+/// Access a storage slot of the current contract and report whether it was
+/// warm (EIP-2929/2930)
+/// The slot is warm for the rest of the transaction afterwards
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - key_bytes_offset: Memory offset of the 32-byte storage key
+///
+/// Returns:
+/// - 1 if the slot was already warm
+/// - 0 if this was the first (cold) access
+pub fn access_storage<T>(
+    instance: &ZenInstance<T>,
+    key_bytes_offset: i32,
+) -> HostFunctionResult<i32>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Validate and read the storage key (32 bytes)
+    let key_offset = validate_bytes32_param(instance, key_bytes_offset)?;
+    let key_bytes = memory.read_bytes32(key_offset)?;
+
+    Ok(evmhost.access_storage(evmhost.get_address(), &key_bytes) as i32)
+}
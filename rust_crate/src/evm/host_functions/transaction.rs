@@ -8,8 +8,14 @@
 
 use crate::core::instance::ZenInstance;
 use crate::evm::error::HostFunctionResult;
-use crate::evm::traits::EvmHost;
-use crate::evm::utils::{validate_bytes32_param, validate_data_param, MemoryAccessor};
+use crate::evm::gas::charge_memory_expansion;
+use crate::evm::traits::{EvmHost, Hardfork};
+use crate::evm::utils::{
+    validate_bytes32_param, validate_data_param, validate_hardfork, MemoryAccessor,
+};
+
+/// Gas cost of the GAS opcode itself (G_base)
+pub const GAS_OPCODE_COST: u64 = 2;
 
 /// Get the size of the call data
 /// Returns the size of the current call data in bytes
@@ -64,6 +70,9 @@ where
         ));
     }
 
+    // Charge EVM memory expansion for the destination range
+    charge_memory_expansion(instance, result_offset_u32, length_u32, "call_data_copy")?;
+
     // Create buffer with the exact requested length, initialized with zeros
     let mut buffer = vec![0u8; length_u32 as usize];
 
@@ -107,16 +116,31 @@ where
 /// Get the remaining gas for execution
 /// Returns the amount of gas left for the current execution
 ///
+/// Matches the EVM `GAS` opcode: the opcode's own cost (`GAS_OPCODE_COST`)
+/// is charged first, so the returned value is the gas remaining after it.
+/// The host then sees the instance's remaining gas and may hide gas it
+/// reserves for itself (see `EvmHost::get_gas_left`).
+///
 /// Parameters:
 /// - instance: WASM instance pointer
 ///
 /// Returns:
-/// - The remaining gas as i64
+/// - The remaining gas as i64 (0 if the opcode cost could not be paid)
 pub fn get_gas_left<T>(instance: &ZenInstance<T>) -> i64
 where
     T: EvmHost,
 {
     let gas_left = instance.get_gas_left();
+    if gas_left < GAS_OPCODE_COST {
+        instance.set_gas_left(0);
+        instance.raise_out_of_gas_error();
+        return 0;
+    }
+
+    // Charge the GAS opcode cost before reporting the remaining gas
+    let gas_left = gas_left - GAS_OPCODE_COST;
+    instance.set_gas_left(gas_left);
+
     let evmhost = &instance.extra_ctx;
     let gas_left = evmhost.get_gas_left(gas_left as i64);
 
@@ -147,3 +171,69 @@ where
 
     Ok(())
 }
+
+/// Get the number of blob versioned hashes attached to the transaction (EIP-4844)
+/// Lets contracts bound iteration over `get_blob_hash`
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+///
+/// Returns:
+/// - The number of blob hashes as i32
+pub fn get_blob_hash_count<T>(instance: &ZenInstance<T>) -> HostFunctionResult<i32>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+
+    // Blobs were introduced in Cancun
+    validate_hardfork(instance, Hardfork::Cancun, "get_blob_hash_count")?;
+
+    Ok(evmhost.get_blob_hashes().len() as i32)
+}
+
+/// Get a blob versioned hash of the transaction (BLOBHASH opcode)
+/// Writes the 32-byte versioned hash at the given index to the specified memory location
+///
+/// Following EVM semantics, a zero hash is written when the index is out of range.
+///
+/// Parameters:
+/// - instance: WASM instance pointer
+/// - index_offset: Memory offset of the 32-byte big-endian (u256) blob index
+/// - result_offset: Memory offset where the 32-byte hash should be written
+pub fn get_blob_hash<T>(
+    instance: &ZenInstance<T>,
+    index_offset: i32,
+    result_offset: i32,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let evmhost = &instance.extra_ctx;
+    let memory = MemoryAccessor::new(instance);
+
+    // Blobs were introduced in Cancun
+    validate_hardfork(instance, Hardfork::Cancun, "get_blob_hash")?;
+
+    // Validate the index and result offsets
+    let index_offset_u32 = validate_bytes32_param(instance, index_offset)?;
+    let offset = validate_bytes32_param(instance, result_offset)?;
+
+    // Read the blob index; anything that does not fit in a u64 is out of range
+    let index_bytes = memory.read_bytes32(index_offset_u32)?;
+    let index = if index_bytes[..24].iter().all(|b| *b == 0) {
+        Some(u64::from_be_bytes(index_bytes[24..].try_into().unwrap()))
+    } else {
+        None
+    };
+
+    // Look up the blob hash, falling back to zero for out-of-range indices
+    let blob_hash = index
+        .and_then(|index| evmhost.get_blob_hash(index))
+        .unwrap_or([0u8; 32]);
+
+    // Write the blob hash to memory
+    memory.write_bytes32(offset, &blob_hash)?;
+
+    Ok(())
+}
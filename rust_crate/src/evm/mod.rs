@@ -6,12 +6,18 @@
 //! This module provides a complete implementation of EVM host functions
 //! for testing and development purposes in a WASM environment.
 
+pub mod default_host;
 pub mod error;
+pub mod gas;
 pub mod host_functions;
+pub mod precompiles;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
 pub mod traits;
 pub mod utils;
 
 // Re-export main types for convenience
+pub use default_host::DefaultHost;
 pub use error::{HostFunctionError, HostFunctionResult};
 pub use host_functions::*;
 pub use traits::*;
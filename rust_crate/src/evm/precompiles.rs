@@ -0,0 +1,206 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Precompiled contracts
+//!
+//! Hosts route calls to a precompile address here instead of executing
+//! contract code. Precompiles charge their own gas from the gas forwarded
+//! to the call and fail with all of it consumed when it is insufficient.
+//!
+//! Supported precompiles:
+//!
+//! - `0x01` ecrecover
+//! - `0x05` modexp (EIP-198, priced per EIP-2565)
+
+use crate::evm::gas::{modexp_gas, ECRECOVER_GAS};
+use crate::evm::traits::ContractCallResult;
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use sha3::{Digest, Keccak256};
+
+/// Address of the ecrecover precompile
+pub const ECRECOVER_ADDRESS: [u8; 20] =
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+/// Address of the modexp precompile
+pub const MODEXP_ADDRESS: [u8; 20] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5];
+
+/// Highest address reserved for precompiles (0x0a, point evaluation, as of Cancun)
+const LAST_PRECOMPILE_ID: u8 = 0x0a;
+
+/// Whether `address` is reserved for a precompile, whether or not it is supported here
+pub fn is_precompile(address: &[u8; 20]) -> bool {
+    address[..19].iter().all(|byte| *byte == 0) && (1..=LAST_PRECOMPILE_ID).contains(&address[19])
+}
+
+/// Run the precompile at `address` with `input` and `gas` forwarded gas
+/// Returns None if `address` is not a precompile
+pub fn run_precompile(address: &[u8; 20], input: &[u8], gas: i64) -> Option<ContractCallResult> {
+    match *address {
+        ECRECOVER_ADDRESS => Some(ecrecover_precompile(input, gas)),
+        MODEXP_ADDRESS => Some(modexp(input, gas)),
+        _ => None,
+    }
+}
+
+/// Recover the address that signed `hash` with the ECDSA signature (v, r, s)
+///
+/// `v` must be 27 or 28. Signatures with a high `s` are accepted like the
+/// ecrecover precompile does. Returns None if the signature is invalid.
+pub fn ecrecover(hash: &[u8; 32], v: u8, r: &[u8; 32], s: &[u8; 32]) -> Option<[u8; 20]> {
+    if v != 27 && v != 28 {
+        return None;
+    }
+
+    let mut signature = Signature::from_slice(&[*r, *s].concat()).ok()?;
+    let mut recovery_id = RecoveryId::new(v == 28, false);
+
+    // k256 only recovers low-s signatures; (r, n - s) with the other parity
+    // recovers the same key
+    if let Some(normalized) = signature.normalize_s() {
+        signature = normalized;
+        recovery_id = RecoveryId::new(!recovery_id.is_y_odd(), false);
+    }
+
+    let key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id).ok()?;
+    let public_key = key.to_encoded_point(false);
+    let hash = Keccak256::digest(&public_key.as_bytes()[1..]);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    Some(address)
+}
+
+/// ECDSA public key recovery precompile
+///
+/// The input is the message hash, `v`, `r` and `s` as 32-byte words; missing
+/// input bytes read as zero. The output is the signer address left-padded to
+/// 32 bytes, or empty if the signature is invalid.
+pub fn ecrecover_precompile(input: &[u8], gas: i64) -> ContractCallResult {
+    if ECRECOVER_GAS > gas.max(0) as u64 {
+        return ContractCallResult::failure(vec![], gas.max(0));
+    }
+
+    let input = read_padded(input, 0, 128);
+    let word =
+        |index: usize| -> [u8; 32] { input[index * 32..(index + 1) * 32].try_into().unwrap() };
+    let v_word = word(1);
+
+    let signer = if v_word[..31].iter().all(|byte| *byte == 0) {
+        ecrecover(&word(0), v_word[31], &word(2), &word(3))
+    } else {
+        None
+    };
+
+    let output = signer.map_or_else(Vec::new, |address| {
+        let mut output = vec![0u8; 32];
+        output[12..].copy_from_slice(&address);
+        output
+    });
+    ContractCallResult::success(output, ECRECOVER_GAS as i64)
+}
+
+/// Modular exponentiation precompile (EIP-198)
+///
+/// The input is `base_len`, `exp_len` and `mod_len` as 32-byte words followed
+/// by the base, exponent and modulus; missing input bytes read as zero. The
+/// output is `base^exp % modulus`, left-padded to `mod_len` bytes.
+pub fn modexp(input: &[u8], gas: i64) -> ContractCallResult {
+    let gas_available = gas.max(0) as u64;
+    let out_of_gas = || ContractCallResult::failure(vec![], gas.max(0));
+
+    // Lengths that do not fit in 64 bits can never be paid for
+    let Some((base_len, exp_len, mod_len)) = modexp_lengths(input) else {
+        return out_of_gas();
+    };
+
+    let exp_offset = 96u64.saturating_add(base_len);
+    let exp_head = read_padded(input, exp_offset, exp_len.min(32));
+    let cost = modexp_gas(base_len, exp_len, mod_len, &exp_head);
+    if cost > gas_available {
+        return out_of_gas();
+    }
+
+    // The result has the modulus length, so an empty modulus needs no operands
+    if mod_len == 0 {
+        return ContractCallResult::success(vec![], cost as i64);
+    }
+
+    ContractCallResult::success(
+        modexp_output(input, base_len, exp_len, mod_len),
+        cost as i64,
+    )
+}
+
+/// Read the EIP-198 header of a modexp input: `(base_len, exp_len, mod_len)`
+/// Returns None if any length does not fit in a u64
+pub fn modexp_lengths(input: &[u8]) -> Option<(u64, u64, u64)> {
+    Some((
+        read_length(input, 0)?,
+        read_length(input, 32)?,
+        read_length(input, 64)?,
+    ))
+}
+
+/// Compute `base^exp % modulus` for a modexp input with the given header lengths
+///
+/// The operands follow the 96-byte header; missing input bytes read as zero.
+/// The result is left-padded to `mod_len` bytes and all zeros when the
+/// modulus is zero. Only the result is allocated at a declared length, so
+/// callers must bound `mod_len`; operands never take more than `input`.
+pub fn modexp_output(input: &[u8], base_len: u64, exp_len: u64, mod_len: u64) -> Vec<u8> {
+    if mod_len == 0 {
+        return vec![];
+    }
+
+    let exp_offset = 96u64.saturating_add(base_len);
+    let mod_offset = exp_offset.saturating_add(exp_len);
+    let mut output = vec![0u8; mod_len as usize];
+
+    // Zero padding past the end of the input only shifts the modulus left,
+    // and a non-zero modulus means the base and exponent are fully present
+    let (modulus, modulus_padding) = operand_bytes(input, mod_offset, mod_len);
+    let modulus = BigUint::from_bytes_be(modulus);
+    if modulus.is_zero() {
+        return output;
+    }
+    let modulus = modulus << (8 * modulus_padding);
+
+    let base = BigUint::from_bytes_be(operand_bytes(input, 96, base_len).0);
+    let exponent = BigUint::from_bytes_be(operand_bytes(input, exp_offset, exp_len).0);
+    let result = base.modpow(&exponent, &modulus).to_bytes_be();
+    output[mod_len as usize - result.len()..].copy_from_slice(&result);
+    output
+}
+
+/// Split the operand of `length` bytes at `offset` into the bytes of `input`
+/// it covers and the number of zero bytes padding it past the end of `input`
+fn operand_bytes(input: &[u8], offset: u64, length: u64) -> (&[u8], u64) {
+    let start = usize::try_from(offset).map_or(input.len(), |offset| offset.min(input.len()));
+    let present = ((input.len() - start) as u64).min(length);
+    (&input[start..start + present as usize], length - present)
+}
+
+/// Read a 32-byte big-endian length word at `offset`
+/// Returns None if the length does not fit in a u64
+fn read_length(input: &[u8], offset: u64) -> Option<u64> {
+    let word = read_padded(input, offset, 32);
+    if word[..24].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(u64::from_be_bytes(word[24..].try_into().unwrap()))
+}
+
+/// Read `length` bytes of `input` starting at `offset`, zero-padding past its end
+fn read_padded(input: &[u8], offset: u64, length: u64) -> Vec<u8> {
+    let mut bytes = vec![0u8; length as usize];
+    if let Some(available) = usize::try_from(offset)
+        .ok()
+        .and_then(|offset| input.get(offset..))
+    {
+        let copied = available.len().min(bytes.len());
+        bytes[..copied].copy_from_slice(&available[..copied]);
+    }
+    bytes
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serde helpers encoding byte fields as 0x-prefixed hex strings
+//!
+//! Use `#[serde(with = "serde_hex")]` on `[u8; N]` and `Vec<u8>` fields,
+//! `serde_hex::seq` on sequences of them and `serde_hex::option` on optional ones.
+//! Deserialization accepts hex with or without the `0x` prefix.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Byte containers that can be encoded as hex
+pub trait HexBytes: Sized {
+    /// Bytes to encode
+    fn as_hex_bytes(&self) -> &[u8];
+    /// Rebuild the container from decoded bytes
+    fn from_hex_bytes(bytes: Vec<u8>) -> Result<Self, String>;
+}
+
+impl HexBytes for Vec<u8> {
+    fn as_hex_bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn from_hex_bytes(bytes: Vec<u8>) -> Result<Self, String> {
+        Ok(bytes)
+    }
+}
+
+impl<const N: usize> HexBytes for [u8; N] {
+    fn as_hex_bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn from_hex_bytes(bytes: Vec<u8>) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|bytes: Vec<u8>| format!("expected {} bytes, got {}", N, bytes.len()))
+    }
+}
+
+fn encode<T: HexBytes>(value: &T) -> String {
+    format!("0x{}", hex::encode(value.as_hex_bytes()))
+}
+
+fn decode<T: HexBytes, E: Error>(value: &str) -> Result<T, E> {
+    let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| E::custom(format!("invalid hex '{}': {}", value, e)))?;
+    T::from_hex_bytes(bytes).map_err(E::custom)
+}
+
+/// Serialize bytes as a 0x-prefixed hex string
+pub fn serialize<T: HexBytes, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&encode(value))
+}
+
+/// Deserialize bytes from a hex string
+pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    decode(&String::deserialize(deserializer)?)
+}
+
+/// Sequences of byte containers, such as `Vec<[u8; 32]>`
+pub mod seq {
+    use super::*;
+
+    /// Serialize each element as a 0x-prefixed hex string
+    pub fn serialize<'a, C, T, S>(values: &'a C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a C: IntoIterator<Item = &'a T>,
+        T: HexBytes + 'a,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.into_iter().map(encode))
+    }
+
+    /// Deserialize a sequence of hex strings
+    pub fn deserialize<'de, C, T, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: FromIterator<T>,
+        T: HexBytes,
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|value| decode(value))
+            .collect()
+    }
+}
+
+/// Optional byte containers, such as `Option<[u8; 20]>`
+pub mod option {
+    use super::*;
+
+    /// Serialize the bytes, if any, as a 0x-prefixed hex string
+    pub fn serialize<T: HexBytes, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&encode(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional hex string
+    pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| decode(&value))
+            .transpose()
+    }
+}
@@ -8,29 +8,38 @@
 //! and allow users to integrate with their own blockchain nodes, databases,
 //! or testing environments.
 
+use crate::evm::error::HostFunctionResult;
+use crate::evm::gas::GasCosts;
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
+use ripemd::Ripemd160;
 use sha2::{Digest, Sha256};
-use sha3::Keccak256;
+use sha3::{Keccak256, Keccak512};
 
 /// Log event emitted by a contract
 /// Represents an EVM log entry with contract address, data, and topics
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LogEvent {
     /// Address of the contract that emitted the event
+    #[cfg_attr(feature = "serde", serde(with = "crate::evm::serde_hex"))]
     pub contract_address: [u8; 20],
     /// Event data (arbitrary bytes)
+    #[cfg_attr(feature = "serde", serde(with = "crate::evm::serde_hex"))]
     pub data: Vec<u8>,
     /// Event topics (up to 4 topics, each 32 bytes)
+    #[cfg_attr(feature = "serde", serde(with = "crate::evm::serde_hex::seq"))]
     pub topics: Vec<[u8; 32]>,
 }
 
 /// Result of a contract call operation
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContractCallResult {
     /// Whether the call succeeded (true) or failed (false)
     pub success: bool,
     /// Return data from the call
+    #[cfg_attr(feature = "serde", serde(with = "crate::evm::serde_hex"))]
     pub return_data: Vec<u8>,
     /// Gas used by the call
     pub gas_used: i64,
@@ -68,12 +77,15 @@ impl ContractCallResult {
 
 /// Result of a contract creation operation
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContractCreateResult {
     /// Whether the creation succeeded (true) or failed (false)
     pub success: bool,
     /// Address of the created contract (if successful)
+    #[cfg_attr(feature = "serde", serde(with = "crate::evm::serde_hex::option"))]
     pub contract_address: Option<[u8; 20]>,
     /// Return data from the constructor
+    #[cfg_attr(feature = "serde", serde(with = "crate::evm::serde_hex"))]
     pub return_data: Vec<u8>,
     /// Gas used by the creation
     pub gas_used: i64,
@@ -106,6 +118,37 @@ impl ContractCreateResult {
     }
 }
 
+/// Ethereum hardforks that change host function behavior
+/// Ordered chronologically, so forks can be compared with `<` and `>=`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Hardfork {
+    Frontier,
+    Homestead,
+    Byzantium,
+    Constantinople,
+    Istanbul,
+    Berlin,
+    /// Adds the block base fee (EIP-1559)
+    London,
+    /// The Merge: DIFFICULTY becomes PREVRANDAO
+    Paris,
+    Shanghai,
+    /// Adds transient storage (EIP-1153) and blobs (EIP-4844, EIP-7516)
+    #[default]
+    Cancun,
+}
+
+/// Entry point a contract dispatched a call to, as reported by `recordDispatch`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dispatch {
+    /// A function matched by its 4-byte selector
+    Function([u8; 4]),
+    /// `fallback()`: the call data matched no selector
+    Fallback,
+    /// `receive()`: a plain value transfer with empty call data
+    Receive,
+}
+
 /// Convert a BigUint to a 32-byte array (big-endian, zero-padded)
 /// This ensures the result fits in exactly 32 bytes as required by EVM
 pub fn bigint_to_bytes32(value: &BigUint) -> [u8; 32] {
@@ -126,6 +169,91 @@ pub fn bigint_to_bytes32(value: &BigUint) -> [u8; 32] {
     result
 }
 
+/// A value that does not fit in 256 bits
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArithmeticOverflow {
+    /// Bit length of the value that overflowed
+    pub bits: u64,
+}
+
+impl std::fmt::Display for ArithmeticOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value of {} bits does not fit in 256 bits", self.bits)
+    }
+}
+
+impl std::error::Error for ArithmeticOverflow {}
+
+/// Convert a BigUint to a 32-byte array (big-endian, zero-padded)
+/// Unlike `bigint_to_bytes32`, values larger than 256 bits are an error instead of being truncated
+pub fn bigint_to_bytes32_checked(value: &BigUint) -> Result<[u8; 32], ArithmeticOverflow> {
+    if value.bits() > 256 {
+        return Err(ArithmeticOverflow { bits: value.bits() });
+    }
+    Ok(bigint_to_bytes32(value))
+}
+
+/// keccak256 of empty input, the code hash of an existing account without code (EIP-1052)
+pub const EMPTY_CODE_HASH: [u8; 32] = [
+    0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0,
+    0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85, 0xa4, 0x70,
+];
+
+/// Compute a Keccak-256 hash using the sha3 crate
+/// This is the default implementation of `EvmHost::keccak256`
+pub fn keccak256_digest(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Compute a CREATE contract address
+/// address = keccak256(rlp([sender, nonce]))[12:]
+pub fn create_address(sender: &[u8; 20], nonce: u64) -> [u8; 20] {
+    // RLP-encode the nonce as a minimal big-endian integer
+    let nonce_bytes = nonce.to_be_bytes();
+    let first_nonzero = nonce_bytes.iter().position(|&b| b != 0).unwrap_or(8);
+    let nonce_bytes = &nonce_bytes[first_nonzero..];
+
+    let mut encoded_nonce = Vec::with_capacity(9);
+    match nonce_bytes {
+        [] => encoded_nonce.push(0x80),
+        [b] if *b < 0x80 => encoded_nonce.push(*b),
+        _ => {
+            encoded_nonce.push(0x80 + nonce_bytes.len() as u8);
+            encoded_nonce.extend_from_slice(nonce_bytes);
+        }
+    }
+
+    // The list payload (21-byte address + at most 9-byte nonce) is always short
+    let payload_len = 21 + encoded_nonce.len();
+
+    let mut hasher = Keccak256::new();
+    hasher.update([0xc0 + payload_len as u8, 0x80 + 20]);
+    hasher.update(sender);
+    hasher.update(&encoded_nonce);
+    let hash: [u8; 32] = hasher.finalize().into();
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Compute a CREATE2 contract address (EIP-1014)
+/// address = keccak256(0xff ++ sender ++ salt ++ init_code_hash)[12:]
+pub fn create2_address(sender: &[u8; 20], salt: &[u8; 32], init_code_hash: &[u8; 32]) -> [u8; 20] {
+    let mut hasher = Keccak256::new();
+    hasher.update([0xffu8]);
+    hasher.update(sender);
+    hasher.update(salt);
+    hasher.update(init_code_hash);
+    let hash: [u8; 32] = hasher.finalize().into();
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
 /// Unified EVM Host Interface (EVMC-compatible)
 ///
 /// This trait consolidates all EVM host functions into a single interface,
@@ -169,7 +297,17 @@ pub trait EvmHost {
     /// Get the chain ID
     fn get_chain_id(&self) -> &[u8; 32];
 
-    /// Get the remaining gas for execution
+    /// Check whether the chain ID equals `expected` (EIP-155 replay protection)
+    fn verify_chain_id(&self, expected: u64) -> bool {
+        let mut expected_word = [0u8; 32];
+        expected_word[24..].copy_from_slice(&expected.to_be_bytes());
+        *self.get_chain_id() == expected_word
+    }
+
+    /// Get the remaining gas as observed by the contract (GAS opcode)
+    /// `gas_left` is the gas the runtime reports for the instance. Hosts that
+    /// reserve gas for themselves (e.g. a stipend) subtract it here; the result
+    /// should not exceed `gas_left` or be negative
     fn get_gas_left(&self, gas_left: i64) -> i64;
 
     /// Get the current block gas limit
@@ -190,6 +328,17 @@ pub trait EvmHost {
     /// Load a 32-byte value from contract storage at the given 32-byte key (SLOAD)
     fn storage_load(&self, key: &[u8; 32]) -> [u8; 32];
 
+    /// Store a 32-byte value at a 32-byte key in transient storage (TSTORE, EIP-1153)
+    /// Transient storage is discarded at the end of each transaction
+    /// Does nothing by default, for hosts without transient storage
+    fn transient_store(&self, _key: &[u8; 32], _value: &[u8; 32]) {}
+
+    /// Load a 32-byte value from transient storage (TLOAD, EIP-1153)
+    /// Unset keys read as zero, and so does every key by default
+    fn transient_load(&self, _key: &[u8; 32]) -> [u8; 32] {
+        [0u8; 32]
+    }
+
     /// Add an event to the event log
     fn emit_log_event(&self, event: LogEvent);
 
@@ -201,9 +350,28 @@ pub trait EvmHost {
         self.code_copy().len() as i32
     }
 
+    /// Get the keccak256 hash of the executing contract's runtime code
+    /// Hosts that keep init and runtime code apart must hash the runtime code
+    fn get_self_code_hash(&self) -> [u8; 32] {
+        self.keccak256(self.code_copy().to_vec())
+    }
+
+    /// Get the immutable variable at `index` of the current contract
+    /// Returns None when the host does not provide it; by default no immutables are set
+    fn get_immutable(&self, _index: u32) -> Option<[u8; 32]> {
+        None
+    }
+
     /// Get the current block's base fee
     fn get_base_fee(&self) -> &[u8; 32];
 
+    /// Get the base fee of the block `blocks_ago` blocks before the current one
+    /// Returns None outside the history the host keeps; by default only the current
+    /// base fee (0 blocks ago) is known
+    fn get_base_fee_at(&self, blocks_ago: u64) -> Option<[u8; 32]> {
+        (blocks_ago == 0).then(|| *self.get_base_fee())
+    }
+
     /// Get the current block's blob base fee
     fn get_blob_base_fee(&self) -> &[u8; 32];
 
@@ -213,9 +381,114 @@ pub trait EvmHost {
     /// Get the transaction gas price
     fn get_tx_gas_price(&self) -> &[u8; 32];
 
+    /// Get the transaction's max fee per gas
+    /// Defaults to the gas price, which is the max fee of a legacy transaction
+    fn get_tx_max_fee_per_gas(&self) -> &[u8; 32] {
+        self.get_tx_gas_price()
+    }
+
+    /// Check whether the transaction's max fee per gas covers the block base fee
+    /// Returns false when the transaction could not be included in the current block
+    fn can_afford_base_fee(&self) -> bool {
+        // Both values are 32-byte big-endian, so byte-wise comparison is numeric
+        self.get_tx_max_fee_per_gas() >= self.get_base_fee()
+    }
+
+    /// Get the blob versioned hashes attached to the transaction (EIP-4844)
+    fn get_blob_hashes(&self) -> &[[u8; 32]] {
+        &[]
+    }
+
+    /// Get the blob versioned hash at `index`, or None when out of range
+    fn get_blob_hash(&self, index: u64) -> Option<[u8; 32]> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.get_blob_hashes().get(index))
+            .copied()
+    }
+
+    /// Add to the transaction's gas refund counter
+    fn add_gas_refund(&self, _amount: u64) {}
+
+    /// Subtract from the transaction's gas refund counter
+    /// Net-metered SSTORE takes back refunds when a cleared slot is set again
+    fn remove_gas_refund(&self, _amount: u64) {}
+
+    /// Get the transaction's accumulated gas refund
+    fn get_gas_refund(&self) -> u64 {
+        0
+    }
+
+    /// Charge the host's own gas for a call to the host function `name`
+    /// Called first by the storage, crypto and call host functions; failing with a
+    /// gas error aborts the host function. The default charges nothing
+    fn charge_host_gas(&self, _name: &str) -> HostFunctionResult<()> {
+        Ok(())
+    }
+
+    /// Storage gas schedule for net-metered SSTORE (EIP-2200)
+    /// None (the default) disables SSTORE gas; clearing a slot is still refunded
+    fn sstore_gas_costs(&self) -> Option<GasCosts> {
+        None
+    }
+
+    /// Value of `key` in the current contract's storage at the start of the transaction
+    /// Hosts that do not track it report the current value
+    fn original_storage_value(&self, key: &[u8; 32]) -> [u8; 32] {
+        self.storage_load(key)
+    }
+
+    /// Whether copy host functions charge EVM memory-expansion gas
+    fn charges_memory_expansion(&self) -> bool {
+        false
+    }
+
+    /// Get the highest memory size (in bytes) touched by copy operations
+    fn get_memory_size(&self) -> u64 {
+        0
+    }
+
+    /// Record the highest memory size (in bytes) touched by copy operations
+    fn set_memory_size(&self, _size: u64) {}
+
+    /// Whether address-returning host functions write 32-byte left-padded words
+    /// instead of raw 20-byte addresses
+    fn pads_address_results(&self) -> bool {
+        false
+    }
+
+    /// Whether call host functions charge EIP-2929 account-access gas
+    fn charges_account_access(&self) -> bool {
+        false
+    }
+
+    /// Mark an account as accessed in the current transaction
+    /// Returns true if the account was cold (not accessed before)
+    fn mark_account_warm(&self, _address: &[u8; 20]) -> bool {
+        false
+    }
+
+    /// Access an account (EIP-2929/2930), marking it warm
+    /// Returns true if the account was already warm before this access
+    fn access_account(&self, address: &[u8; 20]) -> bool {
+        !self.mark_account_warm(address)
+    }
+
+    /// Access a storage slot of an account (EIP-2929/2930), marking it warm
+    /// Returns true if the slot was already warm before this access
+    fn access_storage(&self, _address: &[u8; 20], _key: &[u8; 32]) -> bool {
+        true
+    }
+
     /// Get the balance for an account address
     fn get_external_balance(&self, address: &[u8; 20]) -> [u8; 32];
 
+    /// Get the balance of the current contract (SELFBALANCE)
+    /// Defaults to looking up the contract's own address
+    fn get_self_balance(&self) -> [u8; 32] {
+        self.get_external_balance(self.get_address())
+    }
+
     /// Get the size of an external contract's code
     fn get_external_code_size(&self, address: &[u8; 20]) -> Option<i32>;
 
@@ -228,10 +501,42 @@ pub trait EvmHost {
     /// Get the current block's previous randao
     fn get_block_prev_randao(&self) -> &[u8; 32];
 
+    /// Get the current block's proof-of-work difficulty (pre-Merge blocks only)
+    fn get_block_difficulty(&self) -> &[u8; 32] {
+        self.get_block_prev_randao()
+    }
+
+    /// Override the block timestamp for the rest of the execution (cheatcodes)
+    fn set_block_timestamp(&self, _timestamp: i64) {}
+
+    /// Override the block number for the rest of the execution (cheatcodes)
+    fn set_block_number(&self, _number: i64) {}
+
+    /// Override the caller of the next contract call made by this frame (cheatcodes)
+    fn set_prank(&self, _caller: &[u8; 20]) {}
+
+    /// Set the balance of an account (cheatcodes)
+    fn set_balance(&self, _address: &[u8; 20], _balance: &[u8; 32]) {}
+
+    /// Receive a WASM memory region dumped by the contract (debug)
+    /// Does nothing by default; test hosts may capture or print it
+    fn dump_memory(&self, _offset: u32, _data: &[u8]) {}
+
+    /// Record which entry point the contract dispatched the current call to (debugging)
+    fn record_dispatch(&self, _dispatch: Dispatch) {}
+
+    /// Get the hardfork whose rules apply to the current block
+    fn get_hardfork(&self) -> Hardfork {
+        Hardfork::default()
+    }
+
     /// Self-destruct the current contract and transfer balance to recipient
+    /// Returns the amount transferred, which is the contract's balance before the
+    /// call; the recipient's balance increases by exactly this amount
     fn self_destruct(&self, recipient: &[u8; 20]) -> [u8; 32];
 
     /// Execute a regular contract call (CALL opcode)
+    /// `caller` is the address of the calling contract, the callee's msg.sender
     fn call_contract(
         &self,
         target: &[u8; 20],
@@ -242,6 +547,7 @@ pub trait EvmHost {
     ) -> ContractCallResult;
 
     /// Execute a call code operation (CALLCODE opcode)
+    /// `caller` is the address of the calling contract, the callee's msg.sender
     fn call_code(
         &self,
         target: &[u8; 20],
@@ -252,6 +558,8 @@ pub trait EvmHost {
     ) -> ContractCallResult;
 
     /// Execute a delegate call (DELEGATECALL opcode)
+    /// The target's code runs with the calling frame's address, value and msg.sender;
+    /// `caller` is that msg.sender, not the address of the calling contract
     fn call_delegate(
         &self,
         target: &[u8; 20],
@@ -261,6 +569,7 @@ pub trait EvmHost {
     ) -> ContractCallResult;
 
     /// Execute a static call (STATICCALL opcode)
+    /// `caller` is the address of the calling contract, the callee's msg.sender
     fn call_static(
         &self,
         target: &[u8; 20],
@@ -296,6 +605,18 @@ pub trait EvmHost {
     /// Set execution status to invalid
     fn invalid(&self);
 
+    /// Whether the current call frame is static (STATICCALL)
+    /// State-modifying host functions fail when this returns true
+    fn is_static(&self) -> bool {
+        false
+    }
+
+    /// Largest log data payload (in bytes) a contract may emit
+    /// Checked before the data is copied out of WASM memory
+    fn max_log_data(&self) -> usize {
+        crate::evm::utils::MAX_BUFFER_SIZE as usize
+    }
+
     fn sha256(&self, input_data: Vec<u8>) -> [u8; 32] {
         // Compute SHA256 hash using the sha2 crate
         let mut hasher = Sha256::new();
@@ -303,71 +624,128 @@ pub trait EvmHost {
         hasher.finalize().into()
     }
 
+    /// Compute the Keccak-256 hash of `input_data`
+    ///
+    /// Merkle proof verification and code hashing go through this. It hashes
+    /// with `keccak256_slice`, so overriding that one method covers every path.
     fn keccak256(&self, input_data: Vec<u8>) -> [u8; 32] {
-        // Compute Keccak256 hash using the sha3 crate
-        let mut hasher = Keccak256::new();
+        self.keccak256_slice(&input_data)
+    }
+
+    /// Compute the Keccak-256 hash of `input_data` borrowed from contract memory
+    ///
+    /// Keccak dominates the cost of EVM crypto operations. Hosts can override
+    /// this to plug in an accelerated implementation; `keccak256` and the
+    /// KECCAK256 host function both hash through it.
+    fn keccak256_slice(&self, input_data: &[u8]) -> [u8; 32] {
+        keccak256_digest(input_data)
+    }
+
+    /// Compute the RIPEMD-160 hash of `input_data` (the 0x03 precompile)
+    fn ripemd160(&self, input_data: Vec<u8>) -> [u8; 20] {
+        let mut hasher = Ripemd160::new();
         hasher.update(&input_data);
         hasher.finalize().into()
     }
-    fn addmod(&self, a_bytes: [u8; 32], b_bytes: [u8; 32], n_bytes: [u8; 32]) -> [u8; 32] {
-        // Convert bytes to BigUint (big-endian)
-        let a = BigUint::from_bytes_be(&a_bytes);
-        let b = BigUint::from_bytes_be(&b_bytes);
-        let n = BigUint::from_bytes_be(&n_bytes);
-
-        // Handle special case: if n is zero, return zero (EVM behavior)
-        let result = if n.is_zero() {
-            BigUint::zero()
-        } else {
-            (&a + &b) % &n
-        };
 
+    /// Compute the Keccak-512 hash of `input_data`
+    fn keccak512(&self, input_data: Vec<u8>) -> [u8; 64] {
+        let mut hasher = Keccak512::new();
+        hasher.update(&input_data);
+        hasher.finalize().into()
+    }
+
+    /// Recover the address that signed `hash` with the ECDSA signature (v, r, s)
+    /// `v` must be 27 or 28; returns None if the signature is invalid
+    fn ec_recover(&self, hash: &[u8; 32], v: u8, r: &[u8; 32], s: &[u8; 32]) -> Option<[u8; 20]> {
+        crate::evm::precompiles::ecrecover(hash, v, r, s)
+    }
+
+    /// Verify a Merkle proof that `leaf` is part of the tree with root `root`
+    /// Each pair is hashed with keccak256 in sorted order (OpenZeppelin `MerkleProof` convention)
+    fn verify_merkle_proof(&self, leaf: [u8; 32], proof: &[[u8; 32]], root: [u8; 32]) -> bool {
+        let computed = proof.iter().fold(leaf, |hash, sibling| {
+            let (first, second) = if hash <= *sibling {
+                (hash, *sibling)
+            } else {
+                (*sibling, hash)
+            };
+            self.keccak256([first, second].concat())
+        });
+        computed == root
+    }
+    fn addmod(&self, a_bytes: [u8; 32], b_bytes: [u8; 32], n_bytes: [u8; 32]) -> [u8; 32] {
         // Convert result back to 32-byte array (big-endian, zero-padded)
-        bigint_to_bytes32(&result)
+        bigint_to_bytes32(&addmod_u256(a_bytes, b_bytes, n_bytes))
     }
 
     fn mulmod(&self, a_bytes: [u8; 32], b_bytes: [u8; 32], n_bytes: [u8; 32]) -> [u8; 32] {
-        // Convert bytes to BigUint (big-endian)
-        let a = BigUint::from_bytes_be(&a_bytes);
-        let b = BigUint::from_bytes_be(&b_bytes);
-        let n = BigUint::from_bytes_be(&n_bytes);
-
-        // Handle special case: if n is zero, return zero (EVM behavior)
-        let result = if n.is_zero() {
-            BigUint::zero()
-        } else {
-            (&a * &b) % &n
-        };
-
         // Convert result back to 32-byte array (big-endian, zero-padded)
-        bigint_to_bytes32(&result)
+        bigint_to_bytes32(&mulmod_u256(a_bytes, b_bytes, n_bytes))
     }
 
     fn expmod(&self, base_bytes: [u8; 32], exp_bytes: [u8; 32], mod_bytes: [u8; 32]) -> [u8; 32] {
-        // Convert bytes to BigUint (big-endian)
-        let base = BigUint::from_bytes_be(&base_bytes);
-        let exponent = BigUint::from_bytes_be(&exp_bytes);
-        let modulus = BigUint::from_bytes_be(&mod_bytes);
-
-        // Handle special cases according to EVM specification
-        let result = if modulus.is_zero() {
-            // If modulus is 0, return 0 (EVM behavior)
-            BigUint::zero()
-        } else if modulus.is_one() {
-            // If modulus is 1, result is always 0
-            BigUint::zero()
-        } else if exponent.is_zero() {
-            // If exponent is 0, result is always 1 (including 0^0 = 1)
-            BigUint::one()
-        } else if base.is_zero() {
-            // If base is 0 and exponent > 0, result is 0
-            BigUint::zero()
-        } else {
-            // Perform modular exponentiation using the built-in efficient algorithm
-            base.modpow(&exponent, &modulus)
-        };
         // Convert result back to 32-byte array (big-endian, zero-padded)
-        bigint_to_bytes32(&result)
+        bigint_to_bytes32(&expmod_u256(base_bytes, exp_bytes, mod_bytes))
+    }
+}
+
+/// Modular addition (a + b) % n of 32-byte big-endian operands
+/// Returns zero when n is zero (EVM behavior)
+pub fn addmod_u256(a_bytes: [u8; 32], b_bytes: [u8; 32], n_bytes: [u8; 32]) -> BigUint {
+    // Convert bytes to BigUint (big-endian)
+    let a = BigUint::from_bytes_be(&a_bytes);
+    let b = BigUint::from_bytes_be(&b_bytes);
+    let n = BigUint::from_bytes_be(&n_bytes);
+
+    // Handle special case: if n is zero, return zero (EVM behavior)
+    if n.is_zero() {
+        BigUint::zero()
+    } else {
+        (&a + &b) % &n
+    }
+}
+
+/// Modular multiplication (a * b) % n of 32-byte big-endian operands
+/// Returns zero when n is zero (EVM behavior)
+pub fn mulmod_u256(a_bytes: [u8; 32], b_bytes: [u8; 32], n_bytes: [u8; 32]) -> BigUint {
+    // Convert bytes to BigUint (big-endian)
+    let a = BigUint::from_bytes_be(&a_bytes);
+    let b = BigUint::from_bytes_be(&b_bytes);
+    let n = BigUint::from_bytes_be(&n_bytes);
+
+    // Handle special case: if n is zero, return zero (EVM behavior)
+    if n.is_zero() {
+        BigUint::zero()
+    } else {
+        (&a * &b) % &n
+    }
+}
+
+/// Modular exponentiation (base ^ exp) % modulus of 32-byte big-endian operands
+/// Returns zero when the modulus is zero (EVM behavior)
+pub fn expmod_u256(base_bytes: [u8; 32], exp_bytes: [u8; 32], mod_bytes: [u8; 32]) -> BigUint {
+    // Convert bytes to BigUint (big-endian)
+    let base = BigUint::from_bytes_be(&base_bytes);
+    let exponent = BigUint::from_bytes_be(&exp_bytes);
+    let modulus = BigUint::from_bytes_be(&mod_bytes);
+
+    // Handle special cases according to EVM specification
+    if modulus.is_zero() {
+        // If modulus is 0, return 0 (EVM behavior)
+        BigUint::zero()
+    } else if modulus.is_one() {
+        // If modulus is 1, result is always 0
+        BigUint::zero()
+    } else if exponent.is_zero() {
+        // If exponent is 0, result is always 1 (including 0^0 = 1)
+        BigUint::one()
+    } else if base.is_zero() {
+        // If base is 0 and exponent > 0, result is 0
+        BigUint::zero()
+    } else {
+        // Perform modular exponentiation using the built-in efficient algorithm
+        base.modpow(&exponent, &modulus)
     }
 }
 
@@ -537,6 +915,61 @@ mod tests {
         assert_eq!(small_bytes, expected_small);
     }
 
+    #[test]
+    fn test_bigint_to_bytes32_checked() {
+        let max_value = BigUint::from_bytes_be(&[0xFFu8; 32]);
+        assert_eq!(bigint_to_bytes32_checked(&max_value), Ok([0xFFu8; 32]));
+
+        // 2^256 needs 257 bits: the checked variant errors, the default wraps to zero
+        let too_wide = BigUint::one() << 256;
+        assert_eq!(
+            bigint_to_bytes32_checked(&too_wide),
+            Err(ArithmeticOverflow { bits: 257 })
+        );
+        assert_eq!(bigint_to_bytes32(&too_wide), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_create_address() {
+        let sender: [u8; 20] = hex::decode("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(
+            hex::encode(create_address(&sender, 0)),
+            "cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"
+        );
+        assert_eq!(
+            hex::encode(create_address(&sender, 1)),
+            "343c43a37d37dff08ae8c4a11544c718abb4fcf8"
+        );
+        assert_eq!(
+            hex::encode(create_address(&sender, 2)),
+            "f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"
+        );
+    }
+
+    #[test]
+    fn test_create2_address() {
+        // EIP-1014 example vectors with init_code = 0x00
+        let init_code_hash: [u8; 32] = Keccak256::digest([0x00u8]).into();
+
+        let address = create2_address(&[0u8; 20], &[0u8; 32], &init_code_hash);
+        assert_eq!(
+            hex::encode(address),
+            "4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"
+        );
+
+        let mut sender = [0u8; 20];
+        sender[..4].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+        let address = create2_address(&sender, &[0u8; 32], &init_code_hash);
+        assert_eq!(
+            hex::encode(address),
+            "b928f69bb1d91cd65274e3c79d8986362984fda3"
+        );
+    }
+
     #[test]
     fn test_sha256_default_implementation() {
         let host = MockEvmHost;
@@ -582,6 +1015,7 @@ mod tests {
             0x5d, 0x85, 0xa4, 0x70,
         ];
         assert_eq!(empty_result, expected_empty);
+        assert_eq!(host.keccak256(vec![]), EMPTY_CODE_HASH);
 
         // Test "abc"
         let abc_result = host.keccak256(b"abc".to_vec());
@@ -591,6 +1025,7 @@ mod tests {
             0xa1, 0x2d, 0x6c, 0x45,
         ];
         assert_eq!(abc_result, expected_abc);
+        assert_eq!(host.keccak256_slice(b"abc"), expected_abc);
 
         // Test Ethereum function signature "transfer(address,uint256)"
         let transfer_result = host.keccak256(b"transfer(address,uint256)".to_vec());
@@ -602,6 +1037,15 @@ mod tests {
         assert_eq!(transfer_result, expected_transfer);
     }
 
+    #[test]
+    fn test_transient_storage_default_implementation() {
+        let host = MockEvmHost;
+
+        // Stores are dropped and every key loads as zero
+        host.transient_store(&u256_from_u8(1), &u256_from_u8(2));
+        assert_eq!(host.transient_load(&u256_from_u8(1)), [0u8; 32]);
+    }
+
     fn u256_from_u8(value: u8) -> [u8; 32] {
         let mut bytes = [0u8; 32];
         bytes[31] = value;
@@ -750,4 +1194,30 @@ mod tests {
         let expected_large = u256_from_u8(1);
         assert_eq!(large_result, expected_large);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_log_event_json_round_trip() {
+        let event = LogEvent {
+            contract_address: [0x42; 20],
+            data: vec![0xaa, 0xbb],
+            topics: vec![[0x11; 32], [0x22; 32]],
+        };
+
+        let json = serde_json::to_string(&event).expect("Failed to serialize event");
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["contract_address"], format!("0x{}", "42".repeat(20)));
+        assert_eq!(value["data"], "0xaabb");
+        assert_eq!(value["topics"][1], format!("0x{}", "22".repeat(32)));
+
+        let restored: LogEvent = serde_json::from_str(&json).expect("Failed to parse event");
+        assert_eq!(restored, event);
+
+        let created = ContractCreateResult::success([0x01; 20], vec![], 100);
+        let json = serde_json::to_string(&created).expect("Failed to serialize result");
+        assert_eq!(
+            serde_json::from_str::<ContractCreateResult>(&json).unwrap(),
+            created
+        );
+    }
 }
@@ -34,7 +34,11 @@
 //! - Memory corruption
 
 use crate::core::instance::ZenInstance;
-use crate::evm::error::{out_of_bounds_error, HostFunctionResult};
+use crate::evm::error::{
+    execution_error, out_of_bounds_error, static_violation_error, HostFunctionResult,
+};
+use crate::evm::traits::{bigint_to_bytes32, bigint_to_bytes32_checked, EvmHost, Hardfork};
+use num_bigint::BigUint;
 
 // ============================================================================
 // Memory Access Utilities
@@ -87,6 +91,27 @@ impl<'a, T> MemoryAccessor<'a, T> {
         Ok(())
     }
 
+    /// Write `data` followed by zeros up to `total_len` bytes in one bounds-checked pass
+    /// `data` is truncated if it is longer than `total_len`
+    pub fn write_bytes_padded(
+        &self,
+        offset: u32,
+        data: &[u8],
+        total_len: u32,
+    ) -> HostFunctionResult<()> {
+        if !self.validate_range(offset, total_len) {
+            return Err(out_of_bounds_error(offset, total_len, "write_bytes_padded"));
+        }
+
+        let copy_len = data.len().min(total_len as usize);
+        unsafe {
+            let ptr = self.instance.get_host_memory(offset);
+            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, copy_len);
+            std::ptr::write_bytes(ptr.add(copy_len), 0, total_len as usize - copy_len);
+        }
+        Ok(())
+    }
+
     /// Read a 32-byte value from memory
     pub fn read_bytes32(&self, offset: u32) -> HostFunctionResult<[u8; 32]> {
         let bytes = self.read_bytes(offset, 32)?;
@@ -113,6 +138,42 @@ impl<'a, T> MemoryAccessor<'a, T> {
         self.write_bytes(offset, data)
     }
 
+    /// Write a 20-byte address as a 32-byte word, left-padded with 12 zero bytes
+    pub fn write_address_word(&self, offset: u32, data: &[u8; 20]) -> HostFunctionResult<()> {
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(data);
+        self.write_bytes32(offset, &word)
+    }
+
+    /// Read a big-endian u64 from 8 bytes of memory
+    pub fn read_u64_be(&self, offset: u32) -> HostFunctionResult<u64> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.read_bytes(offset, 8)?);
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Read a little-endian u64 from 8 bytes of memory
+    pub fn read_u64_le(&self, offset: u32) -> HostFunctionResult<u64> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.read_bytes(offset, 8)?);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Write a u64 to 8 bytes of memory in big-endian order
+    pub fn write_u64_be(&self, offset: u32, value: u64) -> HostFunctionResult<()> {
+        self.write_bytes(offset, &value.to_be_bytes())
+    }
+
+    /// Read a 32-byte big-endian uint256 from memory
+    pub fn read_u256_be(&self, offset: u32) -> HostFunctionResult<BigUint> {
+        Ok(BigUint::from_bytes_be(&self.read_bytes32(offset)?))
+    }
+
+    /// Write a uint256 to memory as a 32-byte big-endian word, zero-padded on the left
+    pub fn write_u256_be(&self, offset: u32, value: &BigUint) -> HostFunctionResult<()> {
+        self.write_bytes32(offset, &bigint_to_bytes32(value))
+    }
+
     /// Read a variable-length byte array from memory
     pub fn read_bytes_vec(&self, offset: u32, length: u32) -> HostFunctionResult<Vec<u8>> {
         let bytes = self.read_bytes(offset, length)?;
@@ -131,6 +192,25 @@ impl<'a, T> MemoryAccessor<'a, T> {
     }
 }
 
+// ============================================================================
+// Value Utilities
+// ============================================================================
+
+/// Encode a u128 as a 32-byte big-endian value
+pub fn u128_to_bytes32(value: u128) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[16..32].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+/// Parse a decimal string of any precision into a 32-byte big-endian value
+/// Errors if the string is not a decimal number or the value exceeds 256 bits
+pub fn decimal_str_to_bytes32(s: &str) -> Result<[u8; 32], String> {
+    let value = BigUint::parse_bytes(s.as_bytes(), 10)
+        .ok_or_else(|| format!("invalid decimal number '{}'", s))?;
+    bigint_to_bytes32_checked(&value).map_err(|e| format!("'{}': {}", s, e))
+}
+
 // ============================================================================
 // Memory Validation Utilities
 // ============================================================================
@@ -240,6 +320,62 @@ pub fn validate_bytes32_param<T>(
     validate_offset_for_type(instance, offset, 32, "bytes32")
 }
 
+/// Validate the result offset of an address-returning host function and write the address
+/// Writes a 32-byte left-padded word if the host pads address results, else the raw 20 bytes
+pub fn write_address_result<T>(
+    instance: &ZenInstance<T>,
+    result_offset: i32,
+    address: &[u8; 20],
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let memory = MemoryAccessor::new(instance);
+
+    if instance.extra_ctx.pads_address_results() {
+        let offset = validate_bytes32_param(instance, result_offset)?;
+        memory.write_address_word(offset, address)
+    } else {
+        let offset = validate_address_param(instance, result_offset)?;
+        memory.write_address(offset, address)
+    }
+}
+
+/// Validate that the current call frame allows state modification
+/// Fails with a `StaticViolation` error inside a static call frame (STATICCALL)
+pub fn validate_not_static<T>(instance: &ZenInstance<T>, function: &str) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    if instance.extra_ctx.is_static() {
+        return Err(static_violation_error(function));
+    }
+    Ok(())
+}
+
+/// Validate that the active hardfork includes `introduced_in`
+/// Fails when a host function is called before the fork that introduced it
+pub fn validate_hardfork<T>(
+    instance: &ZenInstance<T>,
+    introduced_in: Hardfork,
+    function: &str,
+) -> HostFunctionResult<()>
+where
+    T: EvmHost,
+{
+    let hardfork = instance.extra_ctx.get_hardfork();
+    if hardfork < introduced_in {
+        return Err(execution_error(
+            &format!(
+                "not available before {:?} (active hardfork is {:?})",
+                introduced_in, hardfork
+            ),
+            function,
+        ));
+    }
+    Ok(())
+}
+
 /// Validate buffer size to prevent excessive memory allocation
 pub fn validate_buffer_size(length: u32, operation_name: &str) -> HostFunctionResult<()> {
     if length > MAX_BUFFER_SIZE {
@@ -311,3 +447,36 @@ pub fn validate_data_param<T>(
 
     Ok((offset_u32, length_u32))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u128_to_bytes32() {
+        let mut expected = [0u8; 32];
+        expected[16..].fill(0xff);
+        assert_eq!(u128_to_bytes32(u128::MAX), expected);
+        assert_eq!(u128_to_bytes32(0), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_decimal_str_to_bytes32() {
+        // 10^39 does not fit in a u128
+        let digits = format!("1{}", "0".repeat(39));
+        assert_eq!(digits.len(), 40);
+        let expected = bigint_to_bytes32(&BigUint::from(10u8).pow(39));
+        assert_eq!(decimal_str_to_bytes32(&digits), Ok(expected));
+
+        assert_eq!(
+            decimal_str_to_bytes32(&u128::MAX.to_string()),
+            Ok(u128_to_bytes32(u128::MAX))
+        );
+
+        // 2^256 is one past the largest 256-bit value
+        let overflow = (BigUint::from(1u8) << 256u32).to_string();
+        assert!(decimal_str_to_bytes32(&overflow).is_err());
+        assert!(decimal_str_to_bytes32("12ab").is_err());
+        assert!(decimal_str_to_bytes32("").is_err());
+    }
+}
@@ -9,8 +9,17 @@
 
 extern crate alloc;
 
-use alloc::{vec, vec::Vec};
-use core::{cmp::min, mem, num::NonZeroU32};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    cmp::min,
+    mem::{self, Discriminant},
+    num::NonZeroU32,
+};
 use parity_wasm::{
     builder,
     elements::{self, Instruction, ValueType},
@@ -37,6 +46,32 @@ pub trait Rules {
 
     /// A surcharge cost to calling a function that is added per local of that function.
     fn call_per_local_cost(&self) -> u32;
+
+    /// Returns the cost override for a `call` to the imported function named `name`.
+    ///
+    /// Returning `None` (the default) charges such calls like any other `call` instruction,
+    /// using `instruction_cost`.
+    fn import_call_cost(&self, _name: &str) -> Option<u32> {
+        None
+    }
+
+    /// Returns `true` if the passed `instruction` is banned outright.
+    ///
+    /// Unlike returning `None` from `instruction_cost`, this is meant for deployments that reject
+    /// whole classes of opcodes (e.g. floating point) regardless of their cost.
+    fn is_forbidden(&self, _instruction: &Instruction) -> bool {
+        false
+    }
+
+    /// Returns the `(module, field)` of an imported host function to charge gas through.
+    ///
+    /// When the module imports this function with the signature `(param i64)`, the metering
+    /// code calls it directly instead of a synthesized `__instrumented_use_gas` function, so the
+    /// host accounts all gas centrally. Returning `None` (the default), or a function the module
+    /// does not import, keeps the synthesized gas function.
+    fn gas_function_import(&self) -> Option<(&str, &str)> {
+        None
+    }
 }
 
 /// Dynamic costs for memory growth.
@@ -53,6 +88,17 @@ pub enum MemoryGrowCost {
     Free,
     /// Charge the specified amount for each page that the memory is grown by.
     Linear(NonZeroU32),
+    /// Charge `linear` per page plus a quadratic component on the total memory size.
+    ///
+    /// Memory of `n` pages costs `linear * n + n * n / quad_divisor`, and growing it charges
+    /// the difference between the cost after and before the growth, similar to EVM memory
+    /// expansion. Large memories therefore get increasingly expensive to grow.
+    Quadratic {
+        /// Cost per page grown.
+        linear: NonZeroU32,
+        /// Divisor of the squared memory size in pages.
+        quad_divisor: NonZeroU32,
+    },
 }
 
 impl MemoryGrowCost {
@@ -60,7 +106,7 @@ impl MemoryGrowCost {
     fn enabled(&self) -> bool {
         match self {
             Self::Free => false,
-            Self::Linear(_) => true,
+            Self::Linear(_) | Self::Quadratic { .. } => true,
         }
     }
 }
@@ -77,7 +123,10 @@ impl MemoryGrowCost {
 pub struct ConstantCostRules {
     instruction_cost: u32,
     memory_grow_cost: u32,
+    memory_grow_quad_divisor: u32,
     call_per_local_cost: u32,
+    import_call_costs: BTreeMap<String, u32>,
+    gas_function_import: Option<(String, String)>,
 }
 
 impl ConstantCostRules {
@@ -89,9 +138,36 @@ impl ConstantCostRules {
         Self {
             instruction_cost,
             memory_grow_cost,
+            memory_grow_quad_divisor: 0,
             call_per_local_cost,
+            import_call_costs: BTreeMap::new(),
+            gas_function_import: None,
         }
     }
+
+    /// Add a quadratic component to the memory growth cost.
+    ///
+    /// See [`MemoryGrowCost::Quadratic`]; has no effect while `memory_grow_cost` is `0`.
+    pub fn with_quadratic_memory_grow(mut self, quad_divisor: u32) -> Self {
+        self.memory_grow_quad_divisor = quad_divisor;
+        self
+    }
+
+    /// Charge `cost` for every `call` to the imported function named `name`.
+    ///
+    /// This models host functions that are more expensive than a regular instruction.
+    pub fn with_import_cost(mut self, name: &str, cost: u32) -> Self {
+        self.import_call_costs.insert(name.to_string(), cost);
+        self
+    }
+
+    /// Charge gas through the imported function `module.field` when the module imports it.
+    ///
+    /// See [`Rules::gas_function_import`].
+    pub fn with_gas_function_import(mut self, module: &str, field: &str) -> Self {
+        self.gas_function_import = Some((module.to_string(), field.to_string()));
+        self
+    }
 }
 
 impl Default for ConstantCostRules {
@@ -100,7 +176,10 @@ impl Default for ConstantCostRules {
         Self {
             instruction_cost: 1,
             memory_grow_cost: 0,
+            memory_grow_quad_divisor: 0,
             call_per_local_cost: 1,
+            import_call_costs: BTreeMap::new(),
+            gas_function_import: None,
         }
     }
 }
@@ -111,12 +190,250 @@ impl Rules for ConstantCostRules {
     }
 
     fn memory_grow_cost(&self) -> MemoryGrowCost {
-        NonZeroU32::new(self.memory_grow_cost).map_or(MemoryGrowCost::Free, MemoryGrowCost::Linear)
+        let Some(linear) = NonZeroU32::new(self.memory_grow_cost) else {
+            return MemoryGrowCost::Free;
+        };
+        match NonZeroU32::new(self.memory_grow_quad_divisor) {
+            Some(quad_divisor) => MemoryGrowCost::Quadratic {
+                linear,
+                quad_divisor,
+            },
+            None => MemoryGrowCost::Linear(linear),
+        }
     }
 
     fn call_per_local_cost(&self) -> u32 {
         self.call_per_local_cost
     }
+
+    fn import_call_cost(&self, name: &str) -> Option<u32> {
+        self.import_call_costs.get(name).copied()
+    }
+
+    fn gas_function_import(&self) -> Option<(&str, &str)> {
+        self.gas_function_import
+            .as_ref()
+            .map(|(module, field)| (module.as_str(), field.as_str()))
+    }
+}
+
+/// EVM gas tiers the [`EthereumCostRules`] schedule is built from.
+const EVM_JUMPDEST: u32 = 1;
+const EVM_BASE: u32 = 2;
+const EVM_VERY_LOW: u32 = 3;
+const EVM_LOW: u32 = 5;
+const EVM_MID: u32 = 8;
+const EVM_HIGH: u32 = 10;
+const EVM_WARM_ACCESS: u32 = 100;
+/// EVM memory costs 3 gas per 32-byte word, and a 64 KiB page holds 2048 words.
+const EVM_MEMORY_PAGE_COST: u32 = EVM_VERY_LOW * 2048;
+
+/// A type that implements [`Rules`] with per-opcode costs approximating the Ethereum
+/// mainnet gas schedule.
+///
+/// Each instruction is priced like its closest EVM counterpart: block markers like
+/// `JUMPDEST`, branches like `JUMP`/`JUMPI`, loads and stores like `MLOAD`/`MSTORE`,
+/// multiplication and division in the `LOW` tier and calls like a warm `CALL`. Everything
+/// else, including plain arithmetic, comparisons and constants, costs `VERYLOW` (3).
+///
+/// Memory growth is charged linearly at the EVM's 3 gas per word. The EVM's quadratic
+/// component is left out, as it cannot be expressed per 64 KiB page.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EthereumCostRules;
+
+impl EthereumCostRules {
+    /// Create a new [`EthereumCostRules`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Rules for EthereumCostRules {
+    fn instruction_cost(&self, instruction: &Instruction) -> Option<u32> {
+        use parity_wasm::elements::Instruction::*;
+
+        let cost = match instruction {
+            Nop | Block(_) | Loop(_) | Else | End => EVM_JUMPDEST,
+            Unreachable | Return | Drop | CurrentMemory(_) => EVM_BASE,
+            Br(_) => EVM_MID,
+            BrIf(_) | BrTable(_) | If(_) => EVM_HIGH,
+            Call(_) | CallIndirect(_, _) => EVM_WARM_ACCESS,
+            I32Mul | I32DivS | I32DivU | I32RemS | I32RemU | I64Mul | I64DivS | I64DivU
+            | I64RemS | I64RemU => EVM_LOW,
+            _ => EVM_VERY_LOW,
+        };
+        Some(cost)
+    }
+
+    fn memory_grow_cost(&self) -> MemoryGrowCost {
+        MemoryGrowCost::Linear(NonZeroU32::new(EVM_MEMORY_PAGE_COST).unwrap())
+    }
+
+    fn call_per_local_cost(&self) -> u32 {
+        // Locals start zeroed, like a `PUSH0` per local
+        EVM_BASE
+    }
+}
+
+/// A type that implements [`Rules`] by wrapping [`ConstantCostRules`] and rejecting a
+/// configurable set of opcodes.
+///
+/// Opcodes are matched by discriminant, so forbidding `F64Const(0)` forbids every `f64.const`
+/// regardless of its immediate.
+pub struct ForbiddenSet {
+    rules: ConstantCostRules,
+    forbidden: Vec<Discriminant<Instruction>>,
+}
+
+impl ForbiddenSet {
+    /// Create a new [`ForbiddenSet`] that forbids nothing yet.
+    pub fn new(rules: ConstantCostRules) -> Self {
+        Self {
+            rules,
+            forbidden: Vec::new(),
+        }
+    }
+
+    /// Forbid every instruction with the same opcode as `instruction`.
+    pub fn forbid(mut self, instruction: &Instruction) -> Self {
+        let discriminant = mem::discriminant(instruction);
+        if !self.forbidden.contains(&discriminant) {
+            self.forbidden.push(discriminant);
+        }
+        self
+    }
+}
+
+impl Rules for ForbiddenSet {
+    fn instruction_cost(&self, instruction: &Instruction) -> Option<u32> {
+        self.rules.instruction_cost(instruction)
+    }
+
+    fn memory_grow_cost(&self) -> MemoryGrowCost {
+        self.rules.memory_grow_cost()
+    }
+
+    fn call_per_local_cost(&self) -> u32 {
+        self.rules.call_per_local_cost()
+    }
+
+    fn import_call_cost(&self, name: &str) -> Option<u32> {
+        self.rules.import_call_cost(name)
+    }
+
+    fn is_forbidden(&self, instruction: &Instruction) -> bool {
+        self.forbidden.contains(&mem::discriminant(instruction))
+    }
+
+    fn gas_function_import(&self) -> Option<(&str, &str)> {
+        self.rules.gas_function_import()
+    }
+}
+
+/// Wraps a set of [`Rules`] and applies their import call cost overrides.
+///
+/// Overrides are resolved by import name once per module, so that a `call` instruction can be
+/// priced by the index of the function it targets.
+struct ImportCallRules<'a, R: Rules> {
+    rules: &'a R,
+    /// Cost override per imported function index.
+    import_call_costs: Vec<Option<u32>>,
+}
+
+impl<'a, R: Rules> ImportCallRules<'a, R> {
+    fn new(module: &elements::Module, rules: &'a R) -> Self {
+        let import_call_costs = module
+            .import_section()
+            .map(|import_section| {
+                import_section
+                    .entries()
+                    .iter()
+                    .filter(|entry| matches!(entry.external(), elements::External::Function(_)))
+                    .map(|entry| rules.import_call_cost(entry.field()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            rules,
+            import_call_costs,
+        }
+    }
+}
+
+impl<R: Rules> Rules for ImportCallRules<'_, R> {
+    fn instruction_cost(&self, instruction: &Instruction) -> Option<u32> {
+        if let Instruction::Call(func_idx) = instruction {
+            if let Some(Some(cost)) = self.import_call_costs.get(*func_idx as usize) {
+                return Some(*cost);
+            }
+        }
+        self.rules.instruction_cost(instruction)
+    }
+
+    fn memory_grow_cost(&self) -> MemoryGrowCost {
+        self.rules.memory_grow_cost()
+    }
+
+    fn call_per_local_cost(&self) -> u32 {
+        self.rules.call_per_local_cost()
+    }
+
+    fn is_forbidden(&self, instruction: &Instruction) -> bool {
+        self.rules.is_forbidden(instruction)
+    }
+
+    fn gas_function_import(&self) -> Option<(&str, &str)> {
+        self.rules.gas_function_import()
+    }
+}
+
+/// Returns the function index of the `module.field` import if it has the gas function
+/// signature `(param i64)`.
+pub(crate) fn find_gas_function_import(
+    module: &elements::Module,
+    import_module: &str,
+    import_field: &str,
+) -> Option<u32> {
+    let imports = module.import_section()?.entries();
+    let (func_idx, type_idx) = imports
+        .iter()
+        .filter_map(|entry| match entry.external() {
+            elements::External::Function(type_idx) => Some((entry, *type_idx)),
+            _ => None,
+        })
+        .enumerate()
+        .find(|(_, (entry, _))| entry.module() == import_module && entry.field() == import_field)
+        .map(|(func_idx, (_, type_idx))| (func_idx as u32, type_idx))?;
+
+    let elements::Type::Function(func_type) =
+        module.type_section()?.types().get(type_idx as usize)?;
+    (func_type.params() == [ValueType::I64] && func_type.results().is_empty()).then_some(func_idx)
+}
+
+/// Returns the first instruction in the module's code that `rules` forbid, if any.
+pub fn find_forbidden_instruction<'a, R: Rules>(
+    module: &'a elements::Module,
+    rules: &R,
+) -> Option<&'a Instruction> {
+    module.code_section().and_then(|code_section| {
+        code_section
+            .bodies()
+            .iter()
+            .flat_map(|body| body.code().elements())
+            .find(|instruction| rules.is_forbidden(instruction))
+    })
+}
+
+/// Statistics about the metering code injected into a module.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GasStats {
+    /// Number of metered blocks, i.e. calls to the gas function injected into function bodies.
+    pub metering_blocks: usize,
+    /// Sum of the static gas charges of all metered blocks.
+    pub total_static_cost: u64,
+    /// Number of `memory.grow` instructions redirected to the grow counter.
+    pub memory_grow_points: usize,
 }
 
 /// Transforms a given module into one that tracks the gas charged during its execution.
@@ -158,49 +475,70 @@ impl Rules for ConstantCostRules {
 ///
 /// This routine runs in time linear in the size of the input module.
 ///
-/// The function fails if the module contains any operation forbidden by gas rule set, returning
-/// the original module as an `Err`.
+/// Calls to imported functions for which [`Rules::import_call_cost`] returns an override are
+/// charged that override instead of the regular `call` instruction cost.
+///
+/// If [`Rules::gas_function_import`] names a gas function the module already imports, the
+/// metering calls target that import and no `__instrumented_use_gas` function is added.
+///
+/// On success the instrumented module is returned together with [`GasStats`] describing the
+/// injected metering code.
+///
+/// The function fails if the module contains any operation forbidden by gas rule set, either
+/// through [`Rules::is_forbidden`] or by having no cost, returning the original module as an `Err`.
 pub fn inject<R: Rules>(
     module: elements::Module,
     rules: &R,
-) -> Result<elements::Module, elements::Module> {
+) -> Result<(elements::Module, GasStats), elements::Module> {
+    let rules = &ImportCallRules::new(&module, rules);
     let functions_space = module.functions_space() as u32;
 
-    let mut mbuilder = builder::from_module(module.clone());
-
-    // Inject the export for `__instrumented_use_gas`
-    let gas_func_sig = builder::SignatureBuilder::new()
-        .with_param(ValueType::I64)
-        .build_sig();
-
-    let function = builder::FunctionBuilder::new()
-        .with_signature(gas_func_sig)
-        .body()
-        .with_instructions(elements::Instructions::new(vec![Instruction::End]))
-        .build()
-        .build();
-
-    // Inject local gas function
-    mbuilder.push_function(function);
-
-    // Inject the export entry for the gas counting function
-    let gas_func_idx = functions_space;
-    mbuilder.push_export(
-        builder::export()
-            .field("__instrumented_use_gas")
-            .internal()
-            .func(gas_func_idx)
-            .build(),
-    );
+    let gas_import = rules
+        .gas_function_import()
+        .and_then(|(import_module, import_field)| {
+            find_gas_function_import(&module, import_module, import_field)
+        });
+
+    let (mut resulting_module, gas_func_idx, total_func) = match gas_import {
+        // Charge gas through the host's own gas function; the function space is unchanged
+        Some(gas_func_idx) => (module.clone(), gas_func_idx, functions_space),
+        None => {
+            let mut mbuilder = builder::from_module(module.clone());
+
+            // Inject the export for `__instrumented_use_gas`
+            let gas_func_sig = builder::SignatureBuilder::new()
+                .with_param(ValueType::I64)
+                .build_sig();
+
+            let function = builder::FunctionBuilder::new()
+                .with_signature(gas_func_sig)
+                .body()
+                .with_instructions(elements::Instructions::new(vec![Instruction::End]))
+                .build()
+                .build();
+
+            // Inject local gas function
+            mbuilder.push_function(function);
+
+            // Inject the export entry for the gas counting function
+            let gas_func_idx = functions_space;
+            mbuilder.push_export(
+                builder::export()
+                    .field("__instrumented_use_gas")
+                    .internal()
+                    .func(gas_func_idx)
+                    .build(),
+            );
+
+            // We need the built the module for making injections to its blocks
+            (mbuilder.build(), gas_func_idx, functions_space + 1)
+        }
+    };
 
     // Gas function cost is 0 since it's an empty function and its cost is self-accounted.
     let gas_fn_cost = 0;
-    let total_func = functions_space + 1;
 
-    // We need the built the module for making injections to its blocks
-    let mut resulting_module = mbuilder.build();
-
-    let mut need_grow_counter = false;
+    let mut stats = GasStats::default();
     let mut result = Ok(());
     // Iterate over module sections and perform needed transformations.
     'outer: for section in resulting_module.sections_mut() {
@@ -208,7 +546,8 @@ pub fn inject<R: Rules>(
             elements::Section::Code(code_section) => {
                 // Don't inject counters to the gas function itself, which is the last one.
                 let len = code_section.bodies().len();
-                let injection_targets = &mut code_section.bodies_mut()[..len - 1];
+                let metered_len = if gas_import.is_some() { len } else { len - 1 };
+                let injection_targets = &mut code_section.bodies_mut()[..metered_len];
 
                 for func_body in injection_targets {
                     result = func_body
@@ -224,14 +563,19 @@ pub fn inject<R: Rules>(
                                 rules,
                                 gas_func_idx,
                             )
+                        })
+                        .and_then(|(blocks, cost)| {
+                            stats.metering_blocks += blocks;
+                            stats.total_static_cost =
+                                stats.total_static_cost.checked_add(cost).ok_or(())?;
+                            Ok(())
                         });
                     if result.is_err() {
                         break 'outer;
                     }
-                    if rules.memory_grow_cost().enabled()
-                        && inject_grow_counter(func_body.code_mut(), total_func) > 0
-                    {
-                        need_grow_counter = true;
+                    if rules.memory_grow_cost().enabled() {
+                        stats.memory_grow_points +=
+                            inject_grow_counter(func_body.code_mut(), total_func);
                     }
                 }
             }
@@ -243,10 +587,13 @@ pub fn inject<R: Rules>(
 
     result.map_err(|_| module)?;
 
-    if need_grow_counter {
-        Ok(add_grow_counter(resulting_module, rules, gas_func_idx))
+    if stats.memory_grow_points > 0 {
+        Ok((
+            add_grow_counter(resulting_module, rules, gas_func_idx),
+            stats,
+        ))
     } else {
-        Ok(resulting_module)
+        Ok((resulting_module, stats))
     }
 }
 
@@ -472,10 +819,58 @@ fn add_grow_counter<R: Rules>(
 ) -> elements::Module {
     use parity_wasm::elements::Instruction::*;
 
-    let cost = match rules.memory_grow_cost() {
+    // The pages to grow by stay on the stack for `memory.grow`; the charge is computed on top
+    let mut instructions = vec![GetLocal(0)];
+    match rules.memory_grow_cost() {
         MemoryGrowCost::Free => return module,
-        MemoryGrowCost::Linear(val) => val.get(),
-    };
+        MemoryGrowCost::Linear(val) => instructions.extend([
+            GetLocal(0),
+            I64ExtendUI32,
+            I64Const(i64::from(val.get())),
+            I64Mul,
+        ]),
+        MemoryGrowCost::Quadratic {
+            linear,
+            quad_divisor,
+        } => instructions.extend([
+            // linear * pages
+            GetLocal(0),
+            I64ExtendUI32,
+            I64Const(i64::from(linear.get())),
+            I64Mul,
+            // + new_size * new_size / quad_divisor
+            CurrentMemory(0),
+            I64ExtendUI32,
+            GetLocal(0),
+            I64ExtendUI32,
+            I64Add,
+            CurrentMemory(0),
+            I64ExtendUI32,
+            GetLocal(0),
+            I64ExtendUI32,
+            I64Add,
+            I64Mul,
+            I64Const(i64::from(quad_divisor.get())),
+            I64DivU,
+            I64Add,
+            // - old_size * old_size / quad_divisor
+            CurrentMemory(0),
+            I64ExtendUI32,
+            CurrentMemory(0),
+            I64ExtendUI32,
+            I64Mul,
+            I64Const(i64::from(quad_divisor.get())),
+            I64DivU,
+            I64Sub,
+        ]),
+    }
+    instructions.extend([
+        // todo: there should be strong guarantee that it does not return anything on
+        // stack?
+        Call(gas_func),
+        GrowMemory(0),
+        End,
+    ]);
 
     let mut b = builder::from_module(module);
     b.push_function(
@@ -485,18 +880,7 @@ fn add_grow_counter<R: Rules>(
             .with_result(ValueType::I32)
             .build()
             .body()
-            .with_instructions(elements::Instructions::new(vec![
-                GetLocal(0),
-                GetLocal(0),
-                I64ExtendUI32,
-                I64Const(i64::from(cost)),
-                I64Mul,
-                // todo: there should be strong guarantee that it does not return anything on
-                // stack?
-                Call(gas_func),
-                GrowMemory(0),
-                End,
-            ]))
+            .with_instructions(elements::Instructions::new(instructions))
             .build()
             .build(),
     );
@@ -524,6 +908,9 @@ pub(crate) fn determine_metered_blocks<R: Rules>(
 
     for cursor in 0..instructions.elements().len() {
         let instruction = &instructions.elements()[cursor];
+        if rules.is_forbidden(instruction) {
+            return Err(());
+        }
         let instruction_cost = rules.instruction_cost(instruction).ok_or(())?;
         match instruction {
             Block(_) => {
@@ -593,9 +980,16 @@ fn inject_counter<R: Rules>(
     locals_count: u32,
     rules: &R,
     gas_func: u32,
-) -> Result<(), ()> {
+) -> Result<(usize, u64), ()> {
     let blocks = determine_metered_blocks(instructions, rules, locals_count)?;
-    insert_metering_calls(instructions, gas_function_cost, blocks, gas_func)
+    let block_count = blocks.len();
+    let total_cost = blocks.iter().try_fold(0u64, |total, block| {
+        total
+            .checked_add(block.cost)
+            .and_then(|total| total.checked_add(gas_function_cost))
+    });
+    insert_metering_calls(instructions, gas_function_cost, blocks, gas_func)?;
+    Ok((block_count, total_cost.ok_or(())?))
 }
 
 // Then insert metering calls into a sequence of instructions given the block locations and costs.
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod gas_inject;
-pub use gas_inject::{ConstantCostRules, Rules};
+pub use gas_inject::{
+    ConstantCostRules, EthereumCostRules, ForbiddenSet, GasStats, MemoryGrowCost, Rules,
+};
+mod stack_limit;
 pub mod transform;
 pub use transform::GasMeter;
 #[cfg(test)]
@@ -0,0 +1,123 @@
+// Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Call depth limiting for gas-metered Wasm modules.
+//!
+//! Every `call` to a function defined in the module and every `call_indirect` is wrapped in
+//! code that maintains a depth counter in an injected mutable global. Exceeding the configured
+//! maximum charges all remaining gas through the gas function, aborting execution like running
+//! out of gas would, and traps with `unreachable` should the gas function return.
+//!
+//! The counter is not reset after a trap, so an instance that overflowed must not be reused.
+
+extern crate alloc;
+
+use super::gas_inject::{find_gas_function_import, Rules};
+use alloc::{vec, vec::Vec};
+use parity_wasm::{
+    builder,
+    elements::{self, BlockType, GlobalEntry, GlobalType, InitExpr, Instruction, ValueType},
+};
+
+/// Export name of the gas function synthesized by the gas injection pass.
+const INSTRUMENTED_USE_GAS: &str = "__instrumented_use_gas";
+
+/// Returns the index of the function a gas-metered `module` charges gas through.
+pub(crate) fn find_gas_function<R: Rules>(module: &elements::Module, rules: &R) -> Option<u32> {
+    let imported = rules
+        .gas_function_import()
+        .and_then(|(import_module, import_field)| {
+            find_gas_function_import(module, import_module, import_field)
+        });
+    imported.or_else(|| {
+        module
+            .export_section()?
+            .entries()
+            .iter()
+            .find_map(|export| match (export.field(), export.internal()) {
+                (INSTRUMENTED_USE_GAS, elements::Internal::Function(idx)) => Some(*idx),
+                _ => None,
+            })
+    })
+}
+
+/// Limit the call depth of `module` to `max_depth` nested calls.
+///
+/// Only calls to the first `defined_funcs_end` functions of the function space are counted, so
+/// functions appended by the gas injection pass are left alone. Imported functions are not
+/// counted either, as host calls cannot re-enter the module.
+pub(crate) fn inject_stack_limit(
+    module: elements::Module,
+    max_depth: u32,
+    gas_func: u32,
+    defined_funcs_end: u32,
+) -> elements::Module {
+    let imported_funcs = module.import_count(elements::ImportCountType::Function) as u32;
+    let depth_global = module.globals_space() as u32;
+
+    let mut module = builder::from_module(module)
+        .with_global(GlobalEntry::new(
+            GlobalType::new(ValueType::I32, true),
+            InitExpr::new(vec![Instruction::I32Const(0), Instruction::End]),
+        ))
+        .build();
+
+    let counted = |instruction: &Instruction| match instruction {
+        Instruction::Call(idx) => (imported_funcs..defined_funcs_end).contains(idx),
+        Instruction::CallIndirect(_, _) => true,
+        _ => false,
+    };
+
+    if let Some(code_section) = module.code_section_mut() {
+        for func_body in code_section.bodies_mut() {
+            let code = func_body.code_mut().elements_mut();
+            if !code.iter().any(counted) {
+                continue;
+            }
+
+            let mut instrumented = Vec::with_capacity(code.len());
+            for instruction in code.drain(..) {
+                if counted(&instruction) {
+                    instrumented.extend(enter_call(depth_global, max_depth, gas_func));
+                    instrumented.push(instruction);
+                    instrumented.extend(leave_call(depth_global));
+                } else {
+                    instrumented.push(instruction);
+                }
+            }
+            *code = instrumented;
+        }
+    }
+
+    module
+}
+
+/// Increment the depth counter and abort once it exceeds `max_depth`.
+fn enter_call(depth_global: u32, max_depth: u32, gas_func: u32) -> [Instruction; 12] {
+    use Instruction::*;
+    [
+        GetGlobal(depth_global),
+        I32Const(1),
+        I32Add,
+        SetGlobal(depth_global),
+        GetGlobal(depth_global),
+        I32Const(max_depth as i32),
+        I32GtU,
+        If(BlockType::NoResult),
+        I64Const(i64::MAX),
+        Call(gas_func),
+        Unreachable,
+        End,
+    ]
+}
+
+/// Decrement the depth counter after the call returns.
+fn leave_call(depth_global: u32) -> [Instruction; 4] {
+    use Instruction::*;
+    [
+        GetGlobal(depth_global),
+        I32Const(1),
+        I32Sub,
+        SetGlobal(depth_global),
+    ]
+}
@@ -1,7 +1,8 @@
 // Copyright (C) 2021-2025 the DTVM authors. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::gas_inject::{inject, ConstantCostRules, Rules};
+use super::gas_inject::{find_forbidden_instruction, inject, ConstantCostRules, GasStats, Rules};
+use super::stack_limit::{find_gas_function, inject_stack_limit};
 use parity_wasm::{elements, serialize};
 use thiserror::Error;
 
@@ -14,6 +15,9 @@ pub enum TransformError {
     #[error("Failed to inject gas metering: {0}")]
     Inject(String),
 
+    #[error("Forbidden instruction: {0}")]
+    ForbiddenInstruction(String),
+
     #[error("Failed to serialize WASM: {0}")]
     Serialize(elements::Error),
 }
@@ -37,13 +41,59 @@ impl GasMeter {
     pub fn transform_with_rules<T: Rules>(
         input_wasm: &[u8],
         gas_rules: T,
+    ) -> Result<Vec<u8>, TransformError> {
+        Self::transform_with_stats(input_wasm, gas_rules).map(|(wasm, _)| wasm)
+    }
+
+    /// Transform WASM with custom gas rules and report the injected metering code
+    pub fn transform_with_stats<T: Rules>(
+        input_wasm: &[u8],
+        gas_rules: T,
+    ) -> Result<(Vec<u8>, GasStats), TransformError> {
+        let module = elements::Module::from_bytes(input_wasm).map_err(TransformError::Parse)?;
+
+        if let Some(instruction) = find_forbidden_instruction(&module, &gas_rules) {
+            return Err(TransformError::ForbiddenInstruction(format!(
+                "{:?}",
+                instruction
+            )));
+        }
+
+        let (injected_module, stats) = inject(module, &gas_rules)
+            .map_err(|err| TransformError::Inject(format!("{:?}", err)))?;
+
+        let wasm = serialize(injected_module).map_err(TransformError::Serialize)?;
+        Ok((wasm, stats))
+    }
+
+    /// Transform WASM with custom gas rules and limit the call depth to `max_depth`
+    ///
+    /// Calls nested deeper than `max_depth` abort execution by charging all remaining gas.
+    pub fn transform_with_stack_limit<T: Rules>(
+        input_wasm: &[u8],
+        gas_rules: T,
+        max_depth: u32,
     ) -> Result<Vec<u8>, TransformError> {
         let module = elements::Module::from_bytes(input_wasm).map_err(TransformError::Parse)?;
 
-        let injected_module = inject(module, &gas_rules)
+        if let Some(instruction) = find_forbidden_instruction(&module, &gas_rules) {
+            return Err(TransformError::ForbiddenInstruction(format!(
+                "{:?}",
+                instruction
+            )));
+        }
+
+        let defined_funcs_end = module.functions_space() as u32;
+        let (injected_module, _) = inject(module, &gas_rules)
             .map_err(|err| TransformError::Inject(format!("{:?}", err)))?;
 
-        serialize(injected_module).map_err(TransformError::Serialize)
+        let gas_func = find_gas_function(&injected_module, &gas_rules).ok_or_else(|| {
+            TransformError::Inject("gas function not found after injection".to_string())
+        })?;
+        let limited_module =
+            inject_stack_limit(injected_module, max_depth, gas_func, defined_funcs_end);
+
+        serialize(limited_module).map_err(TransformError::Serialize)
     }
 }
 
@@ -206,6 +256,328 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_transform_with_ethereum_cost_rules() {
+        use crate::gas_metering::EthereumCostRules;
+
+        let wat = r#"
+            (module
+                (memory 1)
+                (func $test (result i32)
+                    i32.const 0
+                    i32.const 6
+                    i32.store
+                    i32.const 0
+                    i32.load
+                    i32.const 7
+                    i32.mul
+                )
+                (export "test" (func $test))
+            )
+        "#;
+
+        let wasm_bytes = wat::parse_str(wat).expect("Failed to parse WAT");
+        let transformed = GasMeter::transform_with_rules(&wasm_bytes, EthereumCostRules::new())
+            .expect("Transform with Ethereum rules should succeed");
+
+        // 4 x i32.const (3) + i32.store (3) + i32.load (3) + i32.mul (5)
+        let expected_cost = 4 * 3 + 3 + 3 + 5;
+        execute_and_assert(
+            &transformed,
+            1000,
+            "test",
+            &[],
+            |values| {
+                assert_eq!(values.len(), 1);
+                assert!(matches!(values[0], ZenValue::ZenI32Value(42)));
+            },
+            |left| {
+                assert_eq!(
+                    left,
+                    1000 - expected_cost,
+                    "Expected gas left {}, got {}",
+                    1000 - expected_cost,
+                    left
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_transform_with_stack_limit() {
+        let wat = r#"
+            (module
+                (func $depth (param $n i32) (result i32)
+                    local.get $n
+                    i32.eqz
+                    if (result i32)
+                        i32.const 0
+                    else
+                        local.get $n
+                        i32.const 1
+                        i32.sub
+                        call $depth
+                        i32.const 1
+                        i32.add
+                    end
+                )
+                (export "depth" (func $depth))
+            )
+        "#;
+
+        let wasm_bytes = wat::parse_str(wat).expect("Failed to parse WAT");
+        let transformed =
+            GasMeter::transform_with_stack_limit(&wasm_bytes, ConstantCostRules::default(), 10)
+                .expect("Transform with stack limit should succeed");
+
+        // depth(n) makes n nested calls
+        execute_and_assert(
+            &transformed,
+            1_000_000,
+            "depth",
+            &[ZenValue::ZenI32Value(10)],
+            |values| {
+                assert!(matches!(values[0], ZenValue::ZenI32Value(10)));
+            },
+            |_| {},
+        );
+
+        let rt = ZenRuntime::new(None);
+        let wasm_mod = rt
+            .load_module_from_bytes("stack_limit_test.wasm", &transformed)
+            .expect("Failed to load transformed WASM module.");
+        let isolation = rt.new_isolation().expect("Failed to create isolation.");
+        let inst = wasm_mod
+            .new_instance(isolation, 1_000_000)
+            .expect("Failed to create WASM instance.");
+        assert!(
+            inst.call_wasm_func("depth", &[ZenValue::ZenI32Value(11)])
+                .is_err(),
+            "Exceeding the call depth limit should trap"
+        );
+    }
+
+    /// Sum the static gas charges injected before calls to the gas function
+    fn total_injected_charge(wasm_bytes: &[u8]) -> i64 {
+        let module =
+            elements::Module::from_bytes(wasm_bytes).expect("Failed to parse transformed WASM");
+        let gas_idx = module
+            .export_section()
+            .and_then(|export_section| {
+                export_section.entries().iter().find_map(|export| {
+                    match (export.field(), export.internal()) {
+                        (INSTRUMENTED_USE_GAS, elements::Internal::Function(idx)) => Some(*idx),
+                        _ => None,
+                    }
+                })
+            })
+            .expect("Transformed WASM should export __instrumented_use_gas");
+
+        module.code_section().map_or(0, |code_section| {
+            code_section
+                .bodies()
+                .iter()
+                .flat_map(|body| body.code().elements().windows(2))
+                .filter_map(|pair| match pair {
+                    [elements::Instruction::I64Const(cost), elements::Instruction::Call(idx)]
+                        if *idx == gas_idx =>
+                    {
+                        Some(*cost)
+                    }
+                    _ => None,
+                })
+                .sum()
+        })
+    }
+
+    #[test]
+    fn test_transform_with_import_cost_override() {
+        let wat = r#"
+            (module
+                (import "env" "expensive" (func $expensive))
+                (import "env" "cheap" (func $cheap))
+                (func $test
+                    call $expensive
+                    call $cheap
+                )
+                (export "test" (func $test))
+            )
+        "#;
+
+        let wasm_bytes = wat::parse_str(wat).expect("Failed to parse WAT");
+
+        // Without an override both calls cost a regular instruction
+        let transformed =
+            GasMeter::transform_with_rules(&wasm_bytes, ConstantCostRules::new(1, 0, 0))
+                .expect("Transform should succeed");
+        assert_eq!(total_injected_charge(&transformed), 2);
+
+        // The override applies only to calls to the named import
+        let rules = ConstantCostRules::new(1, 0, 0).with_import_cost("expensive", 100);
+        let transformed = GasMeter::transform_with_rules(&wasm_bytes, rules)
+            .expect("Transform with import cost should succeed");
+        assert_eq!(total_injected_charge(&transformed), 101);
+    }
+
+    #[test]
+    fn test_transform_with_gas_function_import() {
+        let wat = r#"
+            (module
+                (import "env" "useGas" (func $useGas (param i64)))
+                (func $test (param $n i32) (result i32)
+                    local.get $n
+                    i32.const 1
+                    i32.add
+                )
+                (export "test" (func $test))
+            )
+        "#;
+
+        let wasm_bytes = wat::parse_str(wat).expect("Failed to parse WAT");
+        let rules = ConstantCostRules::new(1, 0, 0).with_gas_function_import("env", "useGas");
+        let transformed = GasMeter::transform_with_rules(&wasm_bytes, rules)
+            .expect("Transform with gas function import should succeed");
+        let module =
+            elements::Module::from_bytes(&transformed).expect("Failed to parse transformed WASM");
+
+        // No gas function is synthesized or exported
+        assert_eq!(module.functions_space(), 2);
+        let exports_gas_function = module.export_section().map_or(false, |export_section| {
+            export_section
+                .entries()
+                .iter()
+                .any(|export| export.field() == INSTRUMENTED_USE_GAS)
+        });
+        assert!(!exports_gas_function);
+
+        // Metering calls target the imported useGas (function index 0)
+        let code = module
+            .code_section()
+            .expect("Missing code section")
+            .bodies()[0]
+            .code()
+            .elements();
+        assert_eq!(
+            &code[..2],
+            &[
+                elements::Instruction::I64Const(3),
+                elements::Instruction::Call(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transform_with_stats() {
+        let wat = r#"
+            (module
+                (memory 1)
+                (func $test (param $n i32)
+                    local.get $n
+                    if
+                        i32.const 1
+                        memory.grow
+                        drop
+                    end
+                )
+                (export "test" (func $test))
+            )
+        "#;
+
+        let wasm_bytes = wat::parse_str(wat).expect("Failed to parse WAT");
+        let (transformed, stats) =
+            GasMeter::transform_with_stats(&wasm_bytes, ConstantCostRules::new(1, 8192, 0))
+                .expect("Transform with stats should succeed");
+
+        assert!(stats.metering_blocks > 0, "Expected metered blocks");
+        assert_eq!(
+            stats.total_static_cost,
+            total_injected_charge(&transformed) as u64
+        );
+        assert_eq!(stats.memory_grow_points, 1);
+
+        // The stats do not change the transformed module
+        let plain = GasMeter::transform_with_rules(&wasm_bytes, ConstantCostRules::new(1, 8192, 0))
+            .expect("Transform should succeed");
+        assert_eq!(transformed, plain);
+    }
+
+    #[test]
+    fn test_transform_forbidden_instruction() {
+        use crate::gas_metering::ForbiddenSet;
+        use parity_wasm::elements::Instruction;
+
+        let wat = r#"
+            (module
+                (func $add (param $a f64) (param $b f64) (result f64)
+                    local.get $a
+                    local.get $b
+                    f64.add
+                )
+                (export "add" (func $add))
+            )
+        "#;
+
+        let wasm_bytes = wat::parse_str(wat).expect("Failed to parse WAT");
+
+        // Floats are accepted unless forbidden
+        let allowed = ForbiddenSet::new(ConstantCostRules::default()).forbid(&Instruction::F32Add);
+        GasMeter::transform_with_rules(&wasm_bytes, allowed)
+            .expect("f64.add should be accepted when not forbidden");
+
+        let forbidden = ForbiddenSet::new(ConstantCostRules::default())
+            .forbid(&Instruction::F32Add)
+            .forbid(&Instruction::F64Add);
+        let result = GasMeter::transform_with_rules(&wasm_bytes, forbidden);
+        assert!(
+            matches!(result, Err(TransformError::ForbiddenInstruction(ref name)) if name == "F64Add"),
+            "f64.add should be rejected when forbidden"
+        );
+    }
+
+    #[test]
+    fn test_transform_with_quadratic_memory_grow() {
+        let wat = r#"
+            (module
+                (memory 1)
+                (func $grow (param $pages i32)
+                    local.get $pages
+                    memory.grow
+                    drop
+                )
+                (export "grow" (func $grow))
+            )
+        "#;
+
+        let wasm_bytes = wat::parse_str(wat).expect("Failed to parse WAT");
+        // Only memory growth costs gas: pages + new_size^2 - old_size^2
+        let rules = ConstantCostRules::new(0, 1, 0).with_quadratic_memory_grow(1);
+        let transformed =
+            GasMeter::transform_with_rules(&wasm_bytes, rules).expect("Transform should succeed");
+
+        let grow_cost = |pages: i32| {
+            let gas_left = std::cell::Cell::new(0);
+            execute_and_assert(
+                &transformed,
+                1000,
+                "grow",
+                &[ZenValue::ZenI32Value(pages)],
+                |_| {},
+                |left| gas_left.set(left),
+            );
+            1000 - gas_left.get()
+        };
+
+        // Growing from 1 page: 1 + 2^2 - 1^2 and 4 + 5^2 - 1^2
+        let one_page = grow_cost(1);
+        let four_pages = grow_cost(4);
+        assert_eq!(one_page, 4);
+        assert_eq!(four_pages, 28);
+        assert!(
+            four_pages > 4 * one_page,
+            "Memory growth should cost super-linearly"
+        );
+    }
+
     #[test]
     fn test_transform_invalid_wasm() {
         let invalid_wasm = b"invalid wasm bytes";
@@ -218,6 +218,14 @@ mod tests {
             self.storage.get(key).copied().unwrap_or([0u8; 32])
         }
 
+        fn transient_store(&self, _key: &[u8; 32], _value: &[u8; 32]) {
+            // For testing purposes, we'll just ignore the store operation
+        }
+
+        fn transient_load(&self, _key: &[u8; 32]) -> [u8; 32] {
+            [0u8; 32]
+        }
+
         fn emit_log_event(&self, _event: LogEvent) {
             // For testing purposes, we'll just ignore the log event
             // In a real implementation, this would need interior mutability
@@ -476,6 +484,20 @@ mod tests {
         assert_eq!(zero_balance, [0u8; 32]);
     }
 
+    #[test]
+    fn test_self_balance() {
+        let mut balance = [0u8; 32];
+        balance[31] = 42;
+        let host = TestEvmHost::new().with_external_balance([1u8; 20], balance);
+
+        // SELFBALANCE matches BALANCE of the contract's own address
+        assert_eq!(host.get_self_balance(), balance);
+        assert_eq!(
+            host.get_self_balance(),
+            host.get_external_balance(host.get_address())
+        );
+    }
+
     #[test]
     fn test_block_operations() {
         let host = TestEvmHost::default();
@@ -834,4 +856,134 @@ mod tests {
         let host_max_gas = TestEvmHost::new().with_gas_left(i64::MAX);
         assert_eq!(host_max_gas.get_gas_left(i64::MAX), i64::MAX);
     }
+
+    /// Instantiate a module with one page of memory for MemoryAccessor tests
+    fn memory_test_instance() -> std::rc::Rc<crate::core::instance::ZenInstance<i64>> {
+        host_test_instance(0)
+    }
+
+    /// Instantiate a module with one page of memory and `host` as its context
+    fn host_test_instance<T: Clone>(host: T) -> std::rc::Rc<crate::core::instance::ZenInstance<T>> {
+        use crate::core::runtime::ZenRuntime;
+
+        let wasm_bytes = wat::parse_str("(module (memory 1))").expect("Failed to parse WAT");
+        let rt = ZenRuntime::new(None);
+        let wasm_mod = rt
+            .load_module_from_bytes("memory_accessor_test.wasm", &wasm_bytes)
+            .expect("Failed to load module");
+        let isolation = rt.new_isolation().expect("Failed to create isolation");
+        wasm_mod
+            .new_instance_with_context(isolation, 1000, host)
+            .expect("Failed to create instance")
+    }
+
+    #[test]
+    fn test_memory_accessor_integer_round_trips() {
+        use crate::evm::utils::MemoryAccessor;
+        use num_bigint::BigUint;
+
+        let inst = memory_test_instance();
+        let memory = MemoryAccessor::new(&inst);
+
+        // u64 values in both byte orders
+        memory.write_u64_be(0, u64::MAX).unwrap();
+        assert_eq!(memory.read_u64_be(0).unwrap(), u64::MAX);
+        memory.write_u64_be(8, 0x0102_0304_0506_0708).unwrap();
+        assert_eq!(memory.read_bytes(8, 8).unwrap(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(memory.read_u64_le(8).unwrap(), 0x0807_0605_0403_0201);
+
+        // A 31-byte uint256 is zero-padded to a full word
+        let value = BigUint::from_bytes_be(&[0xAB; 31]);
+        memory.write_bytes32(32, &[0xFF; 32]).unwrap();
+        memory.write_u256_be(32, &value).unwrap();
+        assert_eq!(memory.read_bytes(32, 1).unwrap(), &[0]);
+        assert_eq!(memory.read_u256_be(32).unwrap(), value);
+
+        // Reads past the end of memory fail
+        assert!(memory.read_u64_be(65536 - 4).is_err());
+    }
+
+    #[test]
+    fn test_default_host_defaults() {
+        use crate::evm::DefaultHost;
+
+        let host = DefaultHost::new().with_warnings(true);
+
+        assert_eq!(host.get_address(), &[0u8; 20]);
+        assert_eq!(host.get_block_hash(1), None);
+        assert!(host.call_data_copy().is_empty());
+        assert_eq!(host.get_gas_left(1234), 1234);
+        assert_eq!(host.get_external_balance(&[0x11; 20]), [0u8; 32]);
+        assert_eq!(host.get_external_code_hash(&[0x11; 20]), None);
+
+        // Writes are ignored
+        host.storage_store(&[0x01; 32], &[0x02; 32]);
+        assert_eq!(host.storage_load(&[0x01; 32]), [0u8; 32]);
+        host.finish(vec![0x01]);
+        assert!(host.return_data_copy().is_empty());
+
+        // Calls and creations fail
+        assert_eq!(
+            host.call_contract(&[0x11; 20], &[0u8; 20], &[0u8; 32], &[], 1000),
+            ContractCallResult::simple_failure()
+        );
+        assert_eq!(
+            host.create_contract(&[0u8; 20], &[0u8; 32], &[], &[], 1000, None, false),
+            ContractCreateResult::simple_failure()
+        );
+
+        // Provided trait methods build on the defaults
+        assert_eq!(host.get_self_balance(), [0u8; 32]);
+        assert!(host.verify_chain_id(0));
+    }
+
+    #[test]
+    fn test_memory_accessor_write_bytes_padded() {
+        use crate::evm::utils::MemoryAccessor;
+
+        let inst = memory_test_instance();
+        let memory = MemoryAccessor::new(&inst);
+
+        // Shorter data is zero-filled up to the total length
+        memory.write_bytes(0, &[0xFF; 8]).unwrap();
+        memory.write_bytes_padded(0, &[1, 2, 3], 6).unwrap();
+        assert_eq!(
+            memory.read_bytes(0, 8).unwrap(),
+            &[1, 2, 3, 0, 0, 0, 0xFF, 0xFF]
+        );
+
+        // Longer data is truncated to the total length
+        memory.write_bytes_padded(16, &[1, 2, 3, 4, 5], 2).unwrap();
+        assert_eq!(memory.read_bytes(16, 5).unwrap(), &[1, 2, 0, 0, 0]);
+
+        // The whole padded range must be in bounds
+        assert!(memory.write_bytes_padded(65536 - 2, &[1], 4).is_err());
+    }
+
+    #[test]
+    fn test_return_data_copy_bounds() {
+        use crate::evm::host_functions::control::return_data_copy;
+        use crate::evm::utils::MemoryAccessor;
+
+        let mut host = TestEvmHost::new();
+        host.set_return_data(vec![1, 2, 3, 4]);
+        let inst = host_test_instance(host);
+        let memory = MemoryAccessor::new(&inst);
+
+        // Copying up to exactly the end of the return data succeeds
+        return_data_copy(&inst, 0, 1, 3).unwrap();
+        assert_eq!(memory.read_bytes(0, 3).unwrap(), &[2, 3, 4]);
+
+        // Reading past the end fails instead of zero-filling
+        let error = return_data_copy(&inst, 32, 2, 3).unwrap_err();
+        assert_eq!(error.category(), "memory");
+        assert_eq!(error.function(), "return_data_copy");
+        assert!(return_data_copy(&inst, 32, 5, 0).is_err());
+
+        // Zero-length copies within the return data leave memory untouched
+        memory.write_bytes(64, &[0xFF; 4]).unwrap();
+        return_data_copy(&inst, 64, 0, 0).unwrap();
+        return_data_copy(&inst, 64, 4, 0).unwrap();
+        assert_eq!(memory.read_bytes(64, 4).unwrap(), &[0xFF; 4]);
+    }
 }